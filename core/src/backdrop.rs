@@ -0,0 +1,37 @@
+use crate::Pixels;
+
+/// A filter applied to whatever has already been drawn behind an element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backdrop {
+    /// The blur radius of the backdrop.
+    pub blur_radius: f32,
+
+    /// The saturation of the backdrop.
+    ///
+    /// 0 means grayscale. 1 leaves the colors untouched.
+    pub saturation: f32,
+}
+
+impl Backdrop {
+    /// Creates a new [`Backdrop`] that blurs with the given radius.
+    pub fn blur(radius: impl Into<Pixels>) -> Self {
+        Self {
+            blur_radius: radius.into().0,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the saturation of the [`Backdrop`].
+    pub fn saturation(self, saturation: f32) -> Self {
+        Self { saturation, ..self }
+    }
+}
+
+impl Default for Backdrop {
+    fn default() -> Self {
+        Self {
+            blur_radius: 0.0,
+            saturation: 1.0,
+        }
+    }
+}
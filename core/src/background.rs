@@ -1,14 +1,18 @@
-use crate::Color;
+//! Fill the background of some element.
+use crate::border;
 use crate::gradient::{self, Gradient};
+use crate::image;
+use crate::{Color, ContentFit, Padding, Point, Radians, Rectangle, Size, Vector};
 
 /// The background of some element.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Background {
     /// A solid color.
     Color(Color),
     /// Linearly interpolate between several colors.
     Gradient(Gradient),
-    // TODO: Add image variant
+    /// A raster image.
+    Image(Image),
 }
 
 impl Background {
@@ -18,6 +22,10 @@ impl Background {
         match self {
             Self::Color(color) => Self::Color(color.scale_alpha(factor)),
             Self::Gradient(gradient) => Self::Gradient(gradient.scale_alpha(factor)),
+            Self::Image(image) => Self::Image(Image {
+                opacity: image.opacity * factor,
+                ..image
+            }),
         }
     }
 }
@@ -39,3 +47,259 @@ impl From<gradient::Linear> for Background {
         Background::Gradient(Gradient::Linear(gradient))
     }
 }
+
+impl From<gradient::Radial> for Background {
+    fn from(gradient: gradient::Radial) -> Self {
+        Background::Gradient(Gradient::Radial(gradient))
+    }
+}
+
+impl From<gradient::Conic> for Background {
+    fn from(gradient: gradient::Conic) -> Self {
+        Background::Gradient(Gradient::Conic(gradient))
+    }
+}
+
+impl From<Image> for Background {
+    fn from(image: Image) -> Self {
+        Background::Image(image)
+    }
+}
+
+impl From<image::Handle> for Background {
+    fn from(handle: image::Handle) -> Self {
+        Background::Image(Image::new(handle))
+    }
+}
+
+/// A raster image used as a [`Background`].
+///
+/// Image backgrounds are only drawn by renderers with image support; that
+/// is, with the `image` feature enabled. Otherwise, the background is left
+/// transparent—while its border and shadow are still drawn—and a warning
+/// is logged once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    /// The handle of the image.
+    pub handle: image::Handle,
+
+    /// The way the image fills the background.
+    pub mode: Mode,
+
+    /// The filter method of the image.
+    pub filter_method: image::FilterMethod,
+
+    /// The opacity of the image.
+    ///
+    /// 0 means transparent. 1 means opaque.
+    pub opacity: f32,
+}
+
+impl Image {
+    /// Creates a new [`Image`] with the given handle.
+    ///
+    /// By default, the image will [`ContentFit::Cover`] the background.
+    pub fn new(handle: impl Into<image::Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            mode: Mode::Fit(ContentFit::Cover),
+            filter_method: image::FilterMethod::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Fits the [`Image`] in the background using the given [`ContentFit`].
+    ///
+    /// The image will be centered and cropped to the background bounds.
+    pub fn fit(self, content_fit: ContentFit) -> Self {
+        Self {
+            mode: Mode::Fit(content_fit),
+            ..self
+        }
+    }
+
+    /// Repeats the [`Image`] at its original size to fill the background,
+    /// starting at its top left corner.
+    pub fn repeat(self) -> Self {
+        Self {
+            mode: Mode::Repeat,
+            ..self
+        }
+    }
+
+    /// Slices the [`Image`] in nine parts using the given insets; which
+    /// are measured in pixels of the image.
+    ///
+    /// The corners keep their original size, the edges are stretched along
+    /// a single axis, and the center is stretched to fill the rest of the
+    /// background.
+    pub fn nine_slice(self, insets: impl Into<Padding>) -> Self {
+        Self {
+            mode: Mode::NineSlice(insets.into()),
+            ..self
+        }
+    }
+
+    /// Sets the filter method of the [`Image`].
+    pub fn filter_method(self, filter_method: image::FilterMethod) -> Self {
+        Self {
+            filter_method,
+            ..self
+        }
+    }
+
+    /// Sets the opacity of the [`Image`].
+    pub fn opacity(self, opacity: impl Into<f32>) -> Self {
+        Self {
+            opacity: opacity.into(),
+            ..self
+        }
+    }
+
+    /// Draws the [`Image`] filling the given bounds with an [`image::Renderer`].
+    ///
+    /// The given border radius rounds the corners of the bounds, which
+    /// clip every part of the [`Image`]; unless it is nine-sliced.
+    pub fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        border_radius: border::Radius,
+    ) where
+        Renderer: image::Renderer<Handle = image::Handle>,
+    {
+        let Some(size) = renderer.measure_image(&self.handle) else {
+            return;
+        };
+
+        let size = Size::new(size.width as f32, size.height as f32);
+
+        if size.width < 1.0 || size.height < 1.0 || bounds.width <= 0.0 || bounds.height <= 0.0 {
+            return;
+        }
+
+        // The border radius of an image is applied to its clip bounds;
+        // so every part of it is clipped to the same rounded bounds
+        let image = image::Image {
+            handle: self.handle.clone(),
+            filter_method: self.filter_method,
+            rotation: Radians(0.0),
+            border_radius,
+            opacity: self.opacity,
+            snap: false,
+            repeat: None,
+        };
+
+        match self.mode {
+            Mode::Fit(content_fit) => {
+                let fitted = content_fit.fit(size, bounds.size());
+
+                let position =
+                    bounds.center() - Vector::new(fitted.width / 2.0, fitted.height / 2.0);
+
+                renderer.draw_image(image, Rectangle::new(position, fitted), bounds);
+            }
+            Mode::Repeat => {
+                renderer.draw_image(
+                    image::Image {
+                        repeat: Some(size),
+                        ..image
+                    },
+                    bounds,
+                    bounds,
+                );
+            }
+            Mode::NineSlice(insets) => {
+                let image = image::Image {
+                    border_radius: border::Radius::default(),
+                    ..image
+                };
+
+                let source_x = slices(insets.left, insets.right, size.width);
+                let source_y = slices(insets.top, insets.bottom, size.height);
+
+                let target_x = slices(insets.left, insets.right, bounds.width);
+                let target_y = slices(insets.top, insets.bottom, bounds.height);
+
+                for row in 0..3 {
+                    for column in 0..3 {
+                        let source = Rectangle {
+                            x: source_x[column],
+                            y: source_y[row],
+                            width: source_x[column + 1] - source_x[column],
+                            height: source_y[row + 1] - source_y[row],
+                        };
+
+                        let target = Rectangle {
+                            x: bounds.x + target_x[column],
+                            y: bounds.y + target_y[row],
+                            width: target_x[column + 1] - target_x[column],
+                            height: target_y[row + 1] - target_y[row],
+                        };
+
+                        if source.width <= 0.0
+                            || source.height <= 0.0
+                            || target.width <= 0.0
+                            || target.height <= 0.0
+                        {
+                            continue;
+                        }
+
+                        // We stretch the whole image so the slice lands
+                        // on its target, and then clip the rest away
+                        let scale_x = target.width / source.width;
+                        let scale_y = target.height / source.height;
+
+                        let stretched = Rectangle::new(
+                            Point::new(
+                                target.x - source.x * scale_x,
+                                target.y - source.y * scale_y,
+                            ),
+                            Size::new(size.width * scale_x, size.height * scale_y),
+                        );
+
+                        renderer.draw_image(image.clone(), stretched, target);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The way an [`Image`] fills a [`Background`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// The image is fitted using the given [`ContentFit`] and centered.
+    Fit(ContentFit),
+    /// The image is repeated at its original size.
+    Repeat,
+    /// The image is sliced in nine parts using the given insets.
+    NineSlice(Padding),
+}
+
+/// Splits a length in three slices using the given insets; shrinking
+/// the insets proportionally if they do not fit.
+fn slices(start: f32, end: f32, length: f32) -> [f32; 4] {
+    let start = start.max(0.0);
+    let end = end.max(0.0);
+
+    let factor = if start + end > length {
+        length / (start + end)
+    } else {
+        1.0
+    };
+
+    [0.0, start * factor, length - end * factor, length]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nine_slices_shrink_insets_that_do_not_fit() {
+        assert_eq!(slices(10.0, 20.0, 100.0), [0.0, 10.0, 80.0, 100.0]);
+        assert_eq!(slices(30.0, 10.0, 20.0), [0.0, 15.0, 15.0, 20.0]);
+        assert_eq!(slices(-5.0, 0.0, 20.0), [0.0, 0.0, 20.0, 20.0]);
+    }
+}
@@ -1,4 +1,10 @@
 //! Access the clipboard.
+use crate::image;
+use crate::window::Screenshot;
+
+use bytes::Bytes;
+
+use std::fmt;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
@@ -8,6 +14,40 @@ pub trait Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     fn write(&mut self, kind: Kind, contents: String);
+
+    /// Reads the current content of the [`Clipboard`] in the given [`Format`].
+    ///
+    /// By default, only [`Format::Text`] is supported.
+    fn read_format(&self, kind: Kind, format: &Format) -> Option<Content> {
+        match format {
+            Format::Text => self.read(kind).map(Content::Text),
+            _ => None,
+        }
+    }
+
+    /// Writes the given contents to the [`Clipboard`], replacing its
+    /// current ones.
+    ///
+    /// Every [`Content`] should be a different representation of the same
+    /// data (e.g. some HTML and its plain text), so readers can pick the
+    /// [`Format`] that suits them best.
+    ///
+    /// By default, only the first [`Content::Text`] is written.
+    fn write_formats(&mut self, kind: Kind, contents: Vec<Content>) {
+        if let Some(text) = contents.into_iter().find_map(Content::into_text) {
+            self.write(kind, text);
+        }
+    }
+
+    /// Returns the [`Format`]s the current content of the [`Clipboard`]
+    /// is available in.
+    ///
+    /// By default, only [`Format::Text`] is detected.
+    fn formats(&self, kind: Kind) -> Vec<Format> {
+        self.read(kind)
+            .map(|_| vec![Format::Text])
+            .unwrap_or_default()
+    }
 }
 
 /// The kind of [`Clipboard`].
@@ -21,6 +61,137 @@ pub enum Kind {
     Primary,
 }
 
+/// The format of some [`Content`] in a [`Clipboard`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Plain UTF-8 text.
+    Text,
+    /// An HTML fragment.
+    Html,
+    /// A decoded [`Image`].
+    Image,
+    /// Raw bytes of the given MIME type (e.g. `application/json`).
+    ///
+    /// The system clipboard of the `iced_winit` shell does not support it.
+    Mime(String),
+}
+
+impl Format {
+    /// Returns the MIME type of the [`Format`].
+    pub fn mime(&self) -> &str {
+        match self {
+            Self::Text => "text/plain;charset=utf-8",
+            Self::Html => "text/html",
+            Self::Image => "image/png",
+            Self::Mime(mime) => mime,
+        }
+    }
+}
+
+/// Some content of a [`Clipboard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Content {
+    /// Plain UTF-8 text.
+    Text(String),
+    /// An HTML fragment.
+    Html(String),
+    /// A decoded [`Image`].
+    Image(Image),
+    /// Raw bytes of some MIME type.
+    Mime {
+        /// The MIME type of the data.
+        mime: String,
+        /// The raw data.
+        data: Bytes,
+    },
+}
+
+impl Content {
+    /// Returns the [`Format`] of the [`Content`].
+    pub fn format(&self) -> Format {
+        match self {
+            Self::Text(_) => Format::Text,
+            Self::Html(_) => Format::Html,
+            Self::Image(_) => Format::Image,
+            Self::Mime { mime, .. } => Format::Mime(mime.clone()),
+        }
+    }
+
+    /// Returns the text of the [`Content`], if it is [`Content::Text`].
+    pub fn into_text(self) -> Option<String> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for Content {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Content {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<Image> for Content {
+    fn from(image: Image) -> Self {
+        Self::Image(image)
+    }
+}
+
+/// An image stored in a [`Clipboard`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Image {
+    /// The width of the [`Image`], in pixels.
+    pub width: u32,
+    /// The height of the [`Image`], in pixels.
+    pub height: u32,
+    /// The RGBA pixels of the [`Image`].
+    pub rgba: Bytes,
+}
+
+impl Image {
+    /// Creates a new [`Image`] with the given dimensions and RGBA pixels.
+    pub fn new(width: u32, height: u32, rgba: impl Into<Bytes>) -> Self {
+        Self {
+            width,
+            height,
+            rgba: rgba.into(),
+        }
+    }
+}
+
+impl fmt::Debug for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Image")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("rgba", &format_args!("{} bytes", self.rgba.len()))
+            .finish()
+    }
+}
+
+impl From<Screenshot> for Image {
+    fn from(screenshot: Screenshot) -> Self {
+        Self::new(
+            screenshot.size.width,
+            screenshot.size.height,
+            screenshot.rgba,
+        )
+    }
+}
+
+impl From<Image> for image::Handle {
+    fn from(image: Image) -> Self {
+        image::Handle::from_rgba(image.width, image.height, image.rgba)
+    }
+}
+
 /// A null implementation of the [`Clipboard`] trait.
 #[derive(Debug, Clone, Copy)]
 pub struct Null;
@@ -32,3 +203,94 @@ impl Clipboard for Null {
 
     fn write(&mut self, _kind: Kind, _contents: String) {}
 }
+
+/// A [`Clipboard`] that keeps its contents in memory.
+///
+/// It supports every [`Format`], which makes it useful for testing.
+#[derive(Debug, Clone, Default)]
+pub struct Memory {
+    standard: Vec<Content>,
+    primary: Vec<Content>,
+}
+
+impl Memory {
+    /// Creates a new empty [`Memory`] clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current contents of the given [`Kind`] of clipboard.
+    pub fn contents(&self, kind: Kind) -> &[Content] {
+        match kind {
+            Kind::Standard => &self.standard,
+            Kind::Primary => &self.primary,
+        }
+    }
+
+    fn contents_mut(&mut self, kind: Kind) -> &mut Vec<Content> {
+        match kind {
+            Kind::Standard => &mut self.standard,
+            Kind::Primary => &mut self.primary,
+        }
+    }
+}
+
+impl Clipboard for Memory {
+    fn read(&self, kind: Kind) -> Option<String> {
+        self.contents(kind)
+            .iter()
+            .find_map(|content| match content {
+                Content::Text(text) => Some(text.clone()),
+                _ => None,
+            })
+    }
+
+    fn write(&mut self, kind: Kind, contents: String) {
+        *self.contents_mut(kind) = vec![Content::Text(contents)];
+    }
+
+    fn read_format(&self, kind: Kind, format: &Format) -> Option<Content> {
+        self.contents(kind)
+            .iter()
+            .find(|content| content.format() == *format)
+            .cloned()
+    }
+
+    fn write_formats(&mut self, kind: Kind, contents: Vec<Content>) {
+        *self.contents_mut(kind) = contents;
+    }
+
+    fn formats(&self, kind: Kind) -> Vec<Format> {
+        self.contents(kind).iter().map(Content::format).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_keeps_every_format() {
+        let mut clipboard = Memory::new();
+
+        clipboard.write_formats(
+            Kind::Standard,
+            vec![
+                Content::Html("<b>iced</b>".to_owned()),
+                Content::Text("iced".to_owned()),
+            ],
+        );
+
+        assert_eq!(clipboard.read(Kind::Standard).as_deref(), Some("iced"));
+        assert_eq!(
+            clipboard.read_format(Kind::Standard, &Format::Html),
+            Some(Content::Html("<b>iced</b>".to_owned()))
+        );
+        assert_eq!(clipboard.read_format(Kind::Standard, &Format::Image), None);
+        assert_eq!(clipboard.formats(Kind::Primary), Vec::new());
+
+        clipboard.write(Kind::Standard, "text".to_owned());
+
+        assert_eq!(clipboard.formats(Kind::Standard), vec![Format::Text]);
+    }
+}
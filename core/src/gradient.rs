@@ -1,21 +1,36 @@
 //! Colors that transition progressively.
-use crate::{Color, Radians};
+use crate::{Color, Point, Radians, Size};
 
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which transitions colors progressively along a direction, either linearly, radially (TBD),
-/// or conically (TBD).
+/// A fill which transitions colors progressively along a direction, either linearly, radially,
+/// or conically.
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction at a specific angle.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from a center.
+    Radial(Radial),
+    /// A conic gradient interpolates colors around a center.
+    Conic(Conic),
 }
 
 impl Gradient {
+    /// Returns the [`ColorStop`]s of the [`Gradient`].
+    pub fn stops(&self) -> &[Option<ColorStop>; 8] {
+        match self {
+            Gradient::Linear(linear) => &linear.stops,
+            Gradient::Radial(radial) => &radial.stops,
+            Gradient::Conic(conic) => &conic.stops,
+        }
+    }
+
     /// Scales the alpha channel of the [`Gradient`] by the given factor.
     pub fn scale_alpha(self, factor: f32) -> Self {
         match self {
             Gradient::Linear(linear) => Gradient::Linear(linear.scale_alpha(factor)),
+            Gradient::Radial(radial) => Gradient::Radial(radial.scale_alpha(factor)),
+            Gradient::Conic(conic) => Gradient::Conic(conic.scale_alpha(factor)),
         }
     }
 }
@@ -26,6 +41,18 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 /// A point along the gradient vector where the specified [`color`] is unmixed.
 ///
@@ -64,19 +91,7 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) = self.stops.binary_search_by(|stop| match stop {
-                None => Ordering::Greater,
-                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-            });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
-        };
-
+        add_stop(&mut self.stops, offset, color);
         self
     }
 
@@ -94,10 +109,137 @@ impl Linear {
     /// Scales the alpha channel of the [`Linear`] gradient by the given
     /// factor.
     pub fn scale_alpha(mut self, factor: f32) -> Self {
-        for stop in self.stops.iter_mut().flatten() {
-            stop.color.a *= factor;
+        scale_alpha(&mut self.stops, factor);
+        self
+    }
+}
+
+/// A radial gradient.
+///
+/// Its center and radii are relative to the bounds of the gradient; that is,
+/// a center of `(0.5, 0.5)` and radii of `(0.5, 0.5)` describe an ellipse
+/// touching the middle of every edge of the bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The relative center of the gradient.
+    pub center: Point,
+    /// The relative horizontal and vertical radii of the gradient.
+    pub radii: Size,
+    /// [`ColorStop`]s along the radial gradient path, from the center outwards.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] gradient with the given relative center and radii.
+    pub fn new(center: impl Into<Point>, radii: impl Into<Size>) -> Self {
+        Self {
+            center: center.into(),
+            radii: radii.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Scales the alpha channel of the [`Radial`] gradient by the given
+    /// factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        scale_alpha(&mut self.stops, factor);
+        self
+    }
+}
+
+/// A conic gradient.
+///
+/// Its center is relative to the bounds of the gradient; that is, a center
+/// of `(0.5, 0.5)` is the center of the bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The relative center of the gradient.
+    pub center: Point,
+    /// The angle where the gradient starts; where `0` points up and the
+    /// gradient progresses clockwise.
+    pub angle: Radians,
+    /// [`ColorStop`]s along the conic gradient path.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] gradient with the given relative center and
+    /// starting angle in [`Radians`].
+    pub fn new(center: impl Into<Point>, angle: impl Into<Radians>) -> Self {
+        Self {
+            center: center.into(),
+            angle: angle.into(),
+            stops: [None; 8],
         }
+    }
 
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
         self
     }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Scales the alpha channel of the [`Conic`] gradient by the given
+    /// factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        scale_alpha(&mut self.stops, factor);
+        self
+    }
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) = stops.binary_search_by(|stop| match stop {
+            None => Ordering::Greater,
+            Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+        });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
+    };
+}
+
+fn scale_alpha(stops: &mut [Option<ColorStop>; 8], factor: f32) {
+    for stop in stops.iter_mut().flatten() {
+        stop.color.a *= factor;
+    }
 }
@@ -36,6 +36,13 @@ pub struct Image<H = Handle> {
     /// This can avoid graphical glitches, specially when using
     /// [`FilterMethod::Nearest`].
     pub snap: bool,
+
+    /// The size of the tiles of the [`Image`], if it is repeated.
+    ///
+    /// A repeated [`Image`] is drawn at this size, starting at the top-left
+    /// corner of its bounds, and repeated until the bounds are filled;
+    /// instead of being stretched to fit them.
+    pub repeat: Option<Size>,
 }
 
 impl Image<Handle> {
@@ -48,6 +55,7 @@ impl Image<Handle> {
             border_radius: border::Radius::default(),
             opacity: 1.0,
             snap: false,
+            repeat: None,
         }
     }
 
@@ -74,6 +82,12 @@ impl Image<Handle> {
         self.snap = snap;
         self
     }
+
+    /// Repeats the [`Image`] with tiles of the given size to fill its bounds.
+    pub fn repeat(mut self, tile: impl Into<Size>) -> Self {
+        self.repeat = Some(tile.into());
+        self
+    }
 }
 
 impl From<&Handle> for Image {
@@ -11,6 +11,7 @@
 )]
 pub mod alignment;
 pub mod animation;
+pub mod background;
 pub mod border;
 pub mod clipboard;
 pub mod event;
@@ -33,7 +34,7 @@ pub mod widget;
 pub mod window;
 
 mod angle;
-mod background;
+mod backdrop;
 mod color;
 mod content_fit;
 mod element;
@@ -52,6 +53,7 @@ mod vector;
 pub use alignment::Alignment;
 pub use angle::{Degrees, Radians};
 pub use animation::Animation;
+pub use backdrop::Backdrop;
 pub use background::Background;
 pub use border::Border;
 pub use clipboard::Clipboard;
@@ -2,9 +2,11 @@
 #[cfg(debug_assertions)]
 mod null;
 
+use crate::border;
 use crate::image;
 use crate::{
-    Background, Border, Color, Font, Pixels, Rectangle, Shadow, Size, Transformation, Vector,
+    Backdrop, Background, Border, Color, Font, Pixels, Rectangle, Shadow, Size, Transformation,
+    Vector,
 };
 
 /// Whether anti-aliasing should be avoided by snapping primitive coordinates to the
@@ -53,6 +55,18 @@ pub trait Renderer {
     /// Fills a [`Quad`] with the provided [`Background`].
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
+    /// Applies a [`Backdrop`] filter to anything drawn so far behind the
+    /// given `bounds`, rounded with the given border radius.
+    ///
+    /// By default, this does nothing.
+    fn draw_backdrop(
+        &mut self,
+        _bounds: Rectangle,
+        _border_radius: border::Radius,
+        _backdrop: Backdrop,
+    ) {
+    }
+
     /// Resets the [`Renderer`] to start drawing in the `new_bounds` from scratch.
     fn reset(&mut self, new_bounds: Rectangle);
 
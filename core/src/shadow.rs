@@ -11,4 +11,28 @@ pub struct Shadow {
 
     /// The blur radius of the shadow.
     pub blur_radius: f32,
+
+    /// Whether the shadow is cast inside of the element, instead of
+    /// below it.
+    pub inset: bool,
+}
+
+impl Shadow {
+    /// Creates a new [`Shadow`] cast below an element.
+    pub fn new(color: Color, offset: Vector, blur_radius: f32) -> Self {
+        Self {
+            color,
+            offset,
+            blur_radius,
+            inset: false,
+        }
+    }
+
+    /// Creates a new inset [`Shadow`]; cast inside of an element.
+    pub fn inset(color: Color, offset: Vector, blur_radius: f32) -> Self {
+        Self {
+            inset: true,
+            ..Self::new(color, offset, blur_radius)
+        }
+    }
 }
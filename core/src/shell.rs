@@ -1,14 +1,16 @@
 use crate::InputMethod;
 use crate::event;
+use crate::widget;
 use crate::window;
 
+use std::fmt;
+
 /// A connection to the state of a shell.
 ///
 /// A [`Widget`] can leverage a [`Shell`] to trigger changes in an application,
 /// like publishing messages or invalidating the current layout.
 ///
 /// [`Widget`]: crate::Widget
-#[derive(Debug)]
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     event_status: event::Status,
@@ -16,6 +18,7 @@ pub struct Shell<'a, Message> {
     input_method: InputMethod,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
+    operations: Vec<Box<dyn widget::Operation>>,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -28,6 +31,7 @@ impl<'a, Message> Shell<'a, Message> {
             is_layout_invalid: false,
             are_widgets_invalid: false,
             input_method: InputMethod::Disabled,
+            operations: Vec::new(),
         }
     }
 
@@ -144,6 +148,21 @@ impl<'a, Message> Shell<'a, Message> {
         self.are_widgets_invalid = true;
     }
 
+    /// Requests the given [`widget::Operation`] to be run on the whole widget
+    /// tree right after the current event has been processed.
+    ///
+    /// This is useful for widgets that need to affect the state of other
+    /// widgets; like moving the focus to a sibling.
+    pub fn request_operation(&mut self, operation: impl widget::Operation + 'static) {
+        self.operations.push(Box::new(operation));
+    }
+
+    /// Takes the [`widget::Operation`]s requested so far, leaving none behind.
+    #[must_use]
+    pub fn take_operations(&mut self) -> Vec<Box<dyn widget::Operation>> {
+        std::mem::take(&mut self.operations)
+    }
+
     /// Merges the current [`Shell`] with another one by applying the given
     /// function to the messages of the latter.
     ///
@@ -158,5 +177,23 @@ impl<'a, Message> Shell<'a, Message> {
         self.redraw_request = self.redraw_request.min(other.redraw_request);
         self.event_status = self.event_status.merge(other.event_status);
         self.input_method.merge(&other.input_method);
+        self.operations.extend(other.operations);
+    }
+}
+
+impl<Message> fmt::Debug for Shell<'_, Message>
+where
+    Message: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shell")
+            .field("messages", &self.messages)
+            .field("event_status", &self.event_status)
+            .field("redraw_request", &self.redraw_request)
+            .field("input_method", &self.input_method)
+            .field("is_layout_invalid", &self.is_layout_invalid)
+            .field("are_widgets_invalid", &self.are_widgets_invalid)
+            .field("operations", &self.operations.len())
+            .finish()
     }
 }
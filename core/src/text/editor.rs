@@ -4,6 +4,7 @@ use crate::text::{LineHeight, Wrapping};
 use crate::{Pixels, Point, Rectangle, Size};
 
 use std::borrow::Cow;
+use std::ops;
 use std::sync::Arc;
 
 /// A component that can be used by widgets to edit multi-line text.
@@ -18,11 +19,53 @@ pub trait Editor: Sized + Default {
     fn is_empty(&self) -> bool;
 
     /// Returns the current [`Cursor`] of the [`Editor`].
+    ///
+    /// If the [`Editor`] has multiple cursors, this is the primary one.
     fn cursor(&self) -> Cursor;
 
+    /// Returns all the current cursors of the [`Editor`], starting with
+    /// the primary one.
+    ///
+    /// By default, only the primary [`Cursor`] is returned.
+    fn cursors(&self) -> Vec<Cursor> {
+        vec![self.cursor()]
+    }
+
     /// Returns the current [`Selection`] of the [`Editor`].
+    ///
+    /// If the [`Editor`] has multiple cursors, this is the [`Selection`]
+    /// of the primary one.
     fn selection(&self) -> Selection;
 
+    /// Returns the current [`Selection`] of every cursor of the [`Editor`],
+    /// starting with the primary one.
+    ///
+    /// By default, only the primary [`Selection`] is returned.
+    fn selections(&self) -> Vec<Selection> {
+        vec![self.selection()]
+    }
+
+    /// Returns the visible regions covered by the text between the given
+    /// [`Position`]s, as they would be drawn in a [`Selection::Range`].
+    ///
+    /// By default, the regions are only known when the [`Position`]s match
+    /// the primary [`Selection`] of the [`Editor`].
+    fn range_bounds(&self, start: Position, end: Position) -> Vec<Rectangle> {
+        let cursor = self.cursor();
+
+        let Some(selection) = cursor.selection else {
+            return Vec::new();
+        };
+
+        let is_selected = (selection == start && cursor.position == end)
+            || (selection == end && cursor.position == start);
+
+        match self.selection() {
+            Selection::Range(ranges) if is_selected => ranges,
+            _ => Vec::new(),
+        }
+    }
+
     /// Returns the current selected text of the [`Editor`].
     fn copy(&self) -> Option<String>;
 
@@ -32,6 +75,14 @@ pub trait Editor: Sized + Default {
     /// Returns the amount of lines in the [`Editor`].
     fn line_count(&self) -> usize;
 
+    /// Returns the range of lines of the [`Editor`] that are currently
+    /// visible.
+    ///
+    /// By default, all the lines are considered visible.
+    fn visible_lines(&self) -> ops::Range<usize> {
+        0..self.line_count()
+    }
+
     /// Performs an [`Action`] on the [`Editor`].
     fn perform(&mut self, action: Action);
 
@@ -88,17 +139,64 @@ pub enum Action {
     Click(Point),
     /// Drag the mouse on the [`Editor`] to the given [`Point`].
     Drag(Point),
+    /// Add a new cursor at the given [`Point`], keeping the current ones.
+    ///
+    /// Any [`Motion`], [`Edit`], or word and line selection is applied to every
+    /// cursor. A [`Click`](Self::Click) collapses all the cursors into one.
+    AddCursor(Point),
+    /// Select the rectangular block of text between the given [`Point`]s,
+    /// placing a cursor on every line it spans.
+    SelectRectangle {
+        /// The [`Point`] where the selection starts.
+        from: Point,
+        /// The [`Point`] where the selection ends.
+        to: Point,
+    },
     /// Scroll the [`Editor`] a certain amount of lines.
     Scroll {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Undo the last edit.
+    ///
+    /// An [`Editor`] does not keep any history by itself; the owner
+    /// of the [`Editor`] is in charge of handling this [`Action`].
+    Undo,
+    /// Redo the last undone edit.
+    ///
+    /// An [`Editor`] does not keep any history by itself; the owner
+    /// of the [`Editor`] is in charge of handling this [`Action`].
+    Redo,
+    /// Select the next match of the current search.
+    ///
+    /// An [`Editor`] does not search by itself; the owner of the
+    /// [`Editor`] is in charge of handling this [`Action`].
+    FindNext,
+    /// Select the previous match of the current search.
+    ///
+    /// An [`Editor`] does not search by itself; the owner of the
+    /// [`Editor`] is in charge of handling this [`Action`].
+    FindPrevious,
+    /// Replace the selected match of the current search with the given
+    /// text and select the next one.
+    ///
+    /// An [`Editor`] does not search by itself; the owner of the
+    /// [`Editor`] is in charge of handling this [`Action`].
+    Replace(Arc<String>),
+    /// Replace every match of the current search with the given text.
+    ///
+    /// An [`Editor`] does not search by itself; the owner of the
+    /// [`Editor`] is in charge of handling this [`Action`].
+    ReplaceAll(Arc<String>),
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(
+            self,
+            Self::Edit(_) | Self::Undo | Self::Redo | Self::Replace(_) | Self::ReplaceAll(_)
+        )
     }
 }
 
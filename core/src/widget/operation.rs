@@ -1,8 +1,10 @@
 //! Query or update internal widget state.
+pub mod accessibility;
 pub mod focusable;
 pub mod scrollable;
 pub mod text_input;
 
+pub use accessibility::Accessible;
 pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
@@ -43,12 +45,34 @@ pub trait Operation<T = ()>: Send {
     /// Operates on a widget that can be focused.
     fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, _state: &mut dyn Focusable) {}
 
+    /// Operates on a widget that defines a [`focusable::Scope`] for its children.
+    ///
+    /// The `operate` closure traverses the children inside of the scope, like
+    /// in [`traverse`](Self::traverse). By default, they are traversed as any
+    /// other children.
+    fn focus_scope(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _scope: focusable::Scope,
+        operate: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.traverse(operate);
+    }
+
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _id: Option<&Id>, _bounds: Rectangle, _state: &mut dyn TextInput) {}
 
     /// Operates on a widget that contains some text.
     fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, _text: &str) {}
 
+    /// Operates on a widget that describes itself to assistive technologies.
+    ///
+    /// Widgets with children should call this method right before
+    /// [`traverse`](Self::traverse), so their children become part of
+    /// the same accessible node.
+    fn accessible(&mut self, _id: Option<&Id>, _bounds: Rectangle, _accessible: &Accessible<'_>) {}
+
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _id: Option<&Id>, _bounds: Rectangle, _state: &mut dyn Any) {}
 
@@ -74,6 +98,16 @@ where
         self.as_mut().focusable(id, bounds, state);
     }
 
+    fn focus_scope(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        scope: focusable::Scope,
+        operate: &mut dyn FnMut(&mut dyn Operation<O>),
+    ) {
+        self.as_mut().focus_scope(id, bounds, scope, operate);
+    }
+
     fn scrollable(
         &mut self,
         id: Option<&Id>,
@@ -94,6 +128,10 @@ where
         self.as_mut().text(id, bounds, text);
     }
 
+    fn accessible(&mut self, id: Option<&Id>, bounds: Rectangle, accessible: &Accessible<'_>) {
+        self.as_mut().accessible(id, bounds, accessible);
+    }
+
     fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
         self.as_mut().custom(id, bounds, state);
     }
@@ -155,6 +193,19 @@ where
             self.operation.focusable(id, bounds, state);
         }
 
+        fn focus_scope(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            scope: focusable::Scope,
+            operate: &mut dyn FnMut(&mut dyn Operation<O>),
+        ) {
+            self.operation
+                .focus_scope(id, bounds, scope, &mut |operation| {
+                    operate(&mut BlackBox { operation });
+                });
+        }
+
         fn scrollable(
             &mut self,
             id: Option<&Id>,
@@ -175,6 +226,10 @@ where
             self.operation.text(id, bounds, text);
         }
 
+        fn accessible(&mut self, id: Option<&Id>, bounds: Rectangle, accessible: &Accessible<'_>) {
+            self.operation.accessible(id, bounds, accessible);
+        }
+
         fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
             self.operation.custom(id, bounds, state);
         }
@@ -201,6 +256,69 @@ where
         f: Arc<dyn Fn(A) -> B + Send + Sync>,
     }
 
+    struct MapRef<'a, A> {
+        operation: &'a mut dyn Operation<A>,
+    }
+
+    impl<A, B> Operation<B> for MapRef<'_, A> {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<B>)) {
+            self.operation.traverse(&mut |operation| {
+                operate(&mut MapRef { operation });
+            });
+        }
+
+        fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            let Self { operation, .. } = self;
+
+            operation.container(id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            self.operation
+                .scrollable(id, bounds, content_bounds, translation, state);
+        }
+
+        fn focusable(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Focusable) {
+            self.operation.focusable(id, bounds, state);
+        }
+
+        fn focus_scope(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            scope: focusable::Scope,
+            operate: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            self.operation
+                .focus_scope(id, bounds, scope, &mut |operation| {
+                    operate(&mut MapRef { operation });
+                });
+        }
+
+        fn text_input(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn TextInput) {
+            self.operation.text_input(id, bounds, state);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn accessible(&mut self, id: Option<&Id>, bounds: Rectangle, accessible: &Accessible<'_>) {
+            self.operation.accessible(id, bounds, accessible);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
+            self.operation.custom(id, bounds, state);
+        }
+    }
+
     impl<O, A, B> Operation<B> for Map<O, A, B>
     where
         O: Operation<A>,
@@ -208,62 +326,6 @@ where
         B: 'static,
     {
         fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<B>)) {
-            struct MapRef<'a, A> {
-                operation: &'a mut dyn Operation<A>,
-            }
-
-            impl<A, B> Operation<B> for MapRef<'_, A> {
-                fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<B>)) {
-                    self.operation.traverse(&mut |operation| {
-                        operate(&mut MapRef { operation });
-                    });
-                }
-
-                fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
-                    let Self { operation, .. } = self;
-
-                    operation.container(id, bounds);
-                }
-
-                fn scrollable(
-                    &mut self,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    content_bounds: Rectangle,
-                    translation: Vector,
-                    state: &mut dyn Scrollable,
-                ) {
-                    self.operation
-                        .scrollable(id, bounds, content_bounds, translation, state);
-                }
-
-                fn focusable(
-                    &mut self,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    state: &mut dyn Focusable,
-                ) {
-                    self.operation.focusable(id, bounds, state);
-                }
-
-                fn text_input(
-                    &mut self,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    state: &mut dyn TextInput,
-                ) {
-                    self.operation.text_input(id, bounds, state);
-                }
-
-                fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
-                    self.operation.text(id, bounds, text);
-                }
-
-                fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
-                    self.operation.custom(id, bounds, state);
-                }
-            }
-
             self.operation.traverse(&mut |operation| {
                 operate(&mut MapRef { operation });
             });
@@ -277,6 +339,19 @@ where
             self.operation.focusable(id, bounds, state);
         }
 
+        fn focus_scope(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            scope: focusable::Scope,
+            operate: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            self.operation
+                .focus_scope(id, bounds, scope, &mut |operation| {
+                    operate(&mut MapRef { operation });
+                });
+        }
+
         fn scrollable(
             &mut self,
             id: Option<&Id>,
@@ -297,6 +372,10 @@ where
             self.operation.text(id, bounds, text);
         }
 
+        fn accessible(&mut self, id: Option<&Id>, bounds: Rectangle, accessible: &Accessible<'_>) {
+            self.operation.accessible(id, bounds, accessible);
+        }
+
         fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
             self.operation.custom(id, bounds, state);
         }
@@ -358,6 +437,19 @@ where
             self.operation.focusable(id, bounds, state);
         }
 
+        fn focus_scope(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            scope: focusable::Scope,
+            operate: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            self.operation
+                .focus_scope(id, bounds, scope, &mut |operation| {
+                    operate(&mut black_box(operation));
+                });
+        }
+
         fn scrollable(
             &mut self,
             id: Option<&Id>,
@@ -378,6 +470,10 @@ where
             self.operation.text(id, bounds, text);
         }
 
+        fn accessible(&mut self, id: Option<&Id>, bounds: Rectangle, accessible: &Accessible<'_>) {
+            self.operation.accessible(id, bounds, accessible);
+        }
+
         fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
             self.operation.custom(id, bounds, state);
         }
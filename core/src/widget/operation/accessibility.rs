@@ -0,0 +1,507 @@
+//! Describe widgets to assistive technologies.
+use crate::widget::Id;
+use crate::widget::operation::{Operation, Outcome, Scrollable};
+use crate::{Rectangle, Vector};
+
+use std::borrow::Cow;
+
+/// The accessibility information of a widget.
+///
+/// Widgets report an [`Accessible`] description of themselves through
+/// [`Operation::accessible`], which can then be gathered into a [`Tree`]
+/// with the [`tree`] operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Accessible<'a> {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+
+    /// The label of the widget, if any.
+    ///
+    /// If no label is provided, assistive technologies will normally
+    /// derive one from the contents of the widget.
+    pub label: Option<Cow<'a, str>>,
+
+    /// The current [`Value`] of the widget, if any.
+    pub value: Option<Value<'a>>,
+
+    /// The current [`State`] of the widget.
+    pub state: State,
+}
+
+impl<'a> Accessible<'a> {
+    /// Creates a new [`Accessible`] description with the given [`Role`].
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            value: None,
+            state: State::default(),
+        }
+    }
+
+    /// Sets the label of the [`Accessible`] description.
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the [`Value`] of the [`Accessible`] description.
+    pub fn value(mut self, value: impl Into<Value<'a>>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets whether the widget is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.state.disabled = disabled;
+        self
+    }
+
+    /// Sets whether the widget is focused.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.state.focused = focused;
+        self
+    }
+
+    /// Sets whether the widget is checked.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.state.checked = Some(checked);
+        self
+    }
+
+    /// Sets whether the widget is expanded.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.state.expanded = Some(expanded);
+        self
+    }
+
+    /// Sets whether the widget is selected.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.state.selected = Some(selected);
+        self
+    }
+
+    /// Turns the [`Accessible`] description into an owned one.
+    pub fn into_owned(self) -> Accessible<'static> {
+        Accessible {
+            role: self.role,
+            label: self.label.map(|label| Cow::Owned(label.into_owned())),
+            value: self.value.map(Value::into_owned),
+            state: self.state,
+        }
+    }
+}
+
+/// The semantic role of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Role {
+    /// A generic group of widgets.
+    Group,
+    /// A piece of static text.
+    Label,
+    /// An image.
+    Image,
+    /// A clickable button.
+    Button,
+    /// A checkbox.
+    CheckBox,
+    /// A radio button.
+    RadioButton,
+    /// An on/off switch.
+    Switch,
+    /// A slider that selects a value in a range.
+    Slider,
+    /// A progress indicator.
+    ProgressBar,
+    /// A single-line text field.
+    TextInput,
+    /// A multi-line text field.
+    MultilineTextInput,
+    /// A drop-down list of options.
+    ComboBox,
+    /// A list of options.
+    List,
+    /// An option of a [`Role::List`] or [`Role::ComboBox`].
+    ListItem,
+    /// A scrollable viewport.
+    ScrollView,
+    /// A tab list.
+    TabList,
+    /// A tab of a [`Role::TabList`].
+    Tab,
+    /// A menu bar.
+    MenuBar,
+    /// A menu.
+    Menu,
+    /// An item of a [`Role::Menu`].
+    MenuItem,
+    /// A hierarchical tree of items.
+    Tree,
+    /// An item of a [`Role::Tree`].
+    TreeItem,
+    /// A table of data.
+    Table,
+    /// A dialog or modal window.
+    Dialog,
+}
+
+/// The value of a widget.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Value<'a> {
+    /// A textual value.
+    Text(Cow<'a, str>),
+    /// A numeric value in a range.
+    Number {
+        /// The current value.
+        value: f64,
+        /// The minimum value.
+        min: f64,
+        /// The maximum value.
+        max: f64,
+        /// The step between values, if any.
+        step: Option<f64>,
+    },
+}
+
+impl Value<'_> {
+    /// Turns the [`Value`] into an owned one.
+    pub fn into_owned(self) -> Value<'static> {
+        match self {
+            Value::Text(text) => Value::Text(Cow::Owned(text.into_owned())),
+            Value::Number {
+                value,
+                min,
+                max,
+                step,
+            } => Value::Number {
+                value,
+                min,
+                max,
+                step,
+            },
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(text: &'a str) -> Self {
+        Value::Text(Cow::Borrowed(text))
+    }
+}
+
+impl From<String> for Value<'_> {
+    fn from(text: String) -> Self {
+        Value::Text(Cow::Owned(text))
+    }
+}
+
+/// The state of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Whether the widget is disabled.
+    pub disabled: bool,
+    /// Whether the widget is focused.
+    pub focused: bool,
+    /// Whether the widget is checked, if it can be checked.
+    pub checked: Option<bool>,
+    /// Whether the widget is expanded, if it can be expanded.
+    pub expanded: Option<bool>,
+    /// Whether the widget is selected, if it can be selected.
+    pub selected: Option<bool>,
+}
+
+/// A node of an accessibility [`Tree`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Node {
+    /// The [`Id`] of the widget, if any.
+    ///
+    /// Widget ids are only meaningful within a running application; so
+    /// they are never serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub id: Option<Id>,
+    /// The bounds of the widget.
+    pub bounds: Rectangle,
+    /// The [`Role`] of the widget.
+    pub role: Role,
+    /// The label of the widget, if any.
+    pub label: Option<String>,
+    /// The current [`Value`] of the widget, if any.
+    pub value: Option<Value<'static>>,
+    /// The current [`State`] of the widget.
+    pub state: State,
+    /// The children of the node.
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    fn new(id: Option<&Id>, bounds: Rectangle, accessible: &Accessible<'_>) -> Self {
+        let accessible = accessible.clone().into_owned();
+
+        Self {
+            id: id.cloned(),
+            bounds,
+            role: accessible.role,
+            label: accessible.label.map(Cow::into_owned),
+            value: accessible.value,
+            state: accessible.state,
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns an iterator over the node and all of its descendants, in
+    /// depth-first order.
+    pub fn iter(&self) -> impl Iterator<Item = &Node> {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+
+            Some(node)
+        })
+    }
+}
+
+/// The accessibility tree of a user interface.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tree {
+    /// The root nodes of the [`Tree`].
+    pub nodes: Vec<Node>,
+}
+
+impl Tree {
+    /// Returns an iterator over all the nodes of the [`Tree`], in
+    /// depth-first order.
+    pub fn iter(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.iter().flat_map(Node::iter)
+    }
+
+    /// Finds the [`Node`] of the widget with the given [`Id`], if any.
+    pub fn find(&self, id: &Id) -> Option<&Node> {
+        self.iter().find(|node| node.id.as_ref() == Some(id))
+    }
+}
+
+/// Produces an [`Operation`] that gathers the accessibility [`Tree`] of
+/// the widget tree.
+///
+/// Any text reported through [`Operation::text`] becomes a [`Role::Label`]
+/// node. Widgets that only report themselves as containers are transparent;
+/// their children are attached to the closest accessible ancestor.
+pub fn tree() -> impl Operation<Tree> {
+    struct Builder {
+        levels: Vec<Vec<Node>>,
+        last: Option<usize>,
+        translation: Vector,
+        scroll: Vector,
+    }
+
+    impl Builder {
+        fn level(&mut self) -> &mut Vec<Node> {
+            self.levels.last_mut().expect("Accessibility tree level")
+        }
+    }
+
+    impl Operation<Tree> for Builder {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Tree>)) {
+            let parent = self.last.take();
+            let translation = self.translation;
+
+            self.translation = translation - std::mem::take(&mut self.scroll);
+            self.levels.push(Vec::new());
+
+            operate(self);
+
+            let children = self.levels.pop().unwrap_or_default();
+            self.last = None;
+            self.translation = translation;
+
+            match parent.and_then(|index| self.level().get_mut(index)) {
+                Some(node) => {
+                    if node.label.is_none() {
+                        let text: Vec<_> = children
+                            .iter()
+                            .filter(|child| child.role == Role::Label)
+                            .filter_map(|child| child.label.as_deref())
+                            .collect();
+
+                        if !text.is_empty() {
+                            node.label = Some(text.join(" "));
+                        }
+                    }
+
+                    node.children.extend(children);
+                }
+                None => {
+                    self.level().extend(children);
+                }
+            }
+        }
+
+        fn container(&mut self, _id: Option<&Id>, _bounds: Rectangle) {
+            self.last = None;
+            self.scroll = Vector::ZERO;
+        }
+
+        fn scrollable(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+            _state: &mut dyn Scrollable,
+        ) {
+            self.last = None;
+            self.scroll = translation;
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            let bounds = bounds + self.translation;
+            let last = self.last.take();
+            let level = self.level();
+
+            // Widgets may report their own label as text right after
+            // describing themselves; avoid duplicating it.
+            if last
+                .and_then(|index| level.get(index))
+                .is_some_and(|node| node.bounds == bounds)
+            {
+                return;
+            }
+
+            level.push(Node::new(
+                id,
+                bounds,
+                &Accessible::new(Role::Label).label(text),
+            ));
+        }
+
+        fn accessible(&mut self, id: Option<&Id>, bounds: Rectangle, accessible: &Accessible<'_>) {
+            let bounds = bounds + self.translation;
+            let level = self.level();
+
+            level.push(Node::new(id, bounds, accessible));
+
+            self.last = Some(level.len() - 1);
+        }
+
+        fn finish(&self) -> Outcome<Tree> {
+            Outcome::Some(Tree {
+                nodes: self.levels.first().cloned().unwrap_or_default(),
+            })
+        }
+    }
+
+    Builder {
+        levels: vec![Vec::new()],
+        last: None,
+        translation: Vector::ZERO,
+        scroll: Vector::ZERO,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::widget::operation::scrollable::{AbsoluteOffset, RelativeOffset};
+
+    #[test]
+    fn tree_nests_children_and_derives_labels() {
+        let mut operation = tree();
+        let bounds = Rectangle::with_size(crate::Size::new(100.0, 20.0));
+
+        operation.container(None, bounds);
+        operation.traverse(&mut |operation| {
+            operation.container(None, bounds);
+            operation.accessible(None, bounds, &Accessible::new(Role::Button));
+            operation.traverse(&mut |operation| {
+                operation.text(None, bounds, "Submit");
+            });
+
+            let checkbox = Accessible::new(Role::CheckBox)
+                .label("Remember me")
+                .checked(true);
+
+            operation.accessible(None, bounds, &checkbox);
+            operation.text(None, bounds, "Remember me");
+        });
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("Accessibility tree must be produced");
+        };
+
+        assert_eq!(tree.nodes.len(), 2);
+
+        let button = &tree.nodes[0];
+        assert_eq!(button.role, Role::Button);
+        assert_eq!(button.label.as_deref(), Some("Submit"));
+        assert_eq!(button.children.len(), 1);
+        assert_eq!(button.children[0].role, Role::Label);
+
+        let checkbox = &tree.nodes[1];
+        assert_eq!(checkbox.role, Role::CheckBox);
+        assert_eq!(checkbox.state.checked, Some(true));
+        assert!(checkbox.children.is_empty());
+    }
+
+    #[test]
+    fn scrolled_nodes_keep_their_values_and_ids() {
+        struct Offset;
+
+        impl Scrollable for Offset {
+            fn snap_to(&mut self, _offset: RelativeOffset<Option<f32>>) {}
+
+            fn scroll_to(&mut self, _offset: AbsoluteOffset<Option<f32>>) {}
+
+            fn scroll_by(
+                &mut self,
+                _offset: AbsoluteOffset,
+                _bounds: Rectangle,
+                _content_bounds: Rectangle,
+            ) {
+            }
+        }
+
+        let mut operation = tree();
+        let bounds = Rectangle::with_size(crate::Size::new(100.0, 20.0));
+
+        operation.scrollable(None, bounds, bounds, Vector::new(0.0, 30.0), &mut Offset);
+        operation.traverse(&mut |operation| {
+            let name = Accessible::new(Role::TextInput).value("Ferris");
+
+            let volume = Accessible::new(Role::Slider).value(Value::Number {
+                value: 5.0,
+                min: 0.0,
+                max: 10.0,
+                step: None,
+            });
+
+            operation.accessible(Some(&Id::new("name")), bounds, &name);
+            operation.accessible(None, bounds + Vector::new(0.0, 40.0), &volume);
+        });
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("Accessibility tree must be produced");
+        };
+
+        let name = tree
+            .find(&Id::new("name"))
+            .expect("Text input must be found");
+        assert_eq!(name.value, Some(Value::Text("Ferris".into())));
+        assert_eq!(name.bounds.y, -30.0);
+
+        let volume = &tree.nodes[1];
+        assert_eq!(volume.role, Role::Slider);
+        assert_eq!(volume.bounds.y, 10.0);
+        assert!(matches!(
+            volume.value,
+            Some(Value::Number { value: 5.0, .. })
+        ));
+    }
+}
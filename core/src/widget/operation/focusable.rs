@@ -3,6 +3,8 @@ use crate::Rectangle;
 use crate::widget::Id;
 use crate::widget::operation::{self, Operation, Outcome};
 
+use std::ops::Range;
+
 /// The internal state of a widget that can be focused.
 pub trait Focusable {
     /// Returns whether the widget is focused or not.
@@ -15,6 +17,43 @@ pub trait Focusable {
     fn unfocus(&mut self);
 }
 
+/// A group of focusable widgets that changes how the focus moves among them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Scope {
+    /// The tab index of the focusable widgets inside the [`Scope`].
+    ///
+    /// Widgets with a positive tab index are focused first, in ascending
+    /// order, followed by the widgets with a tab index of zero in traversal
+    /// order. Widgets with a negative tab index are skipped by [`focus_next`]
+    /// and [`focus_previous`], but they can still be focused with [`focus`].
+    ///
+    /// If `None`, the tab index of the parent [`Scope`] is used; or zero,
+    /// if there is none.
+    pub tab_index: Option<i32>,
+
+    /// Whether the [`Scope`] traps the focus.
+    ///
+    /// While a trap is present in a widget tree, [`focus_next`] and
+    /// [`focus_previous`] cycle through the widgets of the last trap only.
+    pub is_trap: bool,
+}
+
+/// A summary of the focus traps present on a widget tree.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Traps {
+    /// The total amount of [`Scope`]s trapping the focus.
+    pub total: usize,
+
+    /// Whether any widget is focused.
+    pub is_focused: bool,
+
+    /// The [`Id`] of the focused widget, if any.
+    pub focused: Option<Id>,
+
+    /// Whether the focused widget is inside of the last trap.
+    pub is_trapped: bool,
+}
+
 /// A summary of the focusable widgets present on a widget tree.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Count {
@@ -101,60 +140,191 @@ pub fn count() -> impl Operation<Count> {
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the previous focusable widget.
 /// - if not found, focuses the last focusable widget.
+///
+/// The tab index and the traps of any [`Scope`] are taken into account.
 pub fn focus_previous<T>() -> impl Operation<T>
 where
     T: Send + 'static,
 {
-    struct FocusPrevious {
-        count: Count,
-        current: usize,
+    operation::then(order(), |order| apply(order.previous()))
+}
+
+/// Produces an [`Operation`] that searches for the current focused widget, and
+/// - if found, focuses the next focusable widget.
+/// - if not found, focuses the first focusable widget.
+///
+/// The tab index and the traps of any [`Scope`] are taken into account.
+pub fn focus_next<T>() -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    operation::then(order(), |order| apply(order.next()))
+}
+
+/// Produces an [`Operation`] that generates a summary of the focus [`Traps`]
+/// of a widget tree.
+pub fn traps() -> impl Operation<Traps> {
+    operation::map(order(), |order| Traps {
+        total: order.traps.len(),
+        is_focused: order.focused.is_some(),
+        focused: order.focused_id.clone(),
+        is_trapped: order.focused.is_some_and(|focused| {
+            order
+                .traps
+                .last()
+                .is_some_and(|trap| trap.contains(&focused))
+        }),
+    })
+}
+
+/// Produces an [`Operation`] that searches for the current focused widget
+/// and returns its [`Id`]; or `None` if no widget is focused or the focused
+/// widget has no [`Id`].
+pub fn focused() -> impl Operation<Option<Id>> {
+    operation::map(order(), |order| order.focused_id.clone())
+}
+
+/// The focus order of a widget tree.
+#[derive(Debug, Clone, Default)]
+struct Order {
+    tab_indices: Vec<i32>,
+    focused: Option<usize>,
+    focused_id: Option<Id>,
+    traps: Vec<Range<usize>>,
+}
+
+impl Order {
+    /// Returns the focusable widgets that can be reached in order, and whether
+    /// they are trapped.
+    fn sequence(&self) -> (Vec<usize>, bool) {
+        let range = self
+            .traps
+            .last()
+            .cloned()
+            .unwrap_or(0..self.tab_indices.len());
+
+        let mut sequence: Vec<usize> = range
+            .clone()
+            .filter(|index| self.tab_indices[*index] >= 0)
+            .collect();
+
+        sequence.sort_by_key(|index| match self.tab_indices[*index] {
+            0 => i32::MAX,
+            tab_index => tab_index,
+        });
+
+        (sequence, !self.traps.is_empty())
     }
 
-    impl<T> Operation<T> for FocusPrevious {
-        fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
-            if self.count.total == 0 {
-                return;
-            }
+    fn next(&self) -> Option<usize> {
+        let (sequence, is_trapped) = self.sequence();
+
+        let position = self
+            .focused
+            .and_then(|focused| sequence.iter().position(|index| *index == focused));
+
+        match position {
+            None => sequence.first().copied(),
+            Some(position) if position + 1 < sequence.len() => Some(sequence[position + 1]),
+            Some(_) if is_trapped => sequence.first().copied(),
+            Some(_) => None,
+        }
+    }
+
+    fn previous(&self) -> Option<usize> {
+        let (sequence, is_trapped) = self.sequence();
+
+        let position = self
+            .focused
+            .and_then(|focused| sequence.iter().position(|index| *index == focused));
+
+        match position {
+            None => sequence.last().copied(),
+            Some(0) if is_trapped => sequence.last().copied(),
+            Some(0) => None,
+            Some(position) => Some(sequence[position - 1]),
+        }
+    }
+}
+
+/// Produces an [`Operation`] that computes the focus [`Order`] of a widget tree.
+fn order() -> impl Operation<Order> {
+    struct Collect {
+        order: Order,
+        scopes: Vec<(Scope, Option<usize>)>,
+    }
+
+    impl Operation<Order> for Collect {
+        fn focusable(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
+            let index = self.order.tab_indices.len();
 
-            match self.count.focused {
-                None if self.current == self.count.total - 1 => state.focus(),
-                Some(0) if self.current == 0 => state.unfocus(),
-                Some(0) => {}
-                Some(focused) if focused == self.current => state.unfocus(),
-                Some(focused) if focused - 1 == self.current => state.focus(),
-                _ => {}
+            if state.is_focused() {
+                self.order.focused = Some(index);
+                self.order.focused_id = id.cloned();
             }
 
-            self.current += 1;
+            let tab_index = self
+                .scopes
+                .iter()
+                .rev()
+                .find_map(|(scope, _)| scope.tab_index)
+                .unwrap_or(0);
+
+            self.order.tab_indices.push(tab_index);
         }
 
-        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        fn focus_scope(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            scope: Scope,
+            operate: &mut dyn FnMut(&mut dyn Operation<Order>),
+        ) {
+            let trap = scope.is_trap.then(|| {
+                let start = self.order.tab_indices.len();
+                self.order.traps.push(start..start);
+
+                self.order.traps.len() - 1
+            });
+
+            self.scopes.push((scope, trap));
+
+            operate(self);
+
+            if let Some((_, Some(trap))) = self.scopes.pop() {
+                self.order.traps[trap].end = self.order.tab_indices.len();
+            }
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Order>)) {
             operate(self);
         }
+
+        fn finish(&self) -> Outcome<Order> {
+            Outcome::Some(self.order.clone())
+        }
     }
 
-    operation::then(count(), |count| FocusPrevious { count, current: 0 })
+    Collect {
+        order: Order::default(),
+        scopes: Vec::new(),
+    }
 }
 
-/// Produces an [`Operation`] that searches for the current focused widget, and
-/// - if found, focuses the next focusable widget.
-/// - if not found, focuses the first focusable widget.
-pub fn focus_next<T>() -> impl Operation<T>
-where
-    T: Send + 'static,
-{
-    struct FocusNext {
-        count: Count,
+/// Produces an [`Operation`] that focuses the focusable widget with the given
+/// index in traversal order, unfocusing any other.
+fn apply<T>(target: Option<usize>) -> impl Operation<T> {
+    struct Apply {
+        target: Option<usize>,
         current: usize,
     }
 
-    impl<T> Operation<T> for FocusNext {
+    impl<T> Operation<T> for Apply {
         fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
-            match self.count.focused {
-                None if self.current == 0 => state.focus(),
-                Some(focused) if focused == self.current => state.unfocus(),
-                Some(focused) if focused + 1 == self.current => state.focus(),
-                _ => {}
+            if self.target == Some(self.current) {
+                state.focus();
+            } else if state.is_focused() {
+                state.unfocus();
             }
 
             self.current += 1;
@@ -165,7 +335,7 @@ where
         }
     }
 
-    operation::then(count(), |count| FocusNext { count, current: 0 })
+    Apply { target, current: 0 }
 }
 
 /// Produces an [`Operation`] that searches for the current focused widget
@@ -232,3 +402,99 @@ pub fn is_focused(target: Id) -> impl Operation<bool> {
         is_focused: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_follows_tab_indices_and_traps() {
+        let order = Order {
+            tab_indices: vec![0, 2, -1, 1, 0],
+            focused: None,
+            focused_id: None,
+            traps: Vec::new(),
+        };
+
+        assert_eq!(order.sequence(), (vec![3, 1, 0, 4], false));
+        assert_eq!(order.next(), Some(3));
+        assert_eq!(order.previous(), Some(4));
+
+        let order = Order {
+            focused: Some(4),
+            ..order
+        };
+
+        assert_eq!(order.next(), None);
+        assert_eq!(order.previous(), Some(0));
+
+        let order = Order {
+            traps: vec![0..5, 3..5],
+            ..order
+        };
+
+        assert_eq!(order.next(), Some(3));
+        assert_eq!(order.previous(), Some(3));
+    }
+
+    #[test]
+    fn scopes_only_apply_to_the_widgets_inside_of_them() {
+        struct State(bool);
+
+        impl Focusable for State {
+            fn is_focused(&self) -> bool {
+                self.0
+            }
+
+            fn focus(&mut self) {
+                self.0 = true;
+            }
+
+            fn unfocus(&mut self) {
+                self.0 = false;
+            }
+        }
+
+        let trap = Scope {
+            tab_index: None,
+            is_trap: true,
+        };
+
+        let bounds = Rectangle::default();
+        let mut operation = traps();
+
+        operation.focusable(None, bounds, &mut State(false));
+        operation.focus_scope(None, bounds, trap, &mut |operation| {
+            operation.focusable(None, bounds, &mut State(true));
+        });
+        operation.focusable(None, bounds, &mut State(false));
+
+        let Outcome::Some(traps) = operation.finish() else {
+            panic!("Traps must be summarized");
+        };
+
+        assert_eq!(
+            traps,
+            Traps {
+                total: 1,
+                is_focused: true,
+                focused: None,
+                is_trapped: true,
+            }
+        );
+
+        let mut operation = order();
+
+        operation.focus_scope(None, bounds, trap, &mut |operation| {
+            operation.focusable(None, bounds, &mut State(false));
+        });
+        operation.focusable(None, bounds, &mut State(true));
+
+        let Outcome::Some(order) = operation.finish() else {
+            panic!("Order must be computed");
+        };
+
+        assert_eq!(order.traps, vec![0..1]);
+        assert_eq!(order.focused, Some(1));
+    }
+}
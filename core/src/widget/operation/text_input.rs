@@ -23,6 +23,11 @@ pub trait TextInput {
     fn select_all(&mut self);
     /// Selects the given content range of the text input.
     fn select_range(&mut self, start: usize, end: usize);
+
+    /// Clears the undo and redo history of the text input.
+    ///
+    /// By default, this does nothing.
+    fn clear_history(&mut self) {}
 }
 
 /// Produces an [`Operation`] that moves the cursor of the widget with the given [`Id`] to the
@@ -150,3 +155,28 @@ pub fn select_range<T>(target: Id, start: usize, end: usize) -> impl Operation<T
 
     SelectRange { target, start, end }
 }
+
+/// Produces an [`Operation`] that clears the undo and redo history of the widget with the given
+/// [`Id`].
+pub fn clear_history<T>(target: Id) -> impl Operation<T> {
+    struct ClearHistory {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for ClearHistory {
+        fn text_input(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn TextInput) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.clear_history();
+                }
+                _ => {}
+            }
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+    }
+
+    ClearHistory { target }
+}
@@ -25,6 +25,7 @@ enum Message {
     ShadowXOffsetChanged(f32),
     ShadowYOffsetChanged(f32),
     ShadowBlurRadiusChanged(f32),
+    ShadowInsetToggled(bool),
     SnapToggled(bool),
 }
 
@@ -33,11 +34,11 @@ impl Example {
         Self {
             radius: border::radius(50),
             border_width: 0.0,
-            shadow: Shadow {
-                color: Color::from_rgba(0.0, 0.0, 0.0, 0.8),
-                offset: Vector::new(0.0, 8.0),
-                blur_radius: 16.0,
-            },
+            shadow: Shadow::new(
+                Color::from_rgba(0.0, 0.0, 0.0, 0.8),
+                Vector::new(0.0, 8.0),
+                16.0,
+            ),
             snap: false,
         }
     }
@@ -68,6 +69,9 @@ impl Example {
             Message::ShadowBlurRadiusChanged(s) => {
                 self.shadow.blur_radius = s;
             }
+            Message::ShadowInsetToggled(inset) => {
+                self.shadow.inset = inset;
+            }
             Message::SnapToggled(snap) => {
                 self.snap = snap;
             }
@@ -106,6 +110,9 @@ impl Example {
             slider(-100.0..=100.0, sx, Message::ShadowXOffsetChanged).step(0.01),
             slider(-100.0..=100.0, sy, Message::ShadowYOffsetChanged).step(0.01),
             slider(0.0..=100.0, sr, Message::ShadowBlurRadiusChanged).step(0.01),
+            toggler(self.shadow.inset)
+                .label("Inset shadow")
+                .on_toggle(Message::ShadowInsetToggled),
             toggler(self.snap)
                 .label("Snap to pixel grid")
                 .on_toggle(Message::SnapToggled),
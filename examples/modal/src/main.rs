@@ -2,8 +2,8 @@ use iced::event::{self, Event};
 use iced::keyboard;
 use iced::keyboard::key;
 use iced::widget::{
-    button, center, column, container, mouse_area, opaque, operation, pick_list, row, space, stack,
-    text, text_input,
+    button, center, column, container, focus_scope, mouse_area, opaque, operation, pick_list, row,
+    space, stack, text, text_input,
 };
 use iced::{Bottom, Color, Element, Fill, Subscription, Task};
 
@@ -43,7 +43,7 @@ impl App {
         match message {
             Message::ShowModal => {
                 self.show_modal = true;
-                operation::focus_next()
+                Task::none()
             }
             Message::HideModal => {
                 self.hide_modal();
@@ -96,7 +96,11 @@ impl App {
         let content = container(
             column![
                 row![text("Top Left"), space::horizontal(), text("Top Right")].height(Fill),
-                center(button(text("Show Modal")).on_press(Message::ShowModal)),
+                center(
+                    button(text("Show Modal"))
+                        .id("show_modal")
+                        .on_press(Message::ShowModal)
+                ),
                 row![
                     text("Bottom Left"),
                     space::horizontal(),
@@ -195,18 +199,20 @@ where
     stack![
         base.into(),
         opaque(
-            mouse_area(center(opaque(content)).style(|_theme| {
-                container::Style {
-                    background: Some(
-                        Color {
-                            a: 0.8,
-                            ..Color::BLACK
-                        }
-                        .into(),
-                    ),
-                    ..container::Style::default()
-                }
-            }))
+            mouse_area(
+                center(opaque(focus_scope(content).trap(true))).style(|_theme| {
+                    container::Style {
+                        background: Some(
+                            Color {
+                                a: 0.8,
+                                ..Color::BLACK
+                            }
+                            .into(),
+                        ),
+                        ..container::Style::default()
+                    }
+                })
+            )
             .on_press(on_blur)
         )
     ]
@@ -71,6 +71,7 @@ impl Example {
                 }
             }
             Message::Clicked(pane) => {
+                self.panes.activate(pane);
                 self.focus = Some(pane);
             }
             Message::Resized(pane_grid::ResizeEvent { split, ratio }) => {
@@ -131,18 +132,36 @@ impl Example {
                 .on_press(Message::TogglePin(id))
                 .padding(3);
 
-            let title = row![
-                pin_button,
-                "Pane",
-                text(pane.id.to_string()).color(if is_focused {
-                    PANE_ID_COLOR_FOCUSED
-                } else {
-                    PANE_ID_COLOR_UNFOCUSED
-                }),
-            ]
-            .spacing(5);
+            let title_bar = if let Some(tabs) = self.panes.tabs(id) {
+                pane_grid::TitleBar::tabs(tabs.iter().filter_map(|&tab| {
+                    let pane = self.panes.get(tab)?;
+
+                    Some((
+                        tab,
+                        container(text!("Pane {}", pane.id).color(if tab == id {
+                            PANE_ID_COLOR_FOCUSED
+                        } else {
+                            PANE_ID_COLOR_UNFOCUSED
+                        }))
+                        .padding([0, 10]),
+                    ))
+                }))
+            } else {
+                let title = row![
+                    pin_button,
+                    "Pane",
+                    text(pane.id.to_string()).color(if is_focused {
+                        PANE_ID_COLOR_FOCUSED
+                    } else {
+                        PANE_ID_COLOR_UNFOCUSED
+                    }),
+                ]
+                .spacing(5);
+
+                pane_grid::TitleBar::new(title)
+            };
 
-            let title_bar = pane_grid::TitleBar::new(title)
+            let title_bar = title_bar
                 .controls(pane_grid::Controls::dynamic(
                     view_controls(id, total_panes, pane.is_pinned, is_maximized),
                     button(text("X").size(14))
@@ -174,6 +193,7 @@ impl Example {
         .width(Fill)
         .height(Fill)
         .spacing(10)
+        .stacking(true)
         .on_click(Message::Clicked)
         .on_drag(Message::Dragged)
         .on_resize(10, Message::Resized);
@@ -3,7 +3,7 @@ use iced::time::{Duration, hours, minutes};
 use iced::widget::{
     center_x, center_y, column, container, row, scrollable, slider, table, text, tooltip,
 };
-use iced::{Center, Element, Fill, Font, Right, Theme};
+use iced::{Center, Element, Fill, Font, Length, Right, Shrink, Theme};
 
 pub fn main() -> iced::Result {
     iced::application(Table::new, Table::update, Table::view)
@@ -15,12 +15,28 @@ struct Table {
     events: Vec<Event>,
     padding: (f32, f32),
     separator: (f32, f32),
+    sort: Option<(Field, table::Direction)>,
+    widths: [Option<f32>; 4],
+    selection: Vec<usize>,
+    anchor: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
 enum Message {
     PaddingChanged(f32, f32),
     SeparatorChanged(f32, f32),
+    Sort(Field, table::Direction),
+    Resize(Field, f32),
+    Select(usize),
+    SelectRange(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Name,
+    Time,
+    Price,
+    Rating,
 }
 
 impl Table {
@@ -29,6 +45,10 @@ impl Table {
             events: Event::list(),
             padding: (10.0, 5.0),
             separator: (1.0, 1.0),
+            sort: None,
+            widths: [None; 4],
+            selection: Vec::new(),
+            anchor: None,
         }
     }
 
@@ -36,6 +56,37 @@ impl Table {
         match message {
             Message::PaddingChanged(x, y) => self.padding = (x, y),
             Message::SeparatorChanged(x, y) => self.separator = (x, y),
+            Message::Sort(field, direction) => {
+                self.events.sort_by(|a, b| {
+                    let ordering = match field {
+                        Field::Name => a.name.cmp(&b.name),
+                        Field::Time => a.duration.cmp(&b.duration),
+                        Field::Price => a.price.total_cmp(&b.price),
+                        Field::Rating => a.rating.total_cmp(&b.rating),
+                    };
+
+                    match direction {
+                        table::Direction::Ascending => ordering,
+                        table::Direction::Descending => ordering.reverse(),
+                    }
+                });
+
+                self.sort = Some((field, direction));
+                self.selection.clear();
+                self.anchor = None;
+            }
+            Message::Resize(field, width) => {
+                self.widths[field as usize] = Some(width);
+            }
+            Message::Select(row) => {
+                self.selection = vec![row];
+                self.anchor = Some(row);
+            }
+            Message::SelectRange(row) => {
+                let anchor = self.anchor.unwrap_or(row);
+
+                self.selection = (anchor.min(row)..=anchor.max(row)).collect();
+            }
         }
     }
 
@@ -87,11 +138,29 @@ impl Table {
                 .align_y(Center),
             ];
 
-            table(columns, &self.events)
-                .padding_x(self.padding.0)
-                .padding_y(self.padding.1)
-                .separator_x(self.separator.0)
-                .separator_y(self.separator.1)
+            let fields = [Field::Name, Field::Time, Field::Price, Field::Rating];
+
+            table(
+                columns.into_iter().zip(fields).map(|(column, field)| {
+                    column
+                        .width(self.widths[field as usize].map_or(Shrink, Length::Fixed))
+                        .sorted(
+                            self.sort
+                                .filter(|(sorted, _)| *sorted == field)
+                                .map(|(_, direction)| direction),
+                        )
+                        .on_sort(move |direction| Message::Sort(field, direction))
+                        .on_resize(move |width| Message::Resize(field, width))
+                }),
+                &self.events,
+            )
+            .on_row_click(Message::Select)
+            .on_row_shift_click(Message::SelectRange)
+            .selected(self.selection.iter().copied())
+            .padding_x(self.padding.0)
+            .padding_y(self.padding.1)
+            .separator_x(self.separator.0)
+            .separator_y(self.separator.1)
         };
 
         let controls = {
@@ -3,20 +3,27 @@
 //! For a gradient that you can use as a background variant for a widget, see [`Gradient`].
 use crate::color;
 use crate::core::gradient::ColorStop;
-use crate::core::{self, Color, Point, Rectangle};
+use crate::core::{self, Color, Point, Radians, Rectangle, Size};
 
 use bytemuck::{Pod, Zeroable};
 use half::f16;
 use std::cmp::Ordering;
+use std::f32::consts::{FRAC_PI_2, TAU};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which linearly interpolates colors along a direction.
+/// A fill which interpolates colors along a direction, outwards from a center, or around
+/// a center.
 ///
 /// For a gradient which can be used as a fill for a background of a widget, see [`crate::core::Gradient`].
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction from its `start` to its `end`
     /// point.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from its `center` up to its `radii`.
+    Radial(Radial),
+    /// A conic gradient interpolates colors clockwise around its `center`, starting at its
+    /// `angle`.
+    Conic(Conic),
 }
 
 impl From<Linear> for Gradient {
@@ -25,11 +32,34 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 impl Gradient {
+    /// Returns the [`ColorStop`]s of the [`Gradient`].
+    pub fn stops(&self) -> &[Option<ColorStop>; 8] {
+        match self {
+            Gradient::Linear(linear) => &linear.stops,
+            Gradient::Radial(radial) => &radial.stops,
+            Gradient::Conic(conic) => &conic.stops,
+        }
+    }
+
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
         match self {
             Gradient::Linear(linear) => linear.pack(),
+            Gradient::Radial(radial) => radial.pack(),
+            Gradient::Conic(conic) => conic.pack(),
         }
     }
 }
@@ -63,22 +93,73 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) = self.stops.binary_search_by(|stop| match stop {
-                None => Ordering::Greater,
-                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-            });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
 
         self
     }
 
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        let (colors, offsets) = pack_stops(&self.stops);
+
+        Packed {
+            colors,
+            offsets,
+            direction: [self.start.x, self.start.y, self.end.x, self.end.y],
+            kind: Kind::Linear as u32,
+        }
+    }
+}
+
+/// A radial gradient.
+///
+/// When drawn in a transformed `Frame`, the `iced_wgpu` renderer keeps the
+/// radii of a [`Radial`] gradient aligned with the axes. Translations and
+/// scales are applied exactly, and so are rotations of circular gradients;
+/// any other transform is approximated with the bounding ellipse of the
+/// transformed gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The absolute center of the gradient.
+    pub center: Point,
+
+    /// The horizontal and vertical radii of the gradient.
+    pub radii: Size,
+
+    /// [`ColorStop`]s along the radial gradient direction, from the center outwards.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] builder.
+    pub fn new(center: Point, radii: impl Into<Size>) -> Self {
+        Self {
+            center,
+            radii: radii.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+        self
+    }
+
     /// Adds multiple [`ColorStop`]s to the gradient.
     ///
     /// Any stop added after the 8th will be silently ignored.
@@ -92,33 +173,88 @@ impl Linear {
 
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
-        let mut colors = [[0u32; 2]; 8];
-        let mut offsets = [f16::from(0u8); 8];
+        let (colors, offsets) = pack_stops(&self.stops);
+
+        Packed {
+            colors,
+            offsets,
+            direction: [
+                self.center.x,
+                self.center.y,
+                self.radii.width,
+                self.radii.height,
+            ],
+            kind: Kind::Radial as u32,
+        }
+    }
+}
+
+/// A conic gradient.
+///
+/// Translations, rotations, and uniform scales are applied exactly to a
+/// [`Conic`] gradient drawn in a transformed `Frame`. Any other transform
+/// is approximated by moving its center and rotating its starting angle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The absolute center of the gradient.
+    pub center: Point,
 
-        for (index, stop) in self.stops.iter().enumerate() {
-            let [r, g, b, a] = color::pack(stop.map_or(Color::default(), |s| s.color)).components();
+    /// The angle where the gradient starts; where `0` points up and the
+    /// gradient progresses clockwise.
+    pub angle: Radians,
 
-            colors[index] = [
-                pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-            ];
+    /// [`ColorStop`]s along the conic gradient direction.
+    pub stops: [Option<ColorStop>; 8],
+}
 
-            offsets[index] = stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
+impl Conic {
+    /// Creates a new [`Conic`] builder.
+    pub fn new(center: Point, angle: impl Into<Radians>) -> Self {
+        Self {
+            center,
+            angle: angle.into(),
+            stops: [None; 8],
         }
+    }
 
-        let offsets = [
-            pack_f16s([offsets[0], offsets[1]]),
-            pack_f16s([offsets[2], offsets[3]]),
-            pack_f16s([offsets[4], offsets[5]]),
-            pack_f16s([offsets[6], offsets[7]]),
-        ];
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+        self
+    }
 
-        let direction = [self.start.x, self.start.y, self.end.x, self.end.y];
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Returns the offset of the [`Conic`] gradient at the given point.
+    pub fn offset_at(&self, point: Point) -> f32 {
+        let direction = point - self.center;
+        let angle = direction.y.atan2(direction.x) + FRAC_PI_2 - self.angle.0;
+
+        (angle / TAU).rem_euclid(1.0)
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        let (colors, offsets) = pack_stops(&self.stops);
 
         Packed {
             colors,
             offsets,
-            direction,
+            direction: [self.center.x, self.center.y, self.angle.0, 0.0],
+            kind: Kind::Conic as u32,
         }
     }
 }
@@ -131,46 +267,99 @@ pub struct Packed {
     colors: [[u32; 2]; 8],
     // 8 offsets, 8x 16 bit floats packed into 4 u32s
     offsets: [u32; 4],
+    // Linear: start & end, Radial: center & radii, Conic: center & angle
     direction: [f32; 4],
+    kind: u32,
+}
+
+/// The kind of a [`Packed`] gradient, as understood by shader code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+enum Kind {
+    Linear = 0,
+    Radial = 1,
+    Conic = 2,
 }
 
 /// Creates a new [`Packed`] gradient for use in shader code.
 pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
+    resolve(gradient, bounds).pack()
+}
+
+/// Resolves a [`core::Gradient`] inside of the given bounds into an absolute [`Gradient`].
+pub fn resolve(gradient: &core::Gradient, bounds: Rectangle) -> Gradient {
     match gradient {
         core::Gradient::Linear(linear) => {
-            let mut colors = [[0u32; 2]; 8];
-            let mut offsets = [f16::from(0u8); 8];
+            let (start, end) = linear.angle.to_distance(&bounds);
 
-            for (index, stop) in linear.stops.iter().enumerate() {
-                let [r, g, b, a] =
-                    color::pack(stop.map_or(Color::default(), |s| s.color)).components();
+            Gradient::Linear(Linear {
+                start,
+                end,
+                stops: linear.stops,
+            })
+        }
+        core::Gradient::Radial(radial) => Gradient::Radial(Radial {
+            center: relative_to(radial.center, bounds),
+            radii: Size::new(
+                radial.radii.width * bounds.width,
+                radial.radii.height * bounds.height,
+            ),
+            stops: radial.stops,
+        }),
+        core::Gradient::Conic(conic) => Gradient::Conic(Conic {
+            center: relative_to(conic.center, bounds),
+            angle: conic.angle,
+            stops: conic.stops,
+        }),
+    }
+}
 
-                colors[index] = [
-                    pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                    pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-                ];
+/// Converts a point relative to the given bounds into an absolute one.
+fn relative_to(point: Point, bounds: Rectangle) -> Point {
+    Point::new(
+        bounds.x + point.x * bounds.width,
+        bounds.y + point.y * bounds.height,
+    )
+}
 
-                offsets[index] = stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
-            }
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) = stops.binary_search_by(|stop| match stop {
+            None => Ordering::Greater,
+            Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+        });
 
-            let offsets = [
-                pack_f16s([offsets[0], offsets[1]]),
-                pack_f16s([offsets[2], offsets[3]]),
-                pack_f16s([offsets[4], offsets[5]]),
-                pack_f16s([offsets[6], offsets[7]]),
-            ];
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
+    };
+}
 
-            let (start, end) = linear.angle.to_distance(&bounds);
+fn pack_stops(stops: &[Option<ColorStop>; 8]) -> ([[u32; 2]; 8], [u32; 4]) {
+    let mut colors = [[0u32; 2]; 8];
+    let mut offsets = [f16::from(0u8); 8];
 
-            let direction = [start.x, start.y, end.x, end.y];
+    for (index, stop) in stops.iter().enumerate() {
+        let [r, g, b, a] = color::pack(stop.map_or(Color::default(), |s| s.color)).components();
 
-            Packed {
-                colors,
-                offsets,
-                direction,
-            }
-        }
+        colors[index] = [
+            pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
+            pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
+        ];
+
+        offsets[index] = stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
     }
+
+    let offsets = [
+        pack_f16s([offsets[0], offsets[1]]),
+        pack_f16s([offsets[2], offsets[3]]),
+        pack_f16s([offsets[4], offsets[5]]),
+        pack_f16s([offsets[6], offsets[7]]),
+    ];
+
+    (colors, offsets)
 }
 
 /// Packs two f16s into one u32.
@@ -180,3 +369,43 @@ fn pack_f16s(f: [f16; 2]) -> u32 {
 
     one | two
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::PI;
+
+    #[test]
+    fn packed_gradients_keep_their_kind_and_direction() {
+        // The shaders read the packed gradient as raw vertex data
+        assert_eq!(std::mem::size_of::<Packed>(), 100);
+
+        let linear = Linear::new(Point::new(1.0, 2.0), Point::new(3.0, 4.0)).pack();
+        assert_eq!(linear.kind, 0);
+        assert_eq!(linear.direction, [1.0, 2.0, 3.0, 4.0]);
+
+        let radial = Radial::new(Point::new(1.0, 2.0), Size::new(3.0, 4.0)).pack();
+        assert_eq!(radial.kind, 1);
+        assert_eq!(radial.direction, [1.0, 2.0, 3.0, 4.0]);
+
+        let conic = Conic::new(Point::new(1.0, 2.0), Radians(PI)).pack();
+        assert_eq!(conic.kind, 2);
+        assert_eq!(conic.direction, [1.0, 2.0, PI, 0.0]);
+    }
+
+    #[test]
+    fn conic_offsets_start_at_the_angle_and_go_clockwise() {
+        let conic = Conic::new(Point::ORIGIN, Radians(0.0));
+
+        let offsets = [(0.0, -1.0), (1.0, 0.0), (0.0, 1.0), (-1.0, 0.0)]
+            .map(|(x, y)| conic.offset_at(Point::new(x, y)));
+
+        assert_eq!(offsets, [0.0, 0.25, 0.5, 0.75]);
+
+        let rotated = Conic::new(Point::new(10.0, 10.0), Radians(PI / 2.0));
+
+        assert_eq!(rotated.offset_at(Point::new(20.0, 10.0)), 0.0);
+        assert_eq!(rotated.offset_at(Point::new(10.0, 0.0)), 0.75);
+    }
+}
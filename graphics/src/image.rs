@@ -33,6 +33,13 @@ impl Image {
             Image::Vector { svg, bounds, .. } => bounds.rotate(svg.rotation),
         }
     }
+
+    /// Returns the clip bounds of the [`Image`].
+    pub fn clip_bounds(&self) -> Rectangle {
+        match self {
+            Image::Raster { clip_bounds, .. } | Image::Vector { clip_bounds, .. } => *clip_bounds,
+        }
+    }
 }
 
 /// An image buffer.
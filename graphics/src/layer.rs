@@ -1,5 +1,5 @@
 //! Draw and stack layers of graphical primitives.
-use crate::core::{Rectangle, Transformation};
+use crate::core::{self, Rectangle, Transformation, border};
 
 /// A layer of graphical primitives.
 ///
@@ -41,6 +41,28 @@ pub trait Layer: Default {
 
     /// Merges a [`Layer`] with the current one.
     fn merge(&mut self, _layer: &mut Self);
+
+    /// Records a [`Backdrop`] in the [`Layer`].
+    ///
+    /// Backdrops must be applied before drawing any other primitives of
+    /// the [`Layer`].
+    ///
+    /// By default, backdrops are ignored.
+    fn draw_backdrop(&mut self, _backdrop: Backdrop) {}
+}
+
+/// A filter applied to whatever has been drawn behind some bounds before
+/// a [`Layer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backdrop {
+    /// The bounds of the [`Backdrop`], already transformed.
+    pub bounds: Rectangle,
+    /// The border radius of the [`Backdrop`], already transformed.
+    pub border_radius: [f32; 4],
+    /// The blur radius of the [`Backdrop`], already transformed.
+    pub blur_radius: f32,
+    /// The saturation of the [`Backdrop`].
+    pub saturation: f32,
 }
 
 /// A stack of layers used for drawing.
@@ -106,6 +128,46 @@ impl<T: Layer> Stack<T> {
         self.current = self.previous.pop().unwrap();
     }
 
+    /// Starts a new [`Layer`] with the same clipping region as the current one.
+    ///
+    /// Anything drawn afterwards will be drawn on top of anything drawn
+    /// before, regardless of the kind of primitives involved.
+    pub fn split(&mut self) {
+        self.flush();
+
+        let bounds = self.layers[self.current].bounds();
+
+        self.current = self.active_count;
+        self.active_count += 1;
+
+        if self.current == self.layers.len() {
+            self.layers.push(T::with_bounds(bounds));
+        } else {
+            self.layers[self.current].resize(bounds);
+        }
+    }
+
+    /// Applies a [`core::Backdrop`] to anything drawn so far behind the given
+    /// bounds; starting a new [`Layer`] in the process.
+    pub fn draw_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: border::Radius,
+        backdrop: core::Backdrop,
+    ) {
+        self.split();
+
+        let transformation = self.transformation();
+        let scale_factor = transformation.scale_factor();
+
+        self.layers[self.current].draw_backdrop(Backdrop {
+            bounds: bounds * transformation,
+            border_radius: (border_radius * scale_factor).into(),
+            blur_radius: backdrop.blur_radius * scale_factor,
+            saturation: backdrop.saturation,
+        });
+    }
+
     /// Pushes a new [`Transformation`] in the [`Stack`].
     ///
     /// Future drawing operations will be affected by this new [`Transformation`] until
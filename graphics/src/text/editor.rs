@@ -11,6 +11,7 @@ use cosmic_text::Edit as _;
 
 use std::borrow::Cow;
 use std::fmt;
+use std::ops;
 use std::sync::{self, Arc, RwLock};
 
 /// A multi-line text editor.
@@ -19,6 +20,7 @@ pub struct Editor(Option<Arc<Internal>>);
 
 struct Internal {
     editor: cosmic_text::Editor<'static>,
+    carets: Vec<Caret>,
     selection: RwLock<Option<Selection>>,
     font: Font,
     bounds: Size,
@@ -113,6 +115,17 @@ impl editor::Editor for Editor {
         buffer.lines.is_empty() || (buffer.lines.len() == 1 && buffer.lines[0].text().is_empty())
     }
 
+    fn range_bounds(&self, start: Position, end: Position) -> Vec<Rectangle> {
+        let internal = self.internal();
+
+        regions(
+            buffer_from_editor(&internal.editor),
+            (start.line, start.column),
+            (end.line, end.column),
+            internal.hint_factor,
+        )
+    }
+
     fn line(&self, index: usize) -> Option<editor::Line<'_>> {
         self.buffer().lines.get(index).map(|line| editor::Line {
             text: Cow::Borrowed(line.text()),
@@ -130,8 +143,86 @@ impl editor::Editor for Editor {
         self.buffer().lines.len()
     }
 
+    fn visible_lines(&self) -> ops::Range<usize> {
+        let mut lines = self.buffer().layout_runs().map(|run| run.line_i);
+
+        let Some(first) = lines.next() else {
+            return 0..0;
+        };
+
+        first..lines.last().unwrap_or(first) + 1
+    }
+
     fn copy(&self) -> Option<String> {
-        self.internal().editor.copy_selection()
+        let internal = self.internal();
+
+        if internal.carets.is_empty() {
+            return internal.editor.copy_selection();
+        }
+
+        let buffer = buffer_from_editor(&internal.editor);
+
+        let mut carets: Vec<_> = std::iter::once(Caret::from_editor(&internal.editor))
+            .chain(internal.carets.iter().copied())
+            .filter(|caret| caret.anchor.is_some())
+            .collect();
+
+        if carets.is_empty() {
+            return None;
+        }
+
+        carets.sort_by_key(Caret::start);
+
+        Some(
+            carets
+                .iter()
+                .map(|caret| {
+                    let (start, end) = caret.bounds();
+
+                    text_between(buffer, start, end)
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    fn cursors(&self) -> Vec<Cursor> {
+        let internal = self.internal();
+
+        std::iter::once(self.cursor())
+            .chain(internal.carets.iter().map(|caret| Cursor {
+                position: Position {
+                    line: caret.cursor.line,
+                    column: caret.cursor.index,
+                },
+                selection: caret.anchor.map(|anchor| Position {
+                    line: anchor.line,
+                    column: anchor.index,
+                }),
+            }))
+            .collect()
+    }
+
+    fn selections(&self) -> Vec<Selection> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        std::iter::once(self.selection())
+            .chain(internal.carets.iter().filter_map(|caret| {
+                if caret.anchor.is_some() {
+                    let (start, end) = caret.bounds();
+
+                    Some(Selection::Range(regions(
+                        buffer,
+                        start,
+                        end,
+                        internal.hint_factor,
+                    )))
+                } else {
+                    caret_position(buffer, caret.cursor, internal.hint_factor).map(Selection::Caret)
+                }
+            }))
+            .collect()
     }
 
     fn selection(&self) -> editor::Selection {
@@ -146,110 +237,19 @@ impl editor::Editor for Editor {
 
         let cursor = match internal.editor.selection_bounds() {
             Some((start, end)) => {
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
-
-                let visual_lines_offset = visual_lines_offset(start.line, buffer);
-
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(
-                                Rectangle {
-                                    x,
-                                    width,
-                                    y: (visual_line as i32 + visual_lines_offset) as f32
-                                        * line_height
-                                        - buffer.scroll().vertical,
-                                    height: line_height,
-                                } * (1.0 / internal.hint_factor),
-                            )
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+                let regions = regions(
+                    buffer,
+                    (start.line, start.index),
+                    (end.line, end.index),
+                    internal.hint_factor,
+                );
 
                 Selection::Range(regions)
             }
-            _ => {
-                let line_height = buffer.metrics().line_height;
-
-                let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
-
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
-
-                let layout = line.layout_opt().expect("Line layout should be cached");
-
-                let mut lines = layout.iter().enumerate();
-
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
-                        let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
-
-                        let is_cursor_before_start = start > cursor.index;
-
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => cursor.index <= end,
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
-
-                Selection::Caret(Point::new(
-                    offset / internal.hint_factor,
-                    ((visual_lines_offset + visual_line as i32) as f32 * line_height
-                        - buffer.scroll().vertical)
-                        / internal.hint_factor,
-                ))
-            }
+            _ => Selection::Caret(
+                caret_position(buffer, cursor, internal.hint_factor)
+                    .expect("Line layout should be cached"),
+            ),
         };
 
         *internal.selection.write().expect("Write to cursor cache") = Some(cursor.clone());
@@ -289,178 +289,27 @@ impl editor::Editor for Editor {
         let mut font_system = text::font_system().write().expect("Write font system");
 
         self.with_internal_mut(|internal| {
-            let editor = &mut internal.editor;
-
-            match action {
-                // Motion events
-                Action::Move(motion) => {
-                    if let Some((start, end)) = editor.selection_bounds() {
-                        editor.set_selection(cosmic_text::Selection::None);
-
-                        match motion {
-                            // These motions are performed as-is even when a selection
-                            // is present
-                            Motion::Home
-                            | Motion::End
-                            | Motion::DocumentStart
-                            | Motion::DocumentEnd => {
-                                editor.action(
-                                    font_system.raw(),
-                                    cosmic_text::Action::Motion(to_motion(motion)),
-                                );
-                            }
-                            // Other motions simply move the cursor to one end of the selection
-                            _ => editor.set_cursor(match motion.direction() {
-                                Direction::Left => start,
-                                Direction::Right => end,
-                            }),
-                        }
-                    } else {
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Motion(to_motion(motion)),
-                        );
-                    }
-                }
-
-                // Selection events
-                Action::Select(motion) => {
-                    let cursor = editor.cursor();
-
-                    if editor.selection_bounds().is_none() {
-                        editor.set_selection(cosmic_text::Selection::Normal(cursor));
-                    }
-
-                    editor.action(
-                        font_system.raw(),
-                        cosmic_text::Action::Motion(to_motion(motion)),
-                    );
-
-                    // Deselect if selection matches cursor position
-                    if let Some((start, end)) = editor.selection_bounds()
-                        && start.line == end.line
-                        && start.index == end.index
-                    {
-                        editor.set_selection(cosmic_text::Selection::None);
-                    }
-                }
-                Action::SelectWord => {
-                    let cursor = editor.cursor();
-
-                    editor.set_selection(cosmic_text::Selection::Word(cursor));
-                }
-                Action::SelectLine => {
-                    let cursor = editor.cursor();
-
-                    editor.set_selection(cosmic_text::Selection::Line(cursor));
-                }
-                Action::SelectAll => {
-                    let buffer = buffer_from_editor(editor);
-
-                    if buffer.lines.len() > 1
-                        || buffer
-                            .lines
-                            .first()
-                            .is_some_and(|line| !line.text().is_empty())
-                    {
-                        let cursor = editor.cursor();
-
-                        editor.set_selection(cosmic_text::Selection::Normal(cosmic_text::Cursor {
-                            line: 0,
-                            index: 0,
-                            ..cursor
-                        }));
-
-                        editor.action(
-                            font_system.raw(),
-                            cosmic_text::Action::Motion(cosmic_text::Motion::BufferEnd),
-                        );
-                    }
-                }
-
-                // Editing events
-                Action::Edit(edit) => {
-                    let topmost_line_before_edit = editor
-                        .selection_bounds()
-                        .map(|(start, _)| start)
-                        .unwrap_or_else(|| editor.cursor())
-                        .line;
-
-                    match edit {
-                        Edit::Insert(c) => {
-                            editor.action(font_system.raw(), cosmic_text::Action::Insert(c));
-                        }
-                        Edit::Paste(text) => {
-                            editor.insert_string(&text, None);
-                        }
-                        Edit::Indent => {
-                            editor.action(font_system.raw(), cosmic_text::Action::Indent);
-                        }
-                        Edit::Unindent => {
-                            editor.action(font_system.raw(), cosmic_text::Action::Unindent);
-                        }
-                        Edit::Enter => {
-                            editor.action(font_system.raw(), cosmic_text::Action::Enter);
-                        }
-                        Edit::Backspace => {
-                            editor.action(font_system.raw(), cosmic_text::Action::Backspace);
-                        }
-                        Edit::Delete => {
-                            editor.action(font_system.raw(), cosmic_text::Action::Delete);
-                        }
-                    }
-
-                    let cursor = editor.cursor();
-                    let selection_start = editor
-                        .selection_bounds()
-                        .map(|(start, _)| start)
-                        .unwrap_or(cursor);
-
-                    internal.topmost_line_changed =
-                        Some(selection_start.line.min(topmost_line_before_edit));
-                }
-
-                // Mouse events
-                Action::Click(position) => {
-                    editor.action(
-                        font_system.raw(),
-                        cosmic_text::Action::Click {
-                            x: (position.x * internal.hint_factor) as i32,
-                            y: (position.y * internal.hint_factor) as i32,
-                        },
-                    );
-                }
-                Action::Drag(position) => {
-                    editor.action(
-                        font_system.raw(),
-                        cosmic_text::Action::Drag {
-                            x: (position.x * internal.hint_factor) as i32,
-                            y: (position.y * internal.hint_factor) as i32,
-                        },
-                    );
-
-                    // Deselect if selection matches cursor position
-                    if let Some((start, end)) = editor.selection_bounds()
-                        && start.line == end.line
-                        && start.index == end.index
-                    {
-                        editor.set_selection(cosmic_text::Selection::None);
-                    }
-                }
-                Action::Scroll { lines } => {
-                    editor.action(
-                        font_system.raw(),
-                        cosmic_text::Action::Scroll {
-                            pixels: lines as f32 * buffer_from_editor(editor).metrics().line_height,
-                        },
-                    );
-                }
+            let applies_to_each = matches!(
+                action,
+                Action::Move(_)
+                    | Action::Select(_)
+                    | Action::SelectWord
+                    | Action::SelectLine
+                    | Action::Edit(_)
+            );
+
+            if applies_to_each && !internal.carets.is_empty() {
+                perform_each(internal, font_system.raw(), action);
+            } else {
+                perform_one(internal, font_system.raw(), action);
             }
         });
     }
 
     fn move_to(&mut self, cursor: Cursor) {
         self.with_internal_mut(|internal| {
+            internal.carets.clear();
+
             // TODO: Expose `Affinity`
             internal.editor.set_cursor(cosmic_text::Cursor {
                 line: cursor.position.line,
@@ -719,6 +568,7 @@ impl Default for Internal {
                     line_height: 1.0,
                 },
             )),
+            carets: Vec::new(),
             selection: RwLock::new(None),
             font: Font::default(),
             bounds: Size::ZERO,
@@ -763,6 +613,636 @@ impl PartialEq for Weak {
     }
 }
 
+/// Performs an [`Action`] on the primary cursor of the [`Editor`].
+fn perform_one(internal: &mut Internal, font_system: &mut cosmic_text::FontSystem, action: Action) {
+    let editor = &mut internal.editor;
+
+    match action {
+        // Motion events
+        Action::Move(motion) => {
+            if let Some((start, end)) = editor.selection_bounds() {
+                editor.set_selection(cosmic_text::Selection::None);
+
+                match motion {
+                    // These motions are performed as-is even when a selection
+                    // is present
+                    Motion::Home | Motion::End | Motion::DocumentStart | Motion::DocumentEnd => {
+                        editor.action(font_system, cosmic_text::Action::Motion(to_motion(motion)));
+                    }
+                    // Other motions simply move the cursor to one end of the selection
+                    _ => editor.set_cursor(match motion.direction() {
+                        Direction::Left => start,
+                        Direction::Right => end,
+                    }),
+                }
+            } else {
+                editor.action(font_system, cosmic_text::Action::Motion(to_motion(motion)));
+            }
+        }
+
+        // Selection events
+        Action::Select(motion) => {
+            let cursor = editor.cursor();
+
+            if editor.selection_bounds().is_none() {
+                editor.set_selection(cosmic_text::Selection::Normal(cursor));
+            }
+
+            editor.action(font_system, cosmic_text::Action::Motion(to_motion(motion)));
+
+            // Deselect if selection matches cursor position
+            if let Some((start, end)) = editor.selection_bounds()
+                && start.line == end.line
+                && start.index == end.index
+            {
+                editor.set_selection(cosmic_text::Selection::None);
+            }
+        }
+        Action::SelectWord => {
+            let cursor = editor.cursor();
+
+            editor.set_selection(cosmic_text::Selection::Word(cursor));
+        }
+        Action::SelectLine => {
+            let cursor = editor.cursor();
+
+            editor.set_selection(cosmic_text::Selection::Line(cursor));
+        }
+        Action::SelectAll => {
+            internal.carets.clear();
+
+            let buffer = buffer_from_editor(editor);
+
+            if buffer.lines.len() > 1
+                || buffer
+                    .lines
+                    .first()
+                    .is_some_and(|line| !line.text().is_empty())
+            {
+                let cursor = editor.cursor();
+
+                editor.set_selection(cosmic_text::Selection::Normal(cosmic_text::Cursor {
+                    line: 0,
+                    index: 0,
+                    ..cursor
+                }));
+
+                editor.action(
+                    font_system,
+                    cosmic_text::Action::Motion(cosmic_text::Motion::BufferEnd),
+                );
+            }
+        }
+
+        // Editing events
+        Action::Edit(edit) => {
+            let topmost_line_before_edit = editor
+                .selection_bounds()
+                .map(|(start, _)| start)
+                .unwrap_or_else(|| editor.cursor())
+                .line;
+
+            match edit {
+                Edit::Insert(c) => {
+                    editor.action(font_system, cosmic_text::Action::Insert(c));
+                }
+                Edit::Paste(text) => {
+                    editor.insert_string(&text, None);
+                }
+                Edit::Indent => {
+                    editor.action(font_system, cosmic_text::Action::Indent);
+                }
+                Edit::Unindent => {
+                    editor.action(font_system, cosmic_text::Action::Unindent);
+                }
+                Edit::Enter => {
+                    editor.action(font_system, cosmic_text::Action::Enter);
+                }
+                Edit::Backspace => {
+                    editor.action(font_system, cosmic_text::Action::Backspace);
+                }
+                Edit::Delete => {
+                    editor.action(font_system, cosmic_text::Action::Delete);
+                }
+            }
+
+            let cursor = editor.cursor();
+            let selection_start = editor
+                .selection_bounds()
+                .map(|(start, _)| start)
+                .unwrap_or(cursor);
+
+            let topmost_line_changed = selection_start.line.min(topmost_line_before_edit);
+
+            internal.topmost_line_changed = Some(
+                internal
+                    .topmost_line_changed
+                    .map_or(topmost_line_changed, |line| line.min(topmost_line_changed)),
+            );
+        }
+
+        // Mouse events
+        Action::Click(position) => {
+            internal.carets.clear();
+
+            editor.action(
+                font_system,
+                cosmic_text::Action::Click {
+                    x: (position.x * internal.hint_factor) as i32,
+                    y: (position.y * internal.hint_factor) as i32,
+                },
+            );
+        }
+        Action::Drag(position) => {
+            internal.carets.clear();
+
+            editor.action(
+                font_system,
+                cosmic_text::Action::Drag {
+                    x: (position.x * internal.hint_factor) as i32,
+                    y: (position.y * internal.hint_factor) as i32,
+                },
+            );
+
+            // Deselect if selection matches cursor position
+            if let Some((start, end)) = editor.selection_bounds()
+                && start.line == end.line
+                && start.index == end.index
+            {
+                editor.set_selection(cosmic_text::Selection::None);
+            }
+        }
+        Action::AddCursor(position) => {
+            let buffer = buffer_from_editor(editor);
+
+            if let Some(cursor) = buffer.hit(
+                position.x * internal.hint_factor,
+                position.y * internal.hint_factor,
+            ) {
+                let primary = Caret::from_editor(editor);
+
+                if primary.cursor != cursor {
+                    internal.carets.retain(|caret| caret.cursor != cursor);
+                    internal.carets.push(primary);
+
+                    Caret {
+                        cursor,
+                        anchor: None,
+                    }
+                    .apply(editor);
+                }
+            }
+        }
+        Action::SelectRectangle { from, to } => {
+            let buffer = buffer_from_editor(editor);
+
+            let from = Point::new(from.x * internal.hint_factor, from.y * internal.hint_factor);
+            let to = Point::new(to.x * internal.hint_factor, to.y * internal.hint_factor);
+
+            let top = from.y.min(to.y);
+            let bottom = from.y.max(to.y);
+
+            let mut carets: Vec<Caret> = buffer
+                .layout_runs()
+                .filter(|run| run.line_top + run.line_height > top && run.line_top <= bottom)
+                .map(|run| {
+                    let cursor = cosmic_text::Cursor::new(run.line_i, run_index_at(&run, to.x));
+                    let anchor = cosmic_text::Cursor::new(run.line_i, run_index_at(&run, from.x));
+
+                    Caret {
+                        cursor,
+                        anchor: (anchor != cursor).then_some(anchor),
+                    }
+                })
+                .collect();
+
+            // The primary cursor follows the mouse
+            let primary = if to.y < from.y {
+                (!carets.is_empty()).then(|| carets.remove(0))
+            } else {
+                carets.pop()
+            };
+
+            if let Some(primary) = primary {
+                primary.apply(editor);
+                internal.carets = carets;
+            }
+        }
+        Action::Scroll { lines } => {
+            editor.action(
+                font_system,
+                cosmic_text::Action::Scroll {
+                    pixels: lines as f32 * buffer_from_editor(editor).metrics().line_height,
+                },
+            );
+        }
+        Action::Undo
+        | Action::Redo
+        | Action::FindNext
+        | Action::FindPrevious
+        | Action::Replace(_)
+        | Action::ReplaceAll(_) => {}
+    }
+}
+
+/// Performs an [`Action`] on every cursor of the [`Editor`].
+///
+/// Cursors are processed from the bottom of the buffer to the top, so every
+/// edit only shifts the cursors that have already been processed. These are
+/// tracked relative to the end of the buffer, which edits before them do not
+/// change.
+fn perform_each(
+    internal: &mut Internal,
+    font_system: &mut cosmic_text::FontSystem,
+    action: Action,
+) {
+    let carets: Vec<Caret> = std::iter::once(Caret::from_editor(&internal.editor))
+        .chain(internal.carets.drain(..))
+        .collect();
+
+    let mut order: Vec<usize> = (0..carets.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(carets[i].end()));
+
+    // Pasting as many lines as cursors distributes them
+    let lines: Option<Vec<String>> = match &action {
+        Action::Edit(Edit::Paste(text)) => {
+            let lines: Vec<_> = text.lines().map(str::to_owned).collect();
+
+            (lines.len() == carets.len()).then_some(lines)
+        }
+        _ => None,
+    };
+
+    let mut anchored = vec![(FromEnd::default(), None); carets.len()];
+
+    for (n, &i) in order.iter().enumerate() {
+        let caret = carets[i];
+
+        match action {
+            Action::Move(motion) | Action::Select(motion) => {
+                let buffer = buffer_mut_from_editor(&mut internal.editor);
+
+                caret
+                    .moved(
+                        buffer,
+                        font_system,
+                        motion,
+                        matches!(action, Action::Select(_)),
+                    )
+                    .apply(&mut internal.editor);
+            }
+            _ => {
+                caret.apply(&mut internal.editor);
+
+                let action = match &lines {
+                    Some(lines) => {
+                        Action::Edit(Edit::Paste(Arc::new(lines[carets.len() - 1 - n].clone())))
+                    }
+                    None => action.clone(),
+                };
+
+                perform_one(internal, font_system, action);
+            }
+        }
+
+        let caret = Caret::from_editor(&internal.editor);
+        let buffer = buffer_from_editor(&internal.editor);
+
+        anchored[i] = (
+            FromEnd::new(buffer, caret.cursor),
+            caret.anchor.map(|anchor| FromEnd::new(buffer, anchor)),
+        );
+    }
+
+    let buffer = buffer_from_editor(&internal.editor);
+
+    let mut carets: Vec<Caret> = anchored
+        .into_iter()
+        .map(|(cursor, anchor)| {
+            let cursor = cursor.resolve(buffer);
+            let anchor = anchor
+                .map(|anchor| anchor.resolve(buffer))
+                .filter(|anchor| *anchor != cursor);
+
+            Caret { cursor, anchor }
+        })
+        .collect();
+
+    let primary = carets.remove(0);
+    primary.apply(&mut internal.editor);
+
+    // Cursors may collapse into each other (e.g. after a backspace)
+    let mut seen = vec![primary.cursor];
+
+    carets.retain(|caret| {
+        if seen.contains(&caret.cursor) {
+            false
+        } else {
+            seen.push(caret.cursor);
+            true
+        }
+    });
+
+    internal.carets = carets;
+}
+
+/// A cursor of an [`Editor`] with an optional selection anchor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Caret {
+    cursor: cosmic_text::Cursor,
+    anchor: Option<cosmic_text::Cursor>,
+}
+
+impl Caret {
+    fn from_editor(editor: &cosmic_text::Editor<'_>) -> Self {
+        let cursor = editor.cursor();
+
+        match editor.selection() {
+            cosmic_text::Selection::None => Self {
+                cursor,
+                anchor: None,
+            },
+            cosmic_text::Selection::Normal(anchor) => Self {
+                cursor,
+                anchor: (anchor != cursor).then_some(anchor),
+            },
+            cosmic_text::Selection::Line(_) | cosmic_text::Selection::Word(_) => {
+                match editor.selection_bounds() {
+                    Some((start, end)) => Self {
+                        cursor: end,
+                        anchor: Some(start),
+                    },
+                    None => Self {
+                        cursor,
+                        anchor: None,
+                    },
+                }
+            }
+        }
+    }
+
+    fn apply(self, editor: &mut cosmic_text::Editor<'_>) {
+        editor.set_cursor(self.cursor);
+        editor.set_selection(
+            self.anchor
+                .map_or(cosmic_text::Selection::None, cosmic_text::Selection::Normal),
+        );
+    }
+
+    fn moved(
+        self,
+        buffer: &mut cosmic_text::Buffer,
+        font_system: &mut cosmic_text::FontSystem,
+        motion: Motion,
+        is_selecting: bool,
+    ) -> Self {
+        if !is_selecting && self.anchor.is_some() {
+            let (start, end) = self.bounds();
+
+            match motion {
+                Motion::Home | Motion::End | Motion::DocumentStart | Motion::DocumentEnd => {}
+                _ => {
+                    let (line, index) = match motion.direction() {
+                        Direction::Left => start,
+                        Direction::Right => end,
+                    };
+
+                    return Self {
+                        cursor: cosmic_text::Cursor::new(line, index),
+                        anchor: None,
+                    };
+                }
+            }
+        }
+
+        // Every cursor keeps its own horizontal position, so we cannot
+        // rely on the one remembered by the `cosmic_text::Editor`
+        let cursor = buffer
+            .cursor_motion(font_system, self.cursor, None, to_motion(motion))
+            .map(|(cursor, _)| cursor)
+            .unwrap_or(self.cursor);
+
+        let anchor = if is_selecting {
+            self.anchor.or(Some(self.cursor))
+        } else {
+            None
+        };
+
+        Self {
+            cursor,
+            anchor: anchor.filter(|anchor| *anchor != cursor),
+        }
+    }
+
+    fn start(&self) -> (usize, usize) {
+        self.bounds().0
+    }
+
+    fn end(&self) -> (usize, usize) {
+        self.bounds().1
+    }
+
+    fn bounds(&self) -> ((usize, usize), (usize, usize)) {
+        let cursor = (self.cursor.line, self.cursor.index);
+
+        match self.anchor {
+            Some(anchor) => {
+                let anchor = (anchor.line, anchor.index);
+
+                (cursor.min(anchor), cursor.max(anchor))
+            }
+            None => (cursor, cursor),
+        }
+    }
+}
+
+/// A position in a buffer relative to its end.
+#[derive(Debug, Clone, Copy, Default)]
+struct FromEnd {
+    lines: usize,
+    bytes: usize,
+}
+
+impl FromEnd {
+    fn new(buffer: &cosmic_text::Buffer, cursor: cosmic_text::Cursor) -> Self {
+        let line_length = buffer
+            .lines
+            .get(cursor.line)
+            .map(|line| line.text().len())
+            .unwrap_or(0);
+
+        Self {
+            lines: buffer.lines.len().saturating_sub(cursor.line + 1),
+            bytes: line_length.saturating_sub(cursor.index),
+        }
+    }
+
+    fn resolve(self, buffer: &cosmic_text::Buffer) -> cosmic_text::Cursor {
+        let line = buffer.lines.len().saturating_sub(self.lines + 1);
+
+        let line_length = buffer
+            .lines
+            .get(line)
+            .map(|line| line.text().len())
+            .unwrap_or(0);
+
+        cosmic_text::Cursor::new(line, line_length.saturating_sub(self.bytes))
+    }
+}
+
+fn caret_position(
+    buffer: &cosmic_text::Buffer,
+    cursor: cosmic_text::Cursor,
+    hint_factor: f32,
+) -> Option<Point> {
+    let line_height = buffer.metrics().line_height;
+
+    let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
+
+    let line = buffer.lines.get(cursor.line)?;
+    let layout = line.layout_opt()?;
+
+    let mut lines = layout.iter().enumerate();
+
+    let (visual_line, offset) = lines
+        .find_map(|(i, line)| {
+            let start = line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
+            let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+            let is_cursor_before_start = start > cursor.index;
+
+            let is_cursor_before_end = match cursor.affinity {
+                cosmic_text::Affinity::Before => cursor.index <= end,
+                cosmic_text::Affinity::After => cursor.index < end,
+            };
+
+            if is_cursor_before_start {
+                // Sometimes, the glyph we are looking for is right
+                // between lines. This can happen when a line wraps
+                // on a space.
+                // In that case, we can assume the cursor is at the
+                // end of the previous line.
+                // i is guaranteed to be > 0 because `start` is always
+                // 0 for the first line, so there is no way for the
+                // cursor to be before it.
+                Some((i - 1, layout[i - 1].w))
+            } else if is_cursor_before_end {
+                let offset = line
+                    .glyphs
+                    .iter()
+                    .take_while(|glyph| cursor.index > glyph.start)
+                    .map(|glyph| glyph.w)
+                    .sum();
+
+                Some((i, offset))
+            } else {
+                None
+            }
+        })
+        .unwrap_or((
+            layout.len().saturating_sub(1),
+            layout.last().map(|line| line.w).unwrap_or(0.0),
+        ));
+
+    Some(Point::new(
+        offset / hint_factor,
+        ((visual_lines_offset + visual_line as i32) as f32 * line_height
+            - buffer.scroll().vertical)
+            / hint_factor,
+    ))
+}
+
+/// Returns the index of the glyph boundary closest to the given horizontal
+/// position in a [`cosmic_text::LayoutRun`].
+fn run_index_at(run: &cosmic_text::LayoutRun<'_>, x: f32) -> usize {
+    run.glyphs
+        .iter()
+        .find(|glyph| x < glyph.x + glyph.w / 2.0)
+        .map(|glyph| glyph.start)
+        .or_else(|| run.glyphs.last().map(|glyph| glyph.end))
+        .unwrap_or(0)
+}
+
+fn text_between(
+    buffer: &cosmic_text::Buffer,
+    (start_line, start_index): (usize, usize),
+    (end_line, end_index): (usize, usize),
+) -> String {
+    let mut text = String::new();
+
+    for (i, line) in buffer
+        .lines
+        .iter()
+        .enumerate()
+        .skip(start_line)
+        .take(end_line + 1 - start_line)
+    {
+        let line = line.text();
+
+        let start = if i == start_line { start_index } else { 0 };
+        let end = if i == end_line { end_index } else { line.len() };
+
+        text.push_str(line.get(start..end).unwrap_or_default());
+
+        if i != end_line {
+            text.push('\n');
+        }
+    }
+
+    text
+}
+
+fn regions(
+    buffer: &cosmic_text::Buffer,
+    (start_line, start_index): (usize, usize),
+    (end_line, end_index): (usize, usize),
+    hint_factor: f32,
+) -> Vec<Rectangle> {
+    if end_line < start_line || start_line >= buffer.lines.len() {
+        return Vec::new();
+    }
+
+    let line_height = buffer.metrics().line_height;
+    let selected_lines = end_line - start_line + 1;
+
+    let visual_lines_offset = visual_lines_offset(start_line, buffer);
+
+    buffer
+        .lines
+        .iter()
+        .skip(start_line)
+        .take(selected_lines)
+        .enumerate()
+        .flat_map(|(i, line)| {
+            highlight_line(
+                line,
+                if i == 0 { start_index } else { 0 },
+                if i == selected_lines - 1 {
+                    end_index
+                } else {
+                    line.text().len()
+                },
+            )
+        })
+        .enumerate()
+        .filter_map(|(visual_line, (x, width))| {
+            if width > 0.0 {
+                Some(
+                    Rectangle {
+                        x,
+                        width,
+                        y: (visual_line as i32 + visual_lines_offset) as f32 * line_height
+                            - buffer.scroll().vertical,
+                        height: line_height,
+                    } * (1.0 / hint_factor),
+                )
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
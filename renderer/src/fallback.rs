@@ -1,9 +1,11 @@
 //! Compose existing renderers and create type-safe fallback strategies.
+use crate::core::border;
 use crate::core::image;
 use crate::core::renderer;
 use crate::core::svg;
 use crate::core::{
-    self, Background, Color, Font, Image, Pixels, Point, Rectangle, Size, Svg, Transformation,
+    self, Backdrop, Background, Color, Font, Image, Pixels, Point, Rectangle, Size, Svg,
+    Transformation,
 };
 use crate::graphics::compositor;
 use crate::graphics::mesh;
@@ -42,6 +44,19 @@ where
         delegate!(self, renderer, renderer.fill_quad(quad, background.into()));
     }
 
+    fn draw_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: border::Radius,
+        backdrop: Backdrop,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.draw_backdrop(bounds, border_radius, backdrop)
+        );
+    }
+
     fn reset(&mut self, new_bounds: Rectangle) {
         delegate!(self, renderer, renderer.reset(new_bounds));
     }
@@ -1,5 +1,5 @@
 //! Access the clipboard.
-use crate::core::clipboard::Kind;
+use crate::core::clipboard::{Content, Format, Kind};
 use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
 
@@ -23,6 +23,24 @@ pub enum Action {
         /// The contents to be written.
         contents: String,
     },
+
+    /// Read the clipboard in a specific [`Format`].
+    ReadFormat {
+        /// The clipboard target.
+        target: Kind,
+        /// The [`Format`] to read.
+        format: Format,
+        /// The channel to send the read contents.
+        channel: oneshot::Sender<Option<Content>>,
+    },
+
+    /// Write the given contents, in multiple formats, to the clipboard.
+    WriteFormats {
+        /// The clipboard target.
+        target: Kind,
+        /// The contents to be written.
+        contents: Vec<Content>,
+    },
 }
 
 /// Read the current contents of the clipboard.
@@ -60,3 +78,29 @@ pub fn write_primary<Message>(contents: String) -> Task<Message> {
         contents,
     }))
 }
+
+/// Read the current contents of the clipboard in the given [`Format`].
+pub fn read_format(format: Format) -> Task<Option<Content>> {
+    task::oneshot(|channel| {
+        crate::Action::Clipboard(Action::ReadFormat {
+            target: Kind::Standard,
+            format,
+            channel,
+        })
+    })
+}
+
+/// Write the given contents to the clipboard.
+///
+/// Every [`Content`] should be a different representation of the same data
+/// (e.g. some HTML and its plain text), so readers can pick the [`Format`]
+/// that suits them best.
+///
+/// The system clipboard may not be able to hold every [`Content`] at once;
+/// in that case, the shell picks the richest one it supports.
+pub fn write_formats<T>(contents: impl IntoIterator<Item = Content>) -> Task<T> {
+    task::effect(crate::Action::Clipboard(Action::WriteFormats {
+        target: Kind::Standard,
+        contents: contents.into_iter().collect(),
+    }))
+}
@@ -5,6 +5,7 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget;
+use crate::core::widget::operation::{accessibility, focusable};
 use crate::core::window;
 use crate::core::{Clipboard, Element, InputMethod, Layout, Rectangle, Shell, Size, Vector};
 
@@ -26,6 +27,8 @@ pub struct UserInterface<'a, Message, Theme, Renderer> {
     state: widget::Tree,
     overlay: Option<Overlay>,
     bounds: Size,
+    focus_history: Vec<Option<widget::Id>>,
+    is_focus_outdated: bool,
 }
 
 struct Overlay {
@@ -96,8 +99,14 @@ where
     ) -> Self {
         let mut root = root.into();
 
-        let Cache { mut state } = cache;
+        let Cache {
+            mut state,
+            focus_history,
+        } = cache;
+
+        let shape = focus_shape(&state);
         state.diff(root.as_widget());
+        let is_focus_outdated = focus_shape(&state) != shape;
 
         let base = root.as_widget_mut().layout(
             &mut state,
@@ -111,6 +120,8 @@ where
             state,
             overlay: None,
             bounds,
+            focus_history,
+            is_focus_outdated,
         }
     }
 
@@ -205,6 +216,8 @@ where
             )
             .map(overlay::Nested::new);
 
+        let mut overlay_operations = Vec::new();
+
         let (base_cursor, overlay_statuses, overlay_interaction) = if maybe_overlay.is_some() {
             let bounds = self.bounds;
 
@@ -227,6 +240,7 @@ where
                 event_statuses.push(shell.event_status());
                 redraw_request = redraw_request.min(shell.redraw_request());
                 input_method.merge(shell.input_method());
+                overlay_operations.extend(shell.take_operations());
 
                 if shell.is_layout_invalid() {
                     drop(maybe_overlay);
@@ -303,6 +317,10 @@ where
 
         drop(maybe_overlay);
 
+        for operation in overlay_operations {
+            self.run_operation(renderer, operation);
+        }
+
         let event_statuses = events
             .iter()
             .zip(overlay_statuses)
@@ -367,10 +385,18 @@ where
                     outdated = true;
                 }
 
+                for operation in shell.take_operations() {
+                    self.run_operation(renderer, operation);
+                }
+
                 shell.event_status().merge(overlay_status)
             })
             .collect();
 
+        if self.is_focus_outdated {
+            self.sync_focus(renderer);
+        }
+
         let mouse_interaction = if overlay_interaction == mouse::Interaction::None {
             self.root.as_widget().mouse_interaction(
                 &self.state,
@@ -529,6 +555,13 @@ where
 
     /// Applies a [`widget::Operation`] to the [`UserInterface`].
     pub fn operate(&mut self, renderer: &Renderer, operation: &mut dyn widget::Operation) {
+        self.traverse(renderer, operation);
+        self.is_focus_outdated = true;
+    }
+
+    /// Traverses the widgets of the [`UserInterface`] with the given
+    /// [`widget::Operation`], including any overlay.
+    fn traverse(&mut self, renderer: &Renderer, operation: &mut dyn widget::Operation) {
         let viewport = Rectangle::with_size(self.bounds);
 
         self.root.as_widget_mut().operate(
@@ -565,23 +598,143 @@ where
         }
     }
 
+    /// Runs the given [`widget::Operation`] until completion, following any
+    /// chained operations along the way.
+    fn run_operation(&mut self, renderer: &Renderer, operation: Box<dyn widget::Operation>) {
+        let mut current = Some(operation);
+
+        while let Some(mut operation) = current.take() {
+            self.traverse(renderer, &mut operation);
+
+            if let widget::operation::Outcome::Chain(next) = operation.finish() {
+                current = Some(next);
+            }
+        }
+
+        self.is_focus_outdated = true;
+    }
+
+    /// Keeps track of the focus traps of the [`UserInterface`].
+    ///
+    /// When a new trap shows up, the focus moves inside of it. When a trap
+    /// goes away, the focus is restored to the widget that was focused
+    /// before the trap showed up—as long as it has an [`Id`](widget::Id)
+    /// and nothing else has been focused since.
+    ///
+    /// Traps can only show up or go away when the widget tree changes, and
+    /// the focus only moves inside of them with operations; so this only
+    /// runs after any of those happen.
+    fn sync_focus(&mut self, renderer: &Renderer) {
+        use widget::Operation as _;
+
+        self.is_focus_outdated = false;
+
+        let mut operation = focusable::traps();
+
+        self.traverse(renderer, &mut widget::operation::black_box(&mut operation));
+
+        let widget::operation::Outcome::Some(traps) = operation.finish() else {
+            return;
+        };
+
+        if self.focus_history.len() > traps.total {
+            let restored = self.focus_history.drain(traps.total..).next().flatten();
+
+            if let Some(id) = restored
+                && !traps.is_focused
+            {
+                self.run_operation(renderer, Box::new(focusable::focus(id)));
+            }
+        } else if self.focus_history.len() < traps.total {
+            self.focus_history.resize(traps.total, traps.focused);
+
+            if !traps.is_trapped {
+                self.run_operation(renderer, Box::new(focusable::focus_next()));
+            }
+        }
+    }
+
+    /// Builds the [`accessibility::Tree`] of the [`UserInterface`].
+    ///
+    /// The resulting tree can be handed to a platform adapter to expose
+    /// the [`UserInterface`] to assistive technologies; like the shell does
+    /// after every build and update.
+    pub fn accessibility(&mut self, renderer: &Renderer) -> accessibility::Tree {
+        use widget::Operation as _;
+
+        let mut operation = accessibility::tree();
+
+        self.traverse(renderer, &mut widget::operation::black_box(&mut operation));
+
+        match operation.finish() {
+            widget::operation::Outcome::Some(tree) => tree,
+            _ => accessibility::Tree::default(),
+        }
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        let is_focus_outdated = self.is_focus_outdated;
+
+        let user_interface = Self::build(
+            self.root,
+            bounds,
+            Cache {
+                state: self.state,
+                focus_history: self.focus_history,
+            },
+            renderer,
+        );
+
+        // The widgets are the same, so the focus traps are too
+        Self {
+            is_focus_outdated,
+            ..user_interface
+        }
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     pub fn into_cache(self) -> Cache {
-        Cache { state: self.state }
+        Cache {
+            state: self.state,
+            focus_history: self.focus_history,
+        }
     }
 }
 
+/// Hashes the parts of a widget tree that can change its focus traps; that
+/// is, its structure and its focus scopes.
+fn focus_shape(tree: &widget::Tree) -> u64 {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    fn visit(tree: &widget::Tree, hasher: &mut DefaultHasher) {
+        tree.tag.hash(hasher);
+        tree.children.len().hash(hasher);
+
+        if let widget::tree::State::Some(state) = &tree.state
+            && let Some(scope) = state.downcast_ref::<focusable::Scope>()
+        {
+            scope.is_trap.hash(hasher);
+        }
+
+        for child in &tree.children {
+            visit(child, hasher);
+        }
+    }
+
+    let mut hasher = DefaultHasher::new();
+    visit(tree, &mut hasher);
+
+    hasher.finish()
+}
+
 /// Reusable data of a specific [`UserInterface`].
 #[derive(Debug)]
 pub struct Cache {
     state: widget::Tree,
+    focus_history: Vec<Option<widget::Id>>,
 }
 
 impl Cache {
@@ -592,6 +745,7 @@ impl Cache {
     pub fn new() -> Cache {
         Cache {
             state: widget::Tree::empty(),
+            focus_history: Vec::new(),
         }
     }
 }
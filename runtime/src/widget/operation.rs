@@ -53,6 +53,12 @@ pub fn is_focused(id: impl Into<Id>) -> Task<bool> {
     task::widget(operation::focusable::is_focused(id.into()))
 }
 
+/// Returns the [`Id`] of the focused widget; or `None` if no widget is
+/// focused or the focused widget has no [`Id`].
+pub fn focused() -> Task<Option<Id>> {
+    task::widget(operation::focusable::focused())
+}
+
 /// Focuses the widget with the given [`Id`].
 pub fn focus<T>(id: impl Into<Id>) -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus(id.into())))
@@ -93,3 +99,20 @@ pub fn select_range<T>(id: impl Into<Id>, start: usize, end: usize) -> Task<T> {
         end,
     )))
 }
+
+/// Clears the undo and redo history of the widget with the given [`Id`].
+///
+/// This is useful to prevent undoing past a programmatic reset of its value
+/// (e.g. after submitting a form).
+pub fn clear_history<T>(id: impl Into<Id>) -> Task<T> {
+    task::effect(Action::widget(operation::text_input::clear_history(
+        id.into(),
+    )))
+}
+
+/// Returns the [`accessibility::Tree`] of the current user interface.
+///
+/// [`accessibility::Tree`]: operation::accessibility::Tree
+pub fn accessibility_tree() -> Task<operation::accessibility::Tree> {
+    task::widget(operation::accessibility::tree())
+}
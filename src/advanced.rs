@@ -23,5 +23,6 @@ pub use crate::core::renderer::{self, Renderer};
 pub use crate::core::svg;
 pub use crate::core::text::{self, Text};
 pub use crate::renderer::graphics;
+pub use crate::shell::accessibility;
 
 pub use widget::Widget;
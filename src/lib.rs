@@ -526,15 +526,16 @@ pub mod advanced;
 
 pub use crate::core::alignment;
 pub use crate::core::animation;
+pub use crate::core::background;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
 pub use crate::core::padding;
 pub use crate::core::theme;
 pub use crate::core::{
-    Alignment, Animation, Background, Border, Color, ContentFit, Degrees, Function, Gradient,
-    Length, Never, Padding, Pixels, Point, Radians, Rectangle, Rotation, Settings, Shadow, Size,
-    Theme, Transformation, Vector, never,
+    Alignment, Animation, Backdrop, Background, Border, Color, ContentFit, Degrees, Function,
+    Gradient, Length, Never, Padding, Pixels, Point, Radians, Rectangle, Rotation, Settings,
+    Shadow, Size, Theme, Transformation, Vector, never,
 };
 pub use crate::program::Preset;
 pub use crate::program::message;
@@ -561,7 +562,10 @@ pub mod task {
 
 pub mod clipboard {
     //! Access the clipboard.
-    pub use crate::runtime::clipboard::{read, read_primary, write, write_primary};
+    pub use crate::core::clipboard::{Content, Format, Image};
+    pub use crate::runtime::clipboard::{
+        read, read_format, read_primary, write, write_formats, write_primary,
+    };
 }
 
 pub mod executor {
@@ -2,6 +2,7 @@
 use iced_futures::core::window::PositionOnMonitor;
 
 use crate::core;
+use crate::core::clipboard::{self, Clipboard as _};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::time::Instant;
@@ -41,7 +42,7 @@ pub struct Emulator<P: Program> {
     size: Size,
     window: core::window::Id,
     cursor: mouse::Cursor,
-    clipboard: Clipboard,
+    clipboard: clipboard::Memory,
     cache: Option<user_interface::Cache>,
     pending_tasks: usize,
 }
@@ -116,7 +117,7 @@ impl<P: Program + 'static> Emulator<P> {
             renderer,
             mode,
             size,
-            clipboard: Clipboard { content: None },
+            clipboard: clipboard::Memory::new(),
             cursor: mouse::Cursor::Unavailable,
             window: core::window::Id::unique(),
             cache: Some(user_interface::Cache::default()),
@@ -204,8 +205,26 @@ impl<P: Program + 'static> Emulator<P> {
                     self.cache = Some(user_interface.into_cache());
                 }
                 runtime::Action::Clipboard(action) => {
-                    // TODO
-                    dbg!(action);
+                    use crate::runtime::clipboard;
+
+                    match action {
+                        clipboard::Action::Read { target, channel } => {
+                            let _ = channel.send(self.clipboard.read(target));
+                        }
+                        clipboard::Action::Write { target, contents } => {
+                            self.clipboard.write(target, contents);
+                        }
+                        clipboard::Action::ReadFormat {
+                            target,
+                            format,
+                            channel,
+                        } => {
+                            let _ = channel.send(self.clipboard.read_format(target, &format));
+                        }
+                        clipboard::Action::WriteFormats { target, contents } => {
+                            self.clipboard.write_formats(target, contents);
+                        }
+                    }
                 }
                 runtime::Action::Window(action) => {
                     use crate::runtime::window;
@@ -525,17 +544,3 @@ impl fmt::Display for Mode {
         })
     }
 }
-
-struct Clipboard {
-    content: Option<String>,
-}
-
-impl core::Clipboard for Clipboard {
-    fn read(&self, _kind: core::clipboard::Kind) -> Option<String> {
-        self.content.clone()
-    }
-
-    fn write(&mut self, _kind: core::clipboard::Kind, contents: String) {
-        self.content = Some(contents);
-    }
-}
@@ -7,6 +7,7 @@ use crate::core::mouse;
 use crate::core::theme;
 use crate::core::time;
 use crate::core::widget;
+use crate::core::widget::operation::accessibility;
 use crate::core::window;
 use crate::core::{Element, Event, Font, Point, Settings, Size, SmolStr};
 use crate::renderer;
@@ -28,6 +29,7 @@ pub struct Simulator<'a, Message, Theme = core::Theme, Renderer = renderer::Rend
     renderer: Renderer,
     size: Size,
     cursor: mouse::Cursor,
+    clipboard: clipboard::Memory,
     messages: Vec<Message>,
 }
 
@@ -89,6 +91,7 @@ where
             renderer,
             size,
             cursor: mouse::Cursor::Unavailable,
+            clipboard: clipboard::Memory::new(),
             messages: Vec::new(),
         }
     }
@@ -119,6 +122,22 @@ where
         }
     }
 
+    /// Returns the [`Clipboard`](clipboard::Memory) of the [`Simulator`].
+    pub fn clipboard(&self) -> &clipboard::Memory {
+        &self.clipboard
+    }
+
+    /// Returns a mutable reference to the [`Clipboard`](clipboard::Memory) of the
+    /// [`Simulator`].
+    pub fn clipboard_mut(&mut self) -> &mut clipboard::Memory {
+        &mut self.clipboard
+    }
+
+    /// Returns the accessibility [`Tree`](accessibility::Tree) of the [`Simulator`].
+    pub fn accessibility(&mut self) -> accessibility::Tree {
+        self.raw.accessibility(&self.renderer)
+    }
+
     /// Points the mouse cursor at the given position in the [`Simulator`].
     ///
     /// This does _not_ produce mouse movement events!
@@ -176,7 +195,7 @@ where
             &events,
             self.cursor,
             &mut self.renderer,
-            &mut clipboard::Null,
+            &mut self.clipboard,
             &mut self.messages,
         );
 
@@ -193,7 +212,7 @@ where
             ))],
             self.cursor,
             &mut self.renderer,
-            &mut clipboard::Null,
+            &mut self.clipboard,
             &mut self.messages,
         );
 
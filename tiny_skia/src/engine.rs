@@ -1,7 +1,10 @@
 use crate::Primitive;
+use crate::core::border;
 use crate::core::renderer::Quad;
-use crate::core::{Background, Color, Gradient, Rectangle, Size, Transformation, Vector};
-use crate::graphics::{Image, Text};
+use crate::core::{Background, Color, Rectangle, Size, Transformation, Vector};
+use crate::gradient;
+use crate::graphics::layer;
+use crate::graphics::{self, Image, Text};
 use crate::text;
 
 #[derive(Debug)]
@@ -63,7 +66,7 @@ impl Engine {
 
         let shadow = quad.shadow;
 
-        if shadow.color.a > 0.0 {
+        if shadow.color.a > 0.0 && !shadow.inset {
             let shadow_bounds = Rectangle {
                 x: quad.bounds.x + shadow.offset.x - shadow.blur_radius,
                 y: quad.bounds.y + shadow.offset.y - shadow.blur_radius,
@@ -108,8 +111,20 @@ impl Engine {
                                 shadow_distance,
                             );
 
+                        // Shadows are only cast outside of the quad
+                        let quad_alpha = (0.5
+                            - rounded_box_sdf(
+                                Vector::new(
+                                    x + 0.5 - physical_bounds.x - half_width,
+                                    y + 0.5 - physical_bounds.y - half_height,
+                                ),
+                                size,
+                                &radii,
+                            ))
+                        .clamp(0.0, 1.0);
+
                         let mut color = into_color(shadow.color);
-                        color.apply_opacity(shadow_alpha);
+                        color.apply_opacity(shadow_alpha * (1.0 - quad_alpha));
 
                         color.to_color_u8().premultiply()
                     })
@@ -130,47 +145,34 @@ impl Engine {
             }
         }
 
+        let pattern;
+
         pixels.fill_path(
             &path,
             &tiny_skia::Paint {
                 shader: match background {
                     Background::Color(color) => tiny_skia::Shader::SolidColor(into_color(*color)),
-                    Background::Gradient(Gradient::Linear(linear)) => {
-                        let (start, end) = linear.angle.to_distance(&quad.bounds);
-
-                        let stops: Vec<tiny_skia::GradientStop> = linear
-                            .stops
-                            .into_iter()
-                            .flatten()
-                            .map(|stop| {
-                                tiny_skia::GradientStop::new(
-                                    stop.offset,
-                                    tiny_skia::Color::from_rgba(
-                                        stop.color.b,
-                                        stop.color.g,
-                                        stop.color.r,
-                                        stop.color.a,
-                                    )
-                                    .expect("Create color"),
+                    Background::Gradient(gradient) => {
+                        match graphics::gradient::resolve(gradient, quad.bounds) {
+                            graphics::Gradient::Conic(conic) => {
+                                pattern = conic_pattern(
+                                    Some(&conic),
+                                    physical_bounds,
+                                    clip_bounds,
+                                    transform,
+                                );
+
+                                pattern.as_ref().map_or(
+                                    tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT),
+                                    gradient::Pattern::shader,
                                 )
-                            })
-                            .collect();
-
-                        tiny_skia::LinearGradient::new(
-                            tiny_skia::Point {
-                                x: start.x,
-                                y: start.y,
-                            },
-                            tiny_skia::Point { x: end.x, y: end.y },
-                            if stops.is_empty() {
-                                vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
-                            } else {
-                                stops
-                            },
-                            tiny_skia::SpreadMode::Pad,
-                            tiny_skia::Transform::identity(),
-                        )
-                        .expect("Create linear gradient")
+                            }
+                            gradient => gradient::shader(&gradient),
+                        }
+                    }
+                    // Image backgrounds are drawn by the raster pipeline
+                    Background::Image(_) => {
+                        tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT)
                     }
                 },
                 anti_alias: true,
@@ -181,6 +183,68 @@ impl Engine {
             clip_mask,
         );
 
+        if shadow.color.a > 0.0
+            && shadow.inset
+            && let Some(bounds) = physical_bounds.intersection(&clip_bounds)
+        {
+            let scale_factor = transformation.scale_factor();
+            let radii = fill_border_radius
+                .into_iter()
+                .map(|radius| radius * scale_factor)
+                .collect::<Vec<_>>();
+            let (x, y, width, height) = (
+                bounds.x as u32,
+                bounds.y as u32,
+                bounds.width.ceil() as u32,
+                bounds.height.ceil() as u32,
+            );
+            let half_width = physical_bounds.width / 2.0;
+            let half_height = physical_bounds.height / 2.0;
+            let blur_radius = shadow.blur_radius * scale_factor;
+
+            let colors = (y..y + height)
+                .flat_map(|y| (x..x + width).map(move |x| (x as f32 + 0.5, y as f32 + 0.5)))
+                .filter_map(|(x, y)| {
+                    tiny_skia::Size::from_wh(half_width, half_height).map(|size| {
+                        let to_center = Vector::new(
+                            x - physical_bounds.x - half_width,
+                            y - physical_bounds.y - half_height,
+                        );
+
+                        // Inset shadows are drawn below the border
+                        let quad_alpha = (0.5
+                            - rounded_box_sdf(to_center, size, &radii)
+                            - border_width * scale_factor)
+                            .clamp(0.0, 1.0);
+
+                        let shadow_distance =
+                            rounded_box_sdf(to_center - shadow.offset * scale_factor, size, &radii);
+
+                        let shadow_alpha =
+                            smoothstep(-blur_radius - 0.5, blur_radius + 0.5, shadow_distance);
+
+                        let mut color = into_color(shadow.color);
+                        color.apply_opacity(quad_alpha * shadow_alpha);
+
+                        color.to_color_u8().premultiply()
+                    })
+                })
+                .collect();
+
+            if let Some(pixmap) = tiny_skia::IntSize::from_wh(width, height)
+                .and_then(|size| tiny_skia::Pixmap::from_vec(bytemuck::cast_vec(colors), size))
+            {
+                pixels.draw_pixmap(
+                    x as i32,
+                    y as i32,
+                    pixmap.as_ref(),
+                    &tiny_skia::PixmapPaint::default(),
+                    tiny_skia::Transform::default(),
+                    clip_mask,
+                );
+            }
+        }
+
         if border_width > 0.0 {
             // Border path is offset by half the border width
             let border_bounds = Rectangle {
@@ -454,7 +518,12 @@ impl Engine {
         clip_bounds: Rectangle,
     ) {
         match primitive {
-            Primitive::Fill { path, paint, rule } => {
+            Primitive::Fill {
+                path,
+                paint,
+                rule,
+                conic,
+            } => {
                 let physical_bounds = {
                     let bounds = path.bounds();
 
@@ -473,18 +542,25 @@ impl Engine {
                 let clip_mask =
                     (!physical_bounds.is_within(&clip_bounds)).then_some(clip_mask as &_);
 
-                pixels.fill_path(
-                    path,
-                    paint,
-                    *rule,
-                    into_transform(transformation),
-                    clip_mask,
-                );
+                let transform = into_transform(transformation);
+                let pattern =
+                    conic_pattern(conic.as_ref(), physical_bounds, clip_bounds, transform);
+
+                let paint = match &pattern {
+                    Some(pattern) => &tiny_skia::Paint {
+                        shader: pattern.shader(),
+                        ..paint.clone()
+                    },
+                    None => paint,
+                };
+
+                pixels.fill_path(path, paint, *rule, transform, clip_mask);
             }
             Primitive::Stroke {
                 path,
                 paint,
                 stroke,
+                conic,
             } => {
                 let physical_bounds = {
                     let bounds = path.bounds();
@@ -504,13 +580,144 @@ impl Engine {
                 let clip_mask =
                     (!physical_bounds.is_within(&clip_bounds)).then_some(clip_mask as &_);
 
-                pixels.stroke_path(
-                    path,
-                    paint,
-                    stroke,
-                    into_transform(transformation),
-                    clip_mask,
+                let transform = into_transform(transformation);
+                let pattern =
+                    conic_pattern(conic.as_ref(), physical_bounds, clip_bounds, transform);
+
+                let paint = match &pattern {
+                    Some(pattern) => &tiny_skia::Paint {
+                        shader: pattern.shader(),
+                        ..paint.clone()
+                    },
+                    None => paint,
+                };
+
+                pixels.stroke_path(path, paint, stroke, transform, clip_mask);
+            }
+        }
+    }
+
+    pub fn draw_backdrop(
+        &mut self,
+        backdrop: &layer::Backdrop,
+        transformation: Transformation,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_bounds: Rectangle,
+    ) {
+        let physical_bounds = backdrop.bounds * transformation;
+
+        let Some(bounds) = physical_bounds.intersection(&clip_bounds) else {
+            return;
+        };
+
+        let scale_factor = transformation.scale_factor();
+        let blur_radius = backdrop.blur_radius * scale_factor;
+        let extent = blur_extent(blur_radius).ceil() as i32;
+
+        let width = pixels.width() as i32;
+        let height = pixels.height() as i32;
+
+        let x = (bounds.x.floor() as i32).clamp(0, width);
+        let y = (bounds.y.floor() as i32).clamp(0, height);
+        let right = ((bounds.x + bounds.width).ceil() as i32).clamp(0, width);
+        let bottom = ((bounds.y + bounds.height).ceil() as i32).clamp(0, height);
+
+        if x >= right || y >= bottom {
+            return;
+        }
+
+        // The blurred region together with the pixels it samples
+        let source_x = (x - extent).max(0);
+        let source_y = (y - extent).max(0);
+        let source_width = ((right + extent).min(width) - source_x) as usize;
+        let source_height = ((bottom + extent).min(height) - source_y) as usize;
+
+        let mut buffer: Vec<[f32; 4]> = Vec::with_capacity(source_width * source_height);
+
+        for row in 0..source_height {
+            let start = (source_y as usize + row) * width as usize + source_x as usize;
+
+            buffer.extend(
+                pixels.as_ref().pixels()[start..start + source_width]
+                    .iter()
+                    .map(|pixel| {
+                        [
+                            f32::from(pixel.red()),
+                            f32::from(pixel.green()),
+                            f32::from(pixel.blue()),
+                            f32::from(pixel.alpha()),
+                        ]
+                    }),
+            );
+        }
+
+        // Three box blurs approximate a gaussian blur
+        let sigma = blur_radius / 2.0;
+        let radius = (((4.0 * sigma * sigma + 1.0).sqrt() - 1.0) / 2.0).round() as usize;
+
+        if radius > 0 {
+            for _ in 0..3 {
+                box_blur(&mut buffer, source_width, source_height, radius, true);
+                box_blur(&mut buffer, source_width, source_height, radius, false);
+            }
+        }
+
+        let radii: Vec<f32> = backdrop
+            .border_radius
+            .iter()
+            .map(|radius| {
+                (radius * scale_factor)
+                    .min(physical_bounds.width / 2.0)
+                    .min(physical_bounds.height / 2.0)
+            })
+            .collect();
+
+        let half_width = physical_bounds.width / 2.0;
+        let half_height = physical_bounds.height / 2.0;
+
+        let Some(size) = tiny_skia::Size::from_wh(half_width, half_height) else {
+            return;
+        };
+
+        let target = pixels.pixels_mut();
+
+        for row in y..bottom {
+            for column in x..right {
+                let coverage = (0.5
+                    - rounded_box_sdf(
+                        Vector::new(
+                            column as f32 + 0.5 - physical_bounds.x - half_width,
+                            row as f32 + 0.5 - physical_bounds.y - half_height,
+                        ),
+                        size,
+                        &radii,
+                    ))
+                .clamp(0.0, 1.0);
+
+                if coverage <= 0.0 {
+                    continue;
+                }
+
+                let [red, green, blue, alpha] = saturate(
+                    buffer[(row - source_y) as usize * source_width + (column - source_x) as usize],
+                    backdrop.saturation,
                 );
+
+                let pixel = &mut target[row as usize * width as usize + column as usize];
+
+                let mix = |blurred: f32, original: u8| {
+                    (blurred * coverage + f32::from(original) * (1.0 - coverage)).round()
+                };
+
+                let alpha = mix(alpha, pixel.alpha());
+
+                *pixel = tiny_skia::PremultipliedColorU8::from_rgba(
+                    mix(red, pixel.red()).min(alpha) as u8,
+                    mix(green, pixel.green()).min(alpha) as u8,
+                    mix(blue, pixel.blue()).min(alpha) as u8,
+                    alpha as u8,
+                )
+                .unwrap_or(*pixel);
             }
         }
     }
@@ -532,8 +739,11 @@ impl Engine {
                     return;
                 }
 
-                let clip_mask =
-                    (!physical_bounds.is_within(&_clip_bounds)).then_some(_clip_mask as &_);
+                // The border radius is applied to the clip mask
+                let is_rounded = image.border_radius != border::Radius::default();
+
+                let clip_mask = (is_rounded || !physical_bounds.is_within(&_clip_bounds))
+                    .then_some(_clip_mask as &_);
 
                 let center = physical_bounds.center();
                 let radians = f32::from(image.rotation);
@@ -548,6 +758,7 @@ impl Engine {
                     &image.handle,
                     image.filter_method,
                     *bounds,
+                    image.repeat,
                     image.opacity,
                     _pixels,
                     transform,
@@ -756,6 +967,87 @@ fn arc_to(
     }
 }
 
+fn conic_pattern(
+    conic: Option<&graphics::gradient::Conic>,
+    physical_bounds: Rectangle,
+    clip_bounds: Rectangle,
+    transform: tiny_skia::Transform,
+) -> Option<gradient::Pattern> {
+    gradient::Pattern::conic(
+        conic?,
+        physical_bounds.intersection(&clip_bounds)?,
+        transform,
+    )
+}
+
+/// Returns the distance a blur with the given radius reaches.
+pub fn blur_extent(blur_radius: f32) -> f32 {
+    // The radius covers two standard deviations; we stop at three
+    blur_radius * 1.5
+}
+
+/// Blurs the lines of the given buffer with a box of the given radius.
+fn box_blur(buffer: &mut [[f32; 4]], width: usize, height: usize, radius: usize, horizontal: bool) {
+    let (lines, length) = if horizontal {
+        (height, width)
+    } else {
+        (width, height)
+    };
+
+    let index = |line: usize, i: usize| {
+        if horizontal {
+            line * width + i
+        } else {
+            i * width + line
+        }
+    };
+
+    let window = (radius * 2 + 1) as f32;
+    let mut pixels = vec![[0.0; 4]; length];
+
+    for line in 0..lines {
+        for (i, pixel) in pixels.iter_mut().enumerate() {
+            *pixel = buffer[index(line, i)];
+        }
+
+        // Edges are extended when sampling outside of the line
+        let mut sum = [0.0; 4];
+
+        for i in 0..=radius * 2 {
+            let pixel = pixels[i.saturating_sub(radius).min(length - 1)];
+
+            for channel in 0..4 {
+                sum[channel] += pixel[channel];
+            }
+        }
+
+        for i in 0..length {
+            buffer[index(line, i)] = sum.map(|channel| channel / window);
+
+            let outgoing = pixels[i.saturating_sub(radius)];
+            let incoming = pixels[(i + radius + 1).min(length - 1)];
+
+            for channel in 0..4 {
+                sum[channel] += incoming[channel] - outgoing[channel];
+            }
+        }
+    }
+}
+
+/// Saturates a premultiplied pixel.
+fn saturate([red, green, blue, alpha]: [f32; 4], saturation: f32) -> [f32; 4] {
+    if saturation == 1.0 {
+        return [red, green, blue, alpha];
+    }
+
+    // Pixels are stored in BGRA
+    let luminance = 0.0722 * red + 0.7152 * green + 0.2126 * blue;
+    let saturate =
+        |channel: f32| (luminance + (channel - luminance) * saturation).clamp(0.0, alpha);
+
+    [saturate(red), saturate(green), saturate(blue), alpha]
+}
+
 fn smoothstep(a: f32, b: f32, x: f32) -> f32 {
     let x = ((x - a) / (b - a)).clamp(0.0, 1.0);
 
@@ -770,10 +1062,38 @@ fn rounded_box_sdf(to_center: Vector, size: tiny_skia::Size, radii: &[f32]) -> f
         (false, false) => radii[0],
     };
 
-    let x = (to_center.x.abs() - size.width() + radius).max(0.0);
-    let y = (to_center.y.abs() - size.height() + radius).max(0.0);
+    let x = to_center.x.abs() - size.width() + radius;
+    let y = to_center.y.abs() - size.height() + radius;
+
+    x.max(y).min(0.0) + (x.max(0.0).powf(2.0) + y.max(0.0).powf(2.0)).sqrt() - radius
+}
+
+/// Returns the border radius of the given [`Image`].
+pub fn border_radius(image: &Image) -> border::Radius {
+    match image {
+        Image::Raster { image, .. } => image.border_radius,
+        Image::Vector { .. } => border::Radius::default(),
+    }
+}
+
+/// Rounds the corners of the clip mask to the given bounds.
+pub fn round_clip_mask(
+    clip_mask: &mut tiny_skia::Mask,
+    bounds: Rectangle,
+    border_radius: border::Radius,
+) {
+    let mut border_radius = <[f32; 4]>::from(border_radius);
+
+    for radius in &mut border_radius {
+        *radius = (*radius).min(bounds.width / 2.0).min(bounds.height / 2.0);
+    }
 
-    (x.powf(2.0) + y.powf(2.0)).sqrt() - radius
+    clip_mask.intersect_path(
+        &rounded_rectangle(bounds, border_radius),
+        tiny_skia::FillRule::EvenOdd,
+        true,
+        tiny_skia::Transform::default(),
+    );
 }
 
 pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
@@ -795,3 +1115,72 @@ pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
         tiny_skia::Transform::default(),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::{Border, Point, Shadow};
+
+    /// The inset shadow of a square quad, as computed by the quad shader
+    /// of `iced_wgpu`.
+    fn expected_inset_alpha(position: Vector, size: f32, shadow: Shadow) -> f32 {
+        let half = size / 2.0;
+        let to_center = position - shadow.offset - Vector::new(half, half);
+
+        let x = to_center.x.abs() - half;
+        let y = to_center.y.abs() - half;
+        let distance = x.max(y).min(0.0) + (x.max(0.0).powi(2) + y.max(0.0).powi(2)).sqrt();
+
+        smoothstep(
+            -shadow.blur_radius - 0.5,
+            shadow.blur_radius + 0.5,
+            distance,
+        )
+    }
+
+    #[test]
+    fn inset_shadows_match_the_wgpu_shader() {
+        const SIZE: u32 = 40;
+
+        let shadow = Shadow::inset(Color::BLACK, Vector::new(2.0, 0.0), 4.0);
+
+        let mut engine = Engine::new();
+        let mut pixmap = tiny_skia::Pixmap::new(SIZE, SIZE).expect("Create pixmap");
+        let mut clip_mask = tiny_skia::Mask::new(SIZE, SIZE).expect("Create clip mask");
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(SIZE as f32, SIZE as f32));
+
+        engine.draw_quad(
+            &Quad {
+                bounds,
+                border: Border::default(),
+                shadow,
+                snap: false,
+            },
+            &Background::Color(Color::WHITE),
+            Transformation::IDENTITY,
+            &mut pixmap.as_mut(),
+            &mut clip_mask,
+            bounds,
+        );
+
+        let y = SIZE / 2;
+
+        for x in 0..SIZE {
+            let pixel = pixmap.pixel(x, y).expect("Read pixel");
+
+            let alpha = expected_inset_alpha(
+                Vector::new(x as f32 + 0.5, y as f32 + 0.5),
+                SIZE as f32,
+                shadow,
+            );
+            let expected = (255.0 * (1.0 - alpha)).round();
+
+            assert!(
+                (f32::from(pixel.red()) - expected).abs() <= 2.0,
+                "pixel {x} is {} instead of {expected}",
+                pixel.red()
+            );
+        }
+    }
+}
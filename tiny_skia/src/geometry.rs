@@ -1,10 +1,12 @@
 use crate::Primitive;
 use crate::core::text::LineHeight;
 use crate::core::{self, Pixels, Point, Radians, Rectangle, Size, Svg, Vector};
+use crate::gradient;
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
 use crate::graphics::geometry::{self, Path, Style};
+use crate::graphics::gradient::Conic;
 use crate::graphics::{self, Gradient, Image, Text};
 
 use std::sync::Arc;
@@ -74,6 +76,25 @@ impl Frame {
             transform: tiny_skia::Transform::identity(),
         }
     }
+
+    fn transform_conic(&self, style: Style) -> Option<Conic> {
+        let Style::Gradient(Gradient::Conic(conic)) = style else {
+            return None;
+        };
+
+        let mut center = tiny_skia::Point {
+            x: conic.center.x,
+            y: conic.center.y,
+        };
+
+        self.transform.map_point(&mut center);
+
+        Some(Conic {
+            center: Point::new(center.x, center.y),
+            angle: conic.angle + Radians(self.transform.ky.atan2(self.transform.sx)),
+            ..conic
+        })
+    }
 }
 
 impl geometry::frame::Backend for Frame {
@@ -109,6 +130,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             rule: into_fill_rule(fill.rule),
+            conic: self.transform_conic(fill.style),
         });
     }
 
@@ -131,6 +153,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             rule: into_fill_rule(fill.rule),
+            conic: self.transform_conic(fill.style),
         });
     }
 
@@ -149,6 +172,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             stroke: skia_stroke,
+            conic: self.transform_conic(stroke.style),
         });
     }
 
@@ -398,46 +422,7 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
                 tiny_skia::Color::from_rgba(color.b, color.g, color.r, color.a)
                     .expect("Create color"),
             ),
-            Style::Gradient(gradient) => match gradient {
-                Gradient::Linear(linear) => {
-                    let stops: Vec<tiny_skia::GradientStop> = linear
-                        .stops
-                        .into_iter()
-                        .flatten()
-                        .map(|stop| {
-                            tiny_skia::GradientStop::new(
-                                stop.offset,
-                                tiny_skia::Color::from_rgba(
-                                    stop.color.b,
-                                    stop.color.g,
-                                    stop.color.r,
-                                    stop.color.a,
-                                )
-                                .expect("Create color"),
-                            )
-                        })
-                        .collect();
-
-                    tiny_skia::LinearGradient::new(
-                        tiny_skia::Point {
-                            x: linear.start.x,
-                            y: linear.start.y,
-                        },
-                        tiny_skia::Point {
-                            x: linear.end.x,
-                            y: linear.end.y,
-                        },
-                        if stops.is_empty() {
-                            vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
-                        } else {
-                            stops
-                        },
-                        tiny_skia::SpreadMode::Pad,
-                        tiny_skia::Transform::identity(),
-                    )
-                    .expect("Create linear gradient")
-                }
-            },
+            Style::Gradient(gradient) => gradient::shader(&gradient),
         },
         anti_alias: true,
         ..Default::default()
@@ -0,0 +1,175 @@
+use crate::core::gradient::ColorStop;
+use crate::core::{Color, Point, Rectangle};
+use crate::engine::into_color;
+use crate::graphics::Gradient;
+use crate::graphics::gradient::Conic;
+
+/// Creates a `tiny-skia` shader for the given [`Gradient`].
+///
+/// Conic gradients are not supported by `tiny-skia`; a [`Pattern`] must
+/// be rasterized for them instead.
+pub fn shader(gradient: &Gradient) -> tiny_skia::Shader<'static> {
+    match gradient {
+        Gradient::Linear(linear) => tiny_skia::LinearGradient::new(
+            tiny_skia::Point {
+                x: linear.start.x,
+                y: linear.start.y,
+            },
+            tiny_skia::Point {
+                x: linear.end.x,
+                y: linear.end.y,
+            },
+            stops(&linear.stops),
+            tiny_skia::SpreadMode::Pad,
+            tiny_skia::Transform::identity(),
+        )
+        .expect("Create linear gradient"),
+        Gradient::Radial(radial) => tiny_skia::RadialGradient::new(
+            tiny_skia::Point { x: 0.0, y: 0.0 },
+            tiny_skia::Point { x: 0.0, y: 0.0 },
+            1.0,
+            stops(&radial.stops),
+            tiny_skia::SpreadMode::Pad,
+            tiny_skia::Transform::from_row(
+                radial.radii.width,
+                0.0,
+                0.0,
+                radial.radii.height,
+                radial.center.x,
+                radial.center.y,
+            ),
+        )
+        .unwrap_or(tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT)),
+        Gradient::Conic(_) => tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT),
+    }
+}
+
+/// A gradient rasterized on the CPU.
+#[derive(Debug)]
+pub struct Pattern {
+    pixmap: tiny_skia::Pixmap,
+    transform: tiny_skia::Transform,
+}
+
+impl Pattern {
+    /// Rasterizes the given [`Conic`] gradient inside the given physical
+    /// bounds, where `transform` maps the gradient to physical pixels.
+    pub fn conic(
+        conic: &Conic,
+        bounds: Rectangle,
+        transform: tiny_skia::Transform,
+    ) -> Option<Self> {
+        let inverse = transform.invert()?;
+
+        let x = bounds.x.floor();
+        let y = bounds.y.floor();
+        let width = ((bounds.x + bounds.width).ceil() - x) as u32;
+        let height = ((bounds.y + bounds.height).ceil() - y) as u32;
+
+        let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+
+        for (index, pixel) in pixmap.pixels_mut().iter_mut().enumerate() {
+            let mut point = tiny_skia::Point {
+                x: x + (index as u32 % width) as f32 + 0.5,
+                y: y + (index as u32 / width) as f32 + 0.5,
+            };
+
+            inverse.map_point(&mut point);
+
+            let offset = conic.offset_at(Point::new(point.x, point.y));
+
+            *pixel = into_color(color_at(&conic.stops, offset))
+                .premultiply()
+                .to_color_u8();
+        }
+
+        Some(Self {
+            pixmap,
+            transform: inverse.pre_translate(x, y),
+        })
+    }
+
+    /// Returns the `tiny-skia` shader of the [`Pattern`].
+    pub fn shader(&self) -> tiny_skia::Shader<'_> {
+        tiny_skia::Pattern::new(
+            self.pixmap.as_ref(),
+            tiny_skia::SpreadMode::Pad,
+            tiny_skia::FilterQuality::Nearest,
+            1.0,
+            self.transform,
+        )
+    }
+}
+
+fn stops(stops: &[Option<ColorStop>; 8]) -> Vec<tiny_skia::GradientStop> {
+    let stops: Vec<_> = stops
+        .iter()
+        .flatten()
+        .map(|stop| tiny_skia::GradientStop::new(stop.offset, into_color(stop.color)))
+        .collect();
+
+    if stops.is_empty() {
+        vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
+    } else {
+        stops
+    }
+}
+
+/// Returns the color of the gradient at the given offset; interpolating
+/// like the `wgpu` renderer does.
+fn color_at(stops: &[Option<ColorStop>; 8], offset: f32) -> Color {
+    let mut stops = stops.iter().flatten().peekable();
+
+    let Some(first) = stops.peek().copied() else {
+        return Color::BLACK;
+    };
+
+    if offset <= first.offset {
+        return first.color;
+    }
+
+    let mut last = first;
+
+    for stop in stops {
+        if offset <= stop.offset {
+            let factor = smoothstep(last.offset, stop.offset, offset);
+
+            return mix(last.color, stop.color, factor);
+        }
+
+        last = stop;
+    }
+
+    last.color
+}
+
+fn mix(from: Color, to: Color, factor: f32) -> Color {
+    let alpha = from.a + (to.a - from.a) * factor;
+
+    if alpha <= 0.0 {
+        return Color::TRANSPARENT;
+    }
+
+    // Colors are interpolated premultiplied
+    let channel = |from_channel: f32, to_channel: f32| {
+        ((from_channel * from.a + (to_channel * to.a - from_channel * from.a) * factor) / alpha)
+            .clamp(0.0, 1.0)
+    };
+
+    Color {
+        r: channel(from.r, to.r),
+        g: channel(from.g, to.g),
+        b: channel(from.b, to.b),
+        a: alpha,
+    }
+}
+
+fn smoothstep(a: f32, b: f32, x: f32) -> f32 {
+    if b <= a {
+        return 1.0;
+    }
+
+    let x = ((x - a) / (b - a)).clamp(0.0, 1.0);
+
+    x * x * (3.0 - 2.0 * x)
+}
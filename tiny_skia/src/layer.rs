@@ -2,7 +2,7 @@ use crate::Primitive;
 use crate::core::renderer::Quad;
 use crate::core::{self, Background, Color, Point, Rectangle, Svg, Transformation};
 use crate::graphics::damage;
-use crate::graphics::layer;
+use crate::graphics::layer::{self, Backdrop};
 use crate::graphics::text::{Editor, Paragraph, Text};
 use crate::graphics::{self, Image};
 
@@ -13,10 +13,12 @@ pub type Stack = layer::Stack<Layer>;
 #[derive(Debug, Clone)]
 pub struct Layer {
     pub bounds: Rectangle,
+    pub backdrops: Vec<Backdrop>,
     pub quads: Vec<(Quad, Background)>,
     pub primitives: Vec<Item<Primitive>>,
     pub images: Vec<Image>,
     pub text: Vec<Item<Text>>,
+    image_backgrounds: Vec<Rectangle>,
 }
 
 impl Layer {
@@ -140,6 +142,26 @@ impl Layer {
         }
     }
 
+    /// Records the bounds of an image background drawn in the [`Layer`].
+    #[cfg(feature = "image")]
+    pub fn draw_image_background(&mut self, bounds: Rectangle, transformation: Transformation) {
+        self.image_backgrounds.push(bounds * transformation);
+    }
+
+    /// Returns whether the given bounds overlap any image background of the
+    /// [`Layer`].
+    pub fn is_over_image_background(
+        &self,
+        bounds: Rectangle,
+        transformation: Transformation,
+    ) -> bool {
+        let bounds = bounds * transformation;
+
+        self.image_backgrounds
+            .iter()
+            .any(|background| background.intersects(&bounds))
+    }
+
     pub fn draw_raster(
         &mut self,
         image: core::Image,
@@ -150,6 +172,9 @@ impl Layer {
         let image = Image::Raster {
             image: core::Image {
                 border_radius: image.border_radius * transformation.scale_factor(),
+                repeat: image
+                    .repeat
+                    .map(|tile| tile * transformation.scale_factor()),
                 ..image
             },
             bounds: bounds * transformation,
@@ -201,11 +226,40 @@ impl Layer {
         ));
     }
 
+    /// Extends the given damage with the surroundings of any [`Backdrop`]
+    /// it touches, since blurring a backdrop needs them redrawn.
+    pub fn extend_damage(layers: &[Self], mut damage: Vec<Rectangle>) -> Vec<Rectangle> {
+        let extended: Vec<_> = layers
+            .iter()
+            .flat_map(|layer| &layer.backdrops)
+            .filter(|backdrop| {
+                damage
+                    .iter()
+                    .any(|region| region.intersects(&backdrop.bounds))
+            })
+            .map(|backdrop| {
+                backdrop
+                    .bounds
+                    .expand(crate::engine::blur_extent(backdrop.blur_radius) + 1.0)
+            })
+            .collect();
+
+        damage.extend(extended);
+        damage
+    }
+
     pub fn damage(previous: &Self, current: &Self) -> Vec<Rectangle> {
         if previous.bounds != current.bounds {
             return vec![previous.bounds, current.bounds];
         }
 
+        let backdrops = damage::list(
+            &previous.backdrops,
+            &current.backdrops,
+            |backdrop| vec![backdrop.bounds.expand(1.0)],
+            Backdrop::eq,
+        );
+
         let mut damage = damage::list(
             &previous.quads,
             &current.quads,
@@ -282,6 +336,7 @@ impl Layer {
             Image::eq,
         );
 
+        damage.extend(backdrops);
         damage.extend(text);
         damage.extend(primitives);
         damage.extend(images);
@@ -293,10 +348,12 @@ impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            backdrops: Vec::new(),
             quads: Vec::new(),
             primitives: Vec::new(),
             text: Vec::new(),
             images: Vec::new(),
+            image_backgrounds: Vec::new(),
         }
     }
 }
@@ -322,13 +379,21 @@ impl graphics::Layer for Layer {
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
 
+        self.backdrops.clear();
         self.quads.clear();
         self.primitives.clear();
         self.text.clear();
         self.images.clear();
+        self.image_backgrounds.clear();
     }
 
     fn start(&self) -> usize {
+        // Backdrops must be applied before anything else; so nothing
+        // can be merged below them
+        if !self.backdrops.is_empty() {
+            return 0;
+        }
+
         if !self.quads.is_empty() {
             return 1;
         }
@@ -365,14 +430,25 @@ impl graphics::Layer for Layer {
             return 1;
         }
 
+        // A layer with only backdrops is not empty
+        if !self.backdrops.is_empty() {
+            return 1;
+        }
+
         0
     }
 
     fn merge(&mut self, layer: &mut Self) {
+        self.backdrops.append(&mut layer.backdrops);
         self.quads.append(&mut layer.quads);
         self.primitives.append(&mut layer.primitives);
         self.text.append(&mut layer.text);
         self.images.append(&mut layer.images);
+        self.image_backgrounds.append(&mut layer.image_backgrounds);
+    }
+
+    fn draw_backdrop(&mut self, backdrop: Backdrop) {
+        self.backdrops.push(backdrop);
     }
 }
 
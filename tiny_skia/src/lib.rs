@@ -3,6 +3,7 @@
 pub mod window;
 
 mod engine;
+mod gradient;
 mod layer;
 mod primitive;
 mod settings;
@@ -107,6 +108,15 @@ impl Renderer {
 
                 engine::adjust_clip_mask(clip_mask, layer_bounds);
 
+                for backdrop in &layer.backdrops {
+                    self.engine.draw_backdrop(
+                        backdrop,
+                        Transformation::scale(scale_factor),
+                        pixels,
+                        layer_bounds,
+                    );
+                }
+
                 if !layer.quads.is_empty() {
                     let render_span = debug::render(debug::Primitive::Quad);
                     for (quad, background) in &layer.quads {
@@ -155,13 +165,39 @@ impl Renderer {
                     let render_span = debug::render(debug::Primitive::Image);
 
                     for image in &layer.images {
+                        let Some(image_bounds) =
+                            (image.clip_bounds() * scale_factor).intersection(&layer_bounds)
+                        else {
+                            continue;
+                        };
+
+                        let border_radius = engine::border_radius(image) * scale_factor;
+                        let is_rounded = border_radius != core::border::Radius::default();
+                        let is_clipped = is_rounded || image_bounds != layer_bounds;
+
+                        if is_clipped {
+                            engine::adjust_clip_mask(clip_mask, image_bounds);
+                        }
+
+                        if is_rounded {
+                            engine::round_clip_mask(
+                                clip_mask,
+                                image.clip_bounds() * scale_factor,
+                                border_radius,
+                            );
+                        }
+
                         self.engine.draw_image(
                             image,
                             Transformation::scale(scale_factor),
                             pixels,
                             clip_mask,
-                            layer_bounds,
+                            image_bounds,
                         );
+
+                        if is_clipped {
+                            engine::adjust_clip_mask(clip_mask, layer_bounds);
+                        }
                     }
 
                     render_span.finish();
@@ -189,6 +225,33 @@ impl Renderer {
 
         self.engine.trim();
     }
+
+    /// Splits the current layer if the given bounds overlap any of its image
+    /// backgrounds.
+    ///
+    /// Images are drawn after the quads and primitives of a layer; anything
+    /// else can be reordered freely while it does not overlap them.
+    fn draw_over_image_backgrounds(&mut self, bounds: Rectangle) {
+        let (layer, transformation) = self.layers.current_mut();
+
+        if layer.is_over_image_background(bounds, transformation) {
+            self.layers.split();
+        }
+    }
+}
+
+/// Returns the bounds of a [`Quad`], including its shadow.
+///
+/// [`Quad`]: renderer::Quad
+fn visible_bounds(quad: &renderer::Quad) -> Rectangle {
+    let shadow = quad.shadow;
+
+    if shadow.inset || shadow.color.a == 0.0 {
+        return quad.bounds;
+    }
+
+    quad.bounds
+        .union(&(quad.bounds + shadow.offset).expand(shadow.blur_radius))
 }
 
 impl core::Renderer for Renderer {
@@ -209,8 +272,45 @@ impl core::Renderer for Renderer {
     }
 
     fn fill_quad(&mut self, quad: renderer::Quad, background: impl Into<Background>) {
+        let background = background.into();
+
+        #[cfg(not(feature = "image"))]
+        if let Background::Image(_) = &background {
+            static WARNING: std::sync::Once = std::sync::Once::new();
+
+            WARNING.call_once(|| {
+                log::warn!(
+                    "Image backgrounds need the `image` feature of `iced_tiny_skia`; \
+                    drawing them as transparent"
+                );
+            });
+        }
+
+        self.draw_over_image_backgrounds(visible_bounds(&quad));
+
+        #[cfg(feature = "image")]
+        if let Background::Image(image) = &background {
+            let bounds = quad.bounds.shrink(quad.border.width);
+
+            let (layer, transformation) = self.layers.current_mut();
+            layer.draw_quad(quad, Background::Color(Color::TRANSPARENT), transformation);
+            layer.draw_image_background(bounds, transformation);
+
+            image.draw(self, bounds, quad.border.radius);
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
-        layer.draw_quad(quad, background.into(), transformation);
+        layer.draw_quad(quad, background, transformation);
+    }
+
+    fn draw_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: core::border::Radius,
+        backdrop: core::Backdrop,
+    ) {
+        self.layers.draw_backdrop(bounds, border_radius, backdrop);
     }
 
     fn reset(&mut self, new_bounds: Rectangle) {
@@ -315,8 +415,6 @@ impl graphics::geometry::Renderer for Renderer {
     }
 
     fn draw_geometry(&mut self, geometry: Self::Geometry) {
-        let (layer, transformation) = self.layers.current_mut();
-
         match geometry {
             Geometry::Live {
                 primitives,
@@ -324,6 +422,9 @@ impl graphics::geometry::Renderer for Renderer {
                 text,
                 clip_bounds,
             } => {
+                self.draw_over_image_backgrounds(clip_bounds);
+
+                let (layer, transformation) = self.layers.current_mut();
                 layer.draw_primitive_group(primitives, clip_bounds, transformation);
 
                 for image in images {
@@ -333,6 +434,9 @@ impl graphics::geometry::Renderer for Renderer {
                 layer.draw_text_group(text, clip_bounds, transformation);
             }
             Geometry::Cache(cache) => {
+                self.draw_over_image_backgrounds(cache.clip_bounds);
+
+                let (layer, transformation) = self.layers.current_mut();
                 layer.draw_primitive_cache(cache.primitives, cache.clip_bounds, transformation);
 
                 for image in cache.images.iter() {
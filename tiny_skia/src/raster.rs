@@ -38,6 +38,7 @@ impl Pipeline {
         handle: &raster::Handle,
         filter_method: raster::FilterMethod,
         bounds: Rectangle,
+        repeat: Option<Size<f32>>,
         opacity: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         transform: tiny_skia::Transform,
@@ -49,16 +50,48 @@ impl Pipeline {
             return;
         };
 
-        let width_scale = bounds.width / image.width() as f32;
-        let height_scale = bounds.height / image.height() as f32;
-
-        let transform = transform.pre_scale(width_scale, height_scale);
-
         let quality = match filter_method {
             raster::FilterMethod::Linear => tiny_skia::FilterQuality::Bilinear,
             raster::FilterMethod::Nearest => tiny_skia::FilterQuality::Nearest,
         };
 
+        if let Some(tile) = repeat {
+            let Some(rect) =
+                tiny_skia::Rect::from_xywh(bounds.x, bounds.y, bounds.width, bounds.height)
+            else {
+                return;
+            };
+
+            let pattern = tiny_skia::Pattern::new(
+                image,
+                tiny_skia::SpreadMode::Repeat,
+                quality,
+                opacity,
+                tiny_skia::Transform::from_translate(bounds.x, bounds.y).pre_scale(
+                    tile.width / image.width() as f32,
+                    tile.height / image.height() as f32,
+                ),
+            );
+
+            pixels.fill_rect(
+                rect,
+                &tiny_skia::Paint {
+                    shader: pattern,
+                    anti_alias: false,
+                    ..tiny_skia::Paint::default()
+                },
+                transform,
+                clip_mask,
+            );
+
+            return;
+        }
+
+        let width_scale = bounds.width / image.width() as f32;
+        let height_scale = bounds.height / image.height() as f32;
+
+        let transform = transform.pre_scale(width_scale, height_scale);
+
         pixels.draw_pixmap(
             (bounds.x / width_scale) as i32,
             (bounds.y / height_scale) as i32,
@@ -0,0 +1,491 @@
+use crate::core::{Rectangle, Size};
+use crate::graphics::layer::Backdrop;
+
+use std::num::NonZeroU64;
+
+/// The distance a blur with the given standard deviation reaches.
+fn extent(sigma: f32) -> f32 {
+    (sigma * 3.0).ceil()
+}
+
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    sampler: wgpu::BindGroup,
+    texture_layout: wgpu::BindGroupLayout,
+    uniforms_layout: wgpu::BindGroupLayout,
+    blur: wgpu::RenderPipeline,
+    composite: wgpu::RenderPipeline,
+    blit: wgpu::RenderPipeline,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let sampler_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("iced_wgpu::backdrop sampler layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            }],
+        });
+
+        let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("iced_wgpu::backdrop texture layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let uniforms_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("iced_wgpu::backdrop uniforms layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: wgpu::BufferSize::new(std::mem::size_of::<Uniforms>() as u64),
+                },
+                count: None,
+            }],
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("iced_wgpu::backdrop sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let sampler = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::backdrop sampler bind group"),
+            layout: &sampler_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Sampler(&sampler),
+            }],
+        });
+
+        let filter_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("iced_wgpu::backdrop filter pipeline layout"),
+            push_constant_ranges: &[],
+            bind_group_layouts: &[&sampler_layout, &texture_layout, &uniforms_layout],
+        });
+
+        let blit_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("iced_wgpu::backdrop blit pipeline layout"),
+            push_constant_ranges: &[],
+            bind_group_layouts: &[&sampler_layout, &texture_layout],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("iced_wgpu::backdrop shader"),
+            source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(include_str!(
+                "shader/backdrop.wgsl"
+            ))),
+        });
+
+        let pipeline = |label, layout, entry_point, blend| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some(entry_point),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+                cache: None,
+            })
+        };
+
+        Self {
+            format,
+            sampler,
+            blur: pipeline(
+                "iced_wgpu::backdrop blur pipeline",
+                &filter_layout,
+                "fs_blur",
+                None,
+            ),
+            composite: pipeline(
+                "iced_wgpu::backdrop composite pipeline",
+                &filter_layout,
+                "fs_composite",
+                Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            ),
+            blit: pipeline(
+                "iced_wgpu::backdrop blit pipeline",
+                &blit_layout,
+                "fs_blit",
+                Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            ),
+            texture_layout,
+            uniforms_layout,
+        }
+    }
+}
+
+/// The offscreen textures used while backdrops are being rendered.
+///
+/// Everything is drawn to the `target` first, since backdrops need to
+/// sample it; and then blitted to the actual frame.
+#[derive(Debug, Clone)]
+pub struct Targets {
+    pub target: wgpu::TextureView,
+    target_bind_group: wgpu::BindGroup,
+    scratch: wgpu::TextureView,
+    scratch_bind_group: wgpu::BindGroup,
+    size: Size<u32>,
+}
+
+impl Targets {
+    fn new(device: &wgpu::Device, pipeline: &Pipeline, size: Size<u32>) -> Self {
+        let texture = |label| {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d {
+                    width: size.width,
+                    height: size.height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: pipeline.format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("iced_wgpu::backdrop texture bind group"),
+                layout: &pipeline.texture_layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                }],
+            });
+
+            (view, bind_group)
+        };
+
+        let (target, target_bind_group) = texture("iced_wgpu::backdrop target");
+        let (scratch, scratch_bind_group) = texture("iced_wgpu::backdrop scratch");
+
+        Self {
+            target,
+            target_bind_group,
+            scratch,
+            scratch_bind_group,
+            size,
+        }
+    }
+
+    /// Blits the target into the given frame.
+    pub fn blit(
+        &self,
+        pipeline: &Pipeline,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+    ) {
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_wgpu::backdrop blit render pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: frame,
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&pipeline.blit);
+        render_pass.set_bind_group(0, &pipeline.sampler, &[]);
+        render_pass.set_bind_group(1, &self.target_bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+struct Uniforms {
+    bounds: [f32; 4],
+    border_radius: [f32; 4],
+    direction: [f32; 2],
+    sigma: f32,
+    saturation: f32,
+}
+
+#[derive(Debug)]
+struct Pass {
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Pass {
+    fn new(device: &wgpu::Device, pipeline: &Pipeline) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::backdrop uniforms buffer"),
+            size: std::mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::backdrop uniforms bind group"),
+            layout: &pipeline.uniforms_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+
+        Self { buffer, bind_group }
+    }
+
+    fn write(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        belt: &mut wgpu::util::StagingBelt,
+        uniforms: Uniforms,
+    ) {
+        belt.write_buffer(
+            encoder,
+            &self.buffer,
+            0,
+            NonZeroU64::new(std::mem::size_of::<Uniforms>() as u64).expect("non-empty uniforms"),
+            device,
+        )
+        .copy_from_slice(bytemuck::bytes_of(&uniforms));
+    }
+}
+
+/// A prepared [`Backdrop`]; blurred horizontally and then vertically.
+#[derive(Debug)]
+struct Prepared {
+    horizontal: Pass,
+    vertical: Pass,
+    bounds: Rectangle,
+    source: Rectangle,
+}
+
+#[derive(Debug, Default)]
+pub struct State {
+    prepared: Vec<Prepared>,
+    prepare_index: usize,
+    targets: Option<Targets>,
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the [`Targets`] of the given size, creating them if needed.
+    pub fn targets(
+        &mut self,
+        device: &wgpu::Device,
+        pipeline: &Pipeline,
+        size: Size<u32>,
+    ) -> Targets {
+        match &self.targets {
+            Some(targets) if targets.size == size => targets.clone(),
+            _ => {
+                let targets = Targets::new(device, pipeline, size);
+                self.targets = Some(targets.clone());
+
+                targets
+            }
+        }
+    }
+
+    pub fn prepare(
+        &mut self,
+        pipeline: &Pipeline,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        belt: &mut wgpu::util::StagingBelt,
+        backdrops: &[Backdrop],
+        scale_factor: f32,
+    ) {
+        for backdrop in backdrops {
+            if self.prepared.len() <= self.prepare_index {
+                self.prepared.push(Prepared {
+                    horizontal: Pass::new(device, pipeline),
+                    vertical: Pass::new(device, pipeline),
+                    bounds: Rectangle::default(),
+                    source: Rectangle::default(),
+                });
+            }
+
+            let prepared = &mut self.prepared[self.prepare_index];
+
+            let bounds = backdrop.bounds * scale_factor;
+            let border_radius = backdrop.border_radius.map(|radius| radius * scale_factor);
+
+            // The blur radius covers two standard deviations
+            let sigma = backdrop.blur_radius * scale_factor / 2.0;
+
+            let uniforms = Uniforms {
+                bounds: [bounds.x, bounds.y, bounds.width, bounds.height],
+                border_radius,
+                direction: [1.0, 0.0],
+                sigma,
+                saturation: backdrop.saturation,
+            };
+
+            prepared.horizontal.write(device, encoder, belt, uniforms);
+
+            prepared.vertical.write(
+                device,
+                encoder,
+                belt,
+                Uniforms {
+                    direction: [0.0, 1.0],
+                    ..uniforms
+                },
+            );
+
+            // The vertical pass samples beyond the bounds
+            let extent = extent(sigma);
+
+            prepared.bounds = bounds;
+            prepared.source = Rectangle {
+                y: bounds.y - extent,
+                height: bounds.height + extent * 2.0,
+                ..bounds
+            };
+
+            self.prepare_index += 1;
+        }
+    }
+
+    /// Renders the given number of prepared backdrops, starting at `index`,
+    /// into the given [`Targets`].
+    pub fn render(
+        &self,
+        pipeline: &Pipeline,
+        encoder: &mut wgpu::CommandEncoder,
+        targets: &Targets,
+        index: usize,
+        count: usize,
+        clip_bounds: Rectangle,
+    ) {
+        let viewport = Rectangle::new(
+            crate::core::Point::ORIGIN,
+            Size::new(targets.size.width as f32, targets.size.height as f32),
+        );
+
+        for prepared in self.prepared.iter().skip(index).take(count) {
+            let Some(source) = prepared
+                .source
+                .intersection(&viewport)
+                .and_then(Rectangle::snap)
+            else {
+                continue;
+            };
+
+            let Some(bounds) = prepared
+                .bounds
+                .intersection(&clip_bounds)
+                .and_then(Rectangle::snap)
+            else {
+                continue;
+            };
+
+            {
+                let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("iced_wgpu::backdrop blur render pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &targets.scratch,
+                        depth_slice: None,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load,
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+
+                render_pass.set_scissor_rect(source.x, source.y, source.width, source.height);
+                render_pass.set_pipeline(&pipeline.blur);
+                render_pass.set_bind_group(0, &pipeline.sampler, &[]);
+                render_pass.set_bind_group(1, &targets.target_bind_group, &[]);
+                render_pass.set_bind_group(2, &prepared.horizontal.bind_group, &[]);
+                render_pass.draw(0..3, 0..1);
+            }
+
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_wgpu::backdrop composite render pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &targets.target,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_scissor_rect(bounds.x, bounds.y, bounds.width, bounds.height);
+            render_pass.set_pipeline(&pipeline.composite);
+            render_pass.set_bind_group(0, &pipeline.sampler, &[]);
+            render_pass.set_bind_group(1, &targets.scratch_bind_group, &[]);
+            render_pass.set_bind_group(2, &prepared.vertical.bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
+    }
+
+    pub fn trim(&mut self) {
+        self.prepare_index = 0;
+    }
+}
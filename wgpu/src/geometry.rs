@@ -565,6 +565,35 @@ impl Transform {
                 linear.start = self.transform_point(linear.start);
                 linear.end = self.transform_point(linear.end);
             }
+            Gradient::Radial(radial) => {
+                // The radii of a packed gradient are aligned with the axes; so
+                // we approximate any rotation or skew with the bounding ellipse
+                // of the transformed one, which is exact for circles rotated by
+                // a similarity
+                let center = self.transform_point(radial.center);
+
+                let horizontal = self
+                    .transform_point(radial.center + Vector::new(radial.radii.width, 0.0))
+                    - center;
+                let vertical = self
+                    .transform_point(radial.center + Vector::new(0.0, radial.radii.height))
+                    - center;
+
+                radial.center = center;
+                radial.radii = Size::new(
+                    horizontal.x.hypot(vertical.x),
+                    horizontal.y.hypot(vertical.y),
+                );
+            }
+            Gradient::Conic(conic) => {
+                // Only similarities keep the angles of a conic gradient;
+                // any other transform just moves its center and rotates it
+                let center = self.transform_point(conic.center);
+                let right = self.transform_point(conic.center + Vector::new(1.0, 0.0)) - center;
+
+                conic.center = center;
+                conic.angle += Radians(right.y.atan2(right.x));
+            }
         }
 
         gradient
@@ -136,6 +136,8 @@ impl Pipeline {
                         8 => Sint32,
                         // Snap
                         9 => Uint32,
+                        // Repeat
+                        10 => Float32x2,
                     ),
                 }],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
@@ -264,6 +266,7 @@ impl State {
                             f32::from(image.rotation),
                             image.opacity,
                             image.snap,
+                            image.repeat,
                             atlas_entry,
                             match image.filter_method {
                                 crate::core::image::FilterMethod::Nearest => {
@@ -313,6 +316,7 @@ impl State {
                             f32::from(svg.rotation),
                             svg.opacity,
                             true,
+                            None,
                             atlas_entry,
                             &mut self.nearest_instances,
                         );
@@ -572,6 +576,7 @@ struct Instance {
     _size_in_atlas: [f32; 2],
     _layer: u32,
     _snap: u32,
+    _repeat: [f32; 2],
 }
 
 impl Instance {
@@ -595,6 +600,7 @@ fn add_instances(
     rotation: f32,
     opacity: f32,
     snap: bool,
+    repeat: Option<Size<f32>>,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
@@ -614,6 +620,11 @@ fn add_instances(
 
     match entry {
         atlas::Entry::Contiguous(allocation) => {
+            // The shader wraps around the allocation as many times as needed
+            let repeat = repeat.map_or([0.0, 0.0], |tile| {
+                [bounds.width / tile.width, bounds.height / tile.height]
+            });
+
             add_instance(
                 center,
                 clip_bounds,
@@ -622,41 +633,57 @@ fn add_instances(
                 rotation,
                 opacity,
                 snap,
+                repeat,
                 allocation,
                 instances,
             );
         }
         atlas::Entry::Fragmented { fragments, size } => {
-            let scaling_x = bounds.width / size.width as f32;
-            let scaling_y = bounds.height / size.height as f32;
-
-            for fragment in fragments {
-                let allocation = &fragment.allocation;
-                let (fragment_x, fragment_y) = fragment.position;
-
-                let Size {
-                    width: fragment_width,
-                    height: fragment_height,
-                } = allocation.size();
-
-                let tile = [
-                    bounds.x + fragment_x as f32 * scaling_x,
-                    bounds.y + fragment_y as f32 * scaling_y,
-                    fragment_width as f32 * scaling_x,
-                    fragment_height as f32 * scaling_y,
-                ];
-
-                add_instance(
-                    center,
-                    clip_bounds,
-                    border_radius,
-                    tile,
-                    rotation,
-                    opacity,
-                    snap,
-                    allocation,
-                    instances,
-                );
+            // Fragmented images are too big to wrap around a single
+            // allocation; but they only need a few tiles
+            let tile = repeat.unwrap_or(bounds.size());
+
+            let columns = (bounds.width / tile.width).ceil().max(1.0) as usize;
+            let rows = (bounds.height / tile.height).ceil().max(1.0) as usize;
+
+            let scaling_x = tile.width / size.width as f32;
+            let scaling_y = tile.height / size.height as f32;
+
+            for row in 0..rows {
+                for column in 0..columns {
+                    let x = bounds.x + column as f32 * tile.width;
+                    let y = bounds.y + row as f32 * tile.height;
+
+                    for fragment in fragments {
+                        let allocation = &fragment.allocation;
+                        let (fragment_x, fragment_y) = fragment.position;
+
+                        let Size {
+                            width: fragment_width,
+                            height: fragment_height,
+                        } = allocation.size();
+
+                        let tile = [
+                            x + fragment_x as f32 * scaling_x,
+                            y + fragment_y as f32 * scaling_y,
+                            fragment_width as f32 * scaling_x,
+                            fragment_height as f32 * scaling_y,
+                        ];
+
+                        add_instance(
+                            center,
+                            clip_bounds,
+                            border_radius,
+                            tile,
+                            rotation,
+                            opacity,
+                            snap,
+                            [0.0, 0.0],
+                            allocation,
+                            instances,
+                        );
+                    }
+                }
             }
         }
     }
@@ -671,6 +698,7 @@ fn add_instance(
     rotation: f32,
     opacity: f32,
     snap: bool,
+    repeat: [f32; 2],
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
@@ -693,6 +721,7 @@ fn add_instance(
         ],
         _layer: layer as u32,
         _snap: snap as u32,
+        _repeat: repeat,
     };
 
     instances.push(instance);
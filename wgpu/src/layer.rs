@@ -2,7 +2,7 @@ use crate::core::{self, Background, Color, Point, Rectangle, Svg, Transformation
 use crate::graphics;
 use crate::graphics::Mesh;
 use crate::graphics::color;
-use crate::graphics::layer;
+use crate::graphics::layer::{self, Backdrop};
 use crate::graphics::mesh;
 use crate::graphics::text::{Editor, Paragraph};
 use crate::image::{self, Image};
@@ -16,6 +16,7 @@ pub type Stack = layer::Stack<Layer>;
 #[derive(Debug)]
 pub struct Layer {
     pub bounds: Rectangle,
+    pub backdrops: Vec<Backdrop>,
     pub quads: quad::Batch,
     pub triangles: triangle::Batch,
     pub primitives: primitive::Batch,
@@ -23,11 +24,13 @@ pub struct Layer {
     pub text: text::Batch,
     pending_meshes: Vec<Mesh>,
     pending_text: Vec<Text>,
+    image_backgrounds: Vec<Rectangle>,
 }
 
 impl Layer {
     pub fn is_empty(&self) -> bool {
-        self.quads.is_empty()
+        self.backdrops.is_empty()
+            && self.quads.is_empty()
             && self.triangles.is_empty()
             && self.primitives.is_empty()
             && self.images.is_empty()
@@ -53,6 +56,7 @@ impl Layer {
             shadow_color: color::pack(quad.shadow.color),
             shadow_offset: (quad.shadow.offset * transformation.scale_factor()).into(),
             shadow_blur_radius: quad.shadow.blur_radius * transformation.scale_factor(),
+            shadow_inset: quad.shadow.inset as u32,
             snap: quad.snap as u32,
         };
 
@@ -149,6 +153,31 @@ impl Layer {
         }
     }
 
+    /// Records the bounds of an image background drawn in the [`Layer`].
+    #[cfg(feature = "image")]
+    pub fn draw_image_background(&mut self, bounds: Rectangle, transformation: Transformation) {
+        self.image_backgrounds.push(bounds * transformation);
+    }
+
+    /// Returns whether the given bounds overlap any image background of the
+    /// [`Layer`]; assuming they do if unknown.
+    pub fn is_over_image_background(
+        &self,
+        bounds: Option<Rectangle>,
+        transformation: Transformation,
+    ) -> bool {
+        match bounds {
+            Some(bounds) => {
+                let bounds = bounds * transformation;
+
+                self.image_backgrounds
+                    .iter()
+                    .any(|background| background.intersects(&bounds))
+            }
+            None => !self.image_backgrounds.is_empty(),
+        }
+    }
+
     pub fn draw_raster(
         &mut self,
         image: core::Image,
@@ -159,6 +188,9 @@ impl Layer {
         let image = Image::Raster {
             image: core::Image {
                 border_radius: image.border_radius * transformation.scale_factor(),
+                repeat: image
+                    .repeat
+                    .map(|tile| tile * transformation.scale_factor()),
                 ..image
             },
             bounds: bounds * transformation,
@@ -292,6 +324,7 @@ impl graphics::Layer for Layer {
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
 
+        self.backdrops.clear();
         self.quads.clear();
         self.triangles.clear();
         self.primitives.clear();
@@ -299,9 +332,16 @@ impl graphics::Layer for Layer {
         self.images.clear();
         self.pending_meshes.clear();
         self.pending_text.clear();
+        self.image_backgrounds.clear();
     }
 
     fn start(&self) -> usize {
+        // Backdrops must be applied before anything else; so nothing
+        // can be merged below them
+        if !self.backdrops.is_empty() {
+            return 0;
+        }
+
         if !self.quads.is_empty() {
             return 1;
         }
@@ -346,15 +386,26 @@ impl graphics::Layer for Layer {
             return 1;
         }
 
+        // A layer with only backdrops is not empty
+        if !self.backdrops.is_empty() {
+            return 1;
+        }
+
         0
     }
 
     fn merge(&mut self, layer: &mut Self) {
+        self.backdrops.append(&mut layer.backdrops);
         self.quads.append(&mut layer.quads);
         self.triangles.append(&mut layer.triangles);
         self.primitives.append(&mut layer.primitives);
         self.images.append(&mut layer.images);
         self.text.append(&mut layer.text);
+        self.image_backgrounds.append(&mut layer.image_backgrounds);
+    }
+
+    fn draw_backdrop(&mut self, backdrop: Backdrop) {
+        self.backdrops.push(backdrop);
     }
 }
 
@@ -362,6 +413,7 @@ impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            backdrops: Vec::new(),
             quads: quad::Batch::default(),
             triangles: triangle::Batch::default(),
             primitives: primitive::Batch::default(),
@@ -369,6 +421,7 @@ impl Default for Layer {
             images: image::Batch::default(),
             pending_meshes: Vec::new(),
             pending_text: Vec::new(),
+            image_backgrounds: Vec::new(),
         }
     }
 }
@@ -30,6 +30,7 @@ pub mod window;
 #[cfg(feature = "geometry")]
 pub mod geometry;
 
+mod backdrop;
 mod buffer;
 mod color;
 mod engine;
@@ -80,6 +81,7 @@ pub struct Renderer {
     scale_factor: Option<f32>,
 
     quad: quad::State,
+    backdrop: backdrop::State,
     triangle: triangle::State,
     text: text::State,
     text_viewport: text::Viewport,
@@ -103,6 +105,7 @@ impl Renderer {
             scale_factor: None,
 
             quad: quad::State::new(),
+            backdrop: backdrop::State::new(),
             triangle: triangle::State::new(&engine.device, &engine.triangle_pipeline),
             text: text::State::new(),
             text_viewport: engine.text_pipeline.create_viewport(&engine.device),
@@ -139,6 +142,7 @@ impl Renderer {
         self.render(&mut encoder, target, clear_color, viewport);
 
         self.quad.trim();
+        self.backdrop.trim();
         self.triangle.trim();
         self.text.trim();
 
@@ -154,6 +158,13 @@ impl Renderer {
         encoder
     }
 
+    /// Draws the current primitives on the given `frame`.
+    ///
+    /// With a `clear_color`, the frame is cleared before drawing. Otherwise,
+    /// everything is drawn on top of its contents; which cannot be sampled
+    /// and, therefore, any [`Backdrop`] is skipped.
+    ///
+    /// [`Backdrop`]: core::Backdrop
     pub fn present(
         &mut self,
         clear_color: Option<Color>,
@@ -298,6 +309,17 @@ impl Renderer {
                 continue;
             }
 
+            if !layer.backdrops.is_empty() {
+                self.backdrop.prepare(
+                    &self.engine.backdrop_pipeline,
+                    &self.engine.device,
+                    encoder,
+                    &mut self.staging_belt,
+                    &layer.backdrops,
+                    scale_factor,
+                );
+            }
+
             if !layer.quads.is_empty() {
                 let prepare_span = debug::prepare(debug::Primitive::Quad);
 
@@ -399,11 +421,28 @@ impl Renderer {
     ) {
         use std::mem::ManuallyDrop;
 
+        // Backdrops need to sample anything drawn before them, so we draw
+        // everything offscreen first and blit it to the frame at the end.
+        //
+        // Without a clear color, we must draw on top of the contents of the
+        // frame; which we cannot sample. Backdrops are skipped in that case.
+        let targets = (clear_color.is_some()
+            && self.layers.iter().any(|layer| !layer.backdrops.is_empty()))
+        .then(|| {
+            self.backdrop.targets(
+                &self.engine.device,
+                &self.engine.backdrop_pipeline,
+                viewport.physical_size(),
+            )
+        });
+
+        let target = targets.as_ref().map_or(frame, |targets| &targets.target);
+
         let mut render_pass =
             ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("iced_wgpu render pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: frame,
+                    view: target,
                     depth_slice: None,
                     resolve_target: None,
                     ops: wgpu::Operations {
@@ -429,6 +468,7 @@ impl Renderer {
                 occlusion_query_set: None,
             }));
 
+        let mut backdrop_layer = 0;
         let mut quad_layer = 0;
         let mut mesh_layer = 0;
         let mut text_layer = 0;
@@ -453,6 +493,40 @@ impl Renderer {
                 continue;
             };
 
+            if let Some(targets) = &targets
+                && !layer.backdrops.is_empty()
+            {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                self.backdrop.render(
+                    &self.engine.backdrop_pipeline,
+                    encoder,
+                    targets,
+                    backdrop_layer,
+                    layer.backdrops.len(),
+                    physical_bounds,
+                );
+
+                backdrop_layer += layer.backdrops.len();
+
+                render_pass =
+                    ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu render pass"),
+                        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                            view: target,
+                            depth_slice: None,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: wgpu::StoreOp::Store,
+                            },
+                        })],
+                        depth_stencil_attachment: None,
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                    }));
+            }
+
             if !layer.quads.is_empty() {
                 let render_span = debug::render(debug::Primitive::Quad);
                 self.quad.render(
@@ -474,7 +548,7 @@ impl Renderer {
                 mesh_layer += self.triangle.render(
                     &self.engine.triangle_pipeline,
                     encoder,
-                    frame,
+                    target,
                     mesh_layer,
                     &layer.triangles,
                     physical_bounds,
@@ -486,7 +560,7 @@ impl Renderer {
                     ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                         label: Some("iced_wgpu render pass"),
                         color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                            view: frame,
+                            view: target,
                             depth_slice: None,
                             resolve_target: None,
                             ops: wgpu::Operations {
@@ -564,16 +638,19 @@ impl Renderer {
                     let _ = ManuallyDrop::into_inner(render_pass);
 
                     for (instance, clip_bounds) in need_render {
-                        instance
-                            .primitive
-                            .render(&primitive_storage, encoder, frame, &clip_bounds);
+                        instance.primitive.render(
+                            &primitive_storage,
+                            encoder,
+                            target,
+                            &clip_bounds,
+                        );
                     }
 
                     render_pass =
                         ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                             label: Some("iced_wgpu render pass"),
                             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                                view: frame,
+                                view: target,
                                 depth_slice: None,
                                 resolve_target: None,
                                 ops: wgpu::Operations {
@@ -620,6 +697,10 @@ impl Renderer {
 
         let _ = ManuallyDrop::into_inner(render_pass);
 
+        if let Some(targets) = targets {
+            targets.blit(&self.engine.backdrop_pipeline, encoder, frame);
+        }
+
         debug::layers_rendered(|| {
             self.layers
                 .iter()
@@ -632,6 +713,33 @@ impl Renderer {
                 .count()
         });
     }
+
+    /// Starts a new layer if the given bounds overlap an image background of
+    /// the current one; assuming they do if unknown.
+    ///
+    /// Images are drawn after the quads, meshes and primitives of a layer;
+    /// anything else can be reordered freely while it does not overlap them.
+    fn draw_over_image_backgrounds(&mut self, bounds: Option<Rectangle>) {
+        let (layer, transformation) = self.layers.current_mut();
+
+        if layer.is_over_image_background(bounds, transformation) {
+            self.layers.split();
+        }
+    }
+}
+
+/// Returns the bounds of a [`Quad`], including its shadow.
+///
+/// [`Quad`]: core::renderer::Quad
+fn visible_bounds(quad: &core::renderer::Quad) -> Rectangle {
+    let shadow = quad.shadow;
+
+    if shadow.inset || shadow.color.a == 0.0 {
+        return quad.bounds;
+    }
+
+    quad.bounds
+        .union(&(quad.bounds + shadow.offset).expand(shadow.blur_radius))
 }
 
 impl core::Renderer for Renderer {
@@ -652,8 +760,45 @@ impl core::Renderer for Renderer {
     }
 
     fn fill_quad(&mut self, quad: core::renderer::Quad, background: impl Into<Background>) {
+        let background = background.into();
+
+        #[cfg(not(feature = "image"))]
+        if let Background::Image(_) = &background {
+            static WARNING: std::sync::Once = std::sync::Once::new();
+
+            WARNING.call_once(|| {
+                log::warn!(
+                    "Image backgrounds need the `image` feature of `iced_wgpu`; \
+                    drawing them as transparent"
+                );
+            });
+        }
+
+        self.draw_over_image_backgrounds(Some(visible_bounds(&quad)));
+
+        #[cfg(feature = "image")]
+        if let Background::Image(image) = &background {
+            let bounds = quad.bounds.shrink(quad.border.width);
+
+            let (layer, transformation) = self.layers.current_mut();
+            layer.draw_quad(quad, Background::Color(Color::TRANSPARENT), transformation);
+            layer.draw_image_background(bounds, transformation);
+
+            image.draw(self, bounds, quad.border.radius);
+            return;
+        }
+
         let (layer, transformation) = self.layers.current_mut();
-        layer.draw_quad(quad, background.into(), transformation);
+        layer.draw_quad(quad, background, transformation);
+    }
+
+    fn draw_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: core::border::Radius,
+        backdrop: core::Backdrop,
+    ) {
+        self.layers.draw_backdrop(bounds, border_radius, backdrop);
     }
 
     fn reset(&mut self, new_bounds: Rectangle) {
@@ -791,11 +936,15 @@ impl graphics::mesh::Renderer for Renderer {
             "Mesh indices length must be a multiple of 3"
         );
 
+        self.draw_over_image_backgrounds(Some(mesh.clip_bounds()));
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_mesh(mesh, transformation);
     }
 
     fn draw_mesh_cache(&mut self, cache: mesh::Cache) {
+        self.draw_over_image_backgrounds(None);
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_mesh_cache(cache, transformation);
     }
@@ -811,6 +960,8 @@ impl graphics::geometry::Renderer for Renderer {
     }
 
     fn draw_geometry(&mut self, geometry: Self::Geometry) {
+        self.draw_over_image_backgrounds(None);
+
         let (layer, transformation) = self.layers.current_mut();
 
         match geometry {
@@ -848,6 +999,8 @@ impl graphics::geometry::Renderer for Renderer {
 
 impl primitive::Renderer for Renderer {
     fn draw_primitive(&mut self, bounds: Rectangle, primitive: impl Primitive) {
+        self.draw_over_image_backgrounds(Some(bounds));
+
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_primitive(bounds, primitive, transformation);
     }
@@ -4,7 +4,7 @@ mod solid;
 use gradient::Gradient;
 use solid::Solid;
 
-use crate::core::{Background, Rectangle, Transformation};
+use crate::core::{Background, Color, Rectangle, Transformation};
 use crate::graphics;
 use crate::graphics::color;
 
@@ -42,6 +42,9 @@ pub struct Quad {
     /// The shadow blur radius of the [`Quad`].
     pub shadow_blur_radius: f32,
 
+    /// Whether the shadow of the [`Quad`] is inset.
+    pub shadow_inset: u32,
+
     /// Whether the [`Quad`] should be snapped to the pixel grid.
     pub snap: u32,
 }
@@ -267,6 +270,15 @@ impl Batch {
 
                 Kind::Solid
             }
+            // Image backgrounds are drawn by the image pipeline
+            Background::Image(_) => {
+                self.solids.push(Solid {
+                    color: color::pack(Color::TRANSPARENT),
+                    quad,
+                });
+
+                Kind::Solid
+            }
             Background::Gradient(gradient) => {
                 self.gradients.push(Gradient {
                     gradient: graphics::gradient::pack(
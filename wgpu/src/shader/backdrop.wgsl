@@ -0,0 +1,93 @@
+struct Uniforms {
+    bounds: vec4<f32>,
+    border_radius: vec4<f32>,
+    direction: vec2<f32>,
+    sigma: f32,
+    saturation: f32,
+}
+
+@group(0) @binding(0) var u_sampler: sampler;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+@group(2) @binding(0) var<uniform> uniforms: Uniforms;
+
+const MAX_SAMPLES: f32 = 32.0;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    // A single triangle covering the whole target; scissors do the rest
+    let uv = vec2<f32>(f32((vertex_index << 1u) & 2u), f32(vertex_index & 2u));
+
+    return vec4<f32>(uv * vec2<f32>(2.0, -2.0) + vec2<f32>(-1.0, 1.0), 0.0, 1.0);
+}
+
+@fragment
+fn fs_blur(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    return blur(position.xy);
+}
+
+@fragment
+fn fs_composite(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let color = saturate_color(blur(position.xy), uniforms.saturation);
+
+    let size = uniforms.bounds.zw;
+
+    let dist = rounded_box_sdf(
+        (position.xy - uniforms.bounds.xy - size / 2.0) * 2.0,
+        size,
+        min(uniforms.border_radius, vec4(min(size.x, size.y) / 2.0)) * 2.0,
+    ) / 2.0;
+
+    return color * clamp(0.5 - dist, 0.0, 1.0);
+}
+
+@fragment
+fn fs_blit(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let size = vec2<f32>(textureDimensions(u_texture));
+
+    return textureSampleLevel(u_texture, u_sampler, position.xy / size, 0.0);
+}
+
+fn blur(position: vec2<f32>) -> vec4<f32> {
+    let size = vec2<f32>(textureDimensions(u_texture));
+
+    if uniforms.sigma <= 0.0 {
+        return textureSampleLevel(u_texture, u_sampler, position / size, 0.0);
+    }
+
+    let extent = ceil(uniforms.sigma * 3.0);
+    let samples = min(extent, MAX_SAMPLES);
+    let step = extent / samples;
+
+    var color = vec4<f32>(0.0);
+    var total = 0.0;
+
+    for (var i = -samples; i <= samples; i += 1.0) {
+        let offset = i * step;
+        let weight = exp(-(offset * offset) / (2.0 * uniforms.sigma * uniforms.sigma));
+
+        color += textureSampleLevel(
+            u_texture,
+            u_sampler,
+            (position + uniforms.direction * offset) / size,
+            0.0
+        ) * weight;
+
+        total += weight;
+    }
+
+    return color / total;
+}
+
+fn saturate_color(color: vec4<f32>, saturation: f32) -> vec4<f32> {
+    let luminance = dot(color.rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+    let rgb = mix(vec3<f32>(luminance), color.rgb, saturation);
+
+    return vec4<f32>(clamp(rgb, vec3<f32>(0.0), vec3<f32>(color.a)), color.a);
+}
+
+fn rounded_box_sdf(p: vec2<f32>, size: vec2<f32>, corners: vec4<f32>) -> f32 {
+    var box_half = select(corners.yz, corners.xw, p.x > 0.0);
+    var corner = select(box_half.y, box_half.x, p.y > 0.0);
+    var q = abs(p) - size + corner;
+    return min(max(q.x, q.y), 0.0) + length(max(q, vec2(0.0))) - corner;
+}
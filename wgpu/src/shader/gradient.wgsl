@@ -0,0 +1,37 @@
+const GRADIENT_LINEAR: u32 = 0u;
+const GRADIENT_RADIAL: u32 = 1u;
+const GRADIENT_CONIC: u32 = 2u;
+
+const TAU: f32 = 6.283185307179586;
+const FRAC_PI_2: f32 = 1.5707963267948966;
+
+/// Returns the offset along a gradient of the given kind at the given position
+///
+/// Linear: direction = start & end
+/// Radial: direction = center & radii
+/// Conic: direction = center & starting angle
+fn gradient_offset(raw_position: vec2<f32>, direction: vec4<f32>, kind: u32) -> f32 {
+    switch kind {
+        case GRADIENT_RADIAL: {
+            let radii = max(direction.zw, vec2<f32>(0.0001, 0.0001));
+
+            return length((raw_position - direction.xy) / radii);
+        }
+        case GRADIENT_CONIC: {
+            let v = raw_position - direction.xy;
+            let angle = atan2(v.y, v.x) + FRAC_PI_2 - direction.z;
+
+            return fract(angle / TAU);
+        }
+        default: {
+            let start = direction.xy;
+            let end = direction.zw;
+
+            let v1 = end - start;
+            let v2 = raw_position - start;
+            let unit = normalize(v1);
+
+            return dot(unit, v2) / length(v1);
+        }
+    }
+}
@@ -19,6 +19,7 @@ struct VertexInput {
     @location(7) atlas_scale: vec2<f32>,
     @location(8) layer: i32,
     @location(9) snap: u32,
+    @location(10) repeat: vec2<f32>,
 }
 
 struct VertexOutput {
@@ -28,7 +29,8 @@ struct VertexOutput {
     @location(2) @interpolate(flat) atlas: vec4<f32>,
     @location(3) @interpolate(flat) layer: i32,
     @location(4) @interpolate(flat) opacity: f32,
-    @location(5) uv: vec2<f32>,
+    @location(5) @interpolate(flat) is_repeated: u32,
+    @location(6) offset: vec2<f32>,
 }
 
 @vertex
@@ -78,12 +80,17 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.position = vec4(vec2(globals.scale_factor), 1.0, 1.0) * vec4<f32>(v_pos, 0.0, 1.0);
     out.clip_bounds = globals.scale_factor * input.clip_bounds;
 
-    // Calculate rotated UV
-    let uv = input.atlas_pos + (v_pos - tile.xy) / tile.zw * input.atlas_scale;
-    let uv_center = input.atlas_pos + input.atlas_scale / 2.0;
+    // Calculate the rotated offset in the atlas; spanning as many copies
+    // of the image as needed if it is repeated
+    let is_repeated = any(input.repeat > vec2<f32>(0.0));
+    let copies = select(vec2<f32>(1.0), input.repeat, is_repeated);
 
-    let d = uv - uv_center;
-    out.uv = vec2<f32>(d.x * cos_r - d.y * sin_r, d.x * sin_r + d.y * cos_r) + uv_center;
+    let offset = (v_pos - tile.xy) / tile.zw * copies * input.atlas_scale;
+    let offset_center = copies * input.atlas_scale / 2.0;
+
+    let d = offset - offset_center;
+    out.offset = vec2<f32>(d.x * cos_r - d.y * sin_r, d.x * sin_r + d.y * cos_r) + offset_center;
+    out.is_repeated = u32(is_repeated);
 
     // Snap position to the pixel grid
     if bool(input.snap) {
@@ -116,9 +123,24 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     ) / 2.0;
 
     let antialias: f32 = clamp(1.0 - d, 0.0, 1.0);
-    let inside = all(input.uv >= input.atlas.xy) && all(input.uv <= input.atlas.zw);
 
-    return textureSample(u_texture, u_sampler, input.uv, input.layer) * vec4<f32>(1.0, 1.0, 1.0, antialias * input.opacity * f32(inside));
+    let size = input.atlas.zw - input.atlas.xy;
+    let is_repeated = input.is_repeated == 1u;
+
+    // Repeated images wrap around, and are kept half a texel away from the
+    // edges of their allocation to avoid sampling their neighbors
+    let wrapped = input.offset - floor(input.offset / size) * size;
+    let offset = select(input.offset, wrapped, is_repeated);
+    let inside = all(offset >= vec2<f32>(0.0)) && all(offset <= size);
+
+    let texel = 0.5 / vec2<f32>(textureDimensions(u_texture));
+    let uv = select(
+        input.atlas.xy + offset,
+        clamp(input.atlas.xy + offset, input.atlas.xy + texel, input.atlas.zw - texel),
+        is_repeated,
+    );
+
+    return textureSample(u_texture, u_sampler, uv, input.layer) * vec4<f32>(1.0, 1.0, 1.0, antialias * input.opacity * f32(inside));
 }
 
 fn rounded_box_sdf(p: vec2<f32>, size: vec2<f32>, corners: vec4<f32>) -> f32 {
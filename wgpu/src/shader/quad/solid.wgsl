@@ -9,7 +9,8 @@ struct SolidVertexInput {
     @location(6) shadow_color: vec4<f32>,
     @location(7) shadow_offset: vec2<f32>,
     @location(8) shadow_blur_radius: f32,
-    @location(9) snap: u32,
+    @location(9) shadow_inset: u32,
+    @location(10) snap: u32,
 }
 
 struct SolidVertexOutput {
@@ -23,14 +24,19 @@ struct SolidVertexOutput {
     @location(6) shadow_color: vec4<f32>,
     @location(7) shadow_offset: vec2<f32>,
     @location(8) shadow_blur_radius: f32,
+    @location(9) @interpolate(flat) shadow_inset: u32,
 }
 
 @vertex
 fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
     var out: SolidVertexOutput;
 
-    var pos: vec2<f32> = (input.pos + min(input.shadow_offset, vec2<f32>(0.0, 0.0)) - input.shadow_blur_radius) * globals.scale;
-    var scale: vec2<f32> = (input.scale + vec2<f32>(abs(input.shadow_offset.x), abs(input.shadow_offset.y)) + input.shadow_blur_radius * 2.0) * globals.scale;
+    // Inset shadows are drawn inside the quad; so we do not need to grow it
+    let shadow_offset = select(input.shadow_offset, vec2<f32>(0.0, 0.0), bool(input.shadow_inset));
+    let shadow_blur_radius = select(input.shadow_blur_radius, 0.0, bool(input.shadow_inset));
+
+    var pos: vec2<f32> = (input.pos + min(shadow_offset, vec2<f32>(0.0, 0.0)) - shadow_blur_radius) * globals.scale;
+    var scale: vec2<f32> = (input.scale + vec2<f32>(abs(shadow_offset.x), abs(shadow_offset.y)) + shadow_blur_radius * 2.0) * globals.scale;
 
     var pos_snap = vec2<f32>(0.0, 0.0);
     var scale_snap = vec2<f32>(0.0, 0.0);
@@ -59,6 +65,7 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
     out.shadow_color = premultiply(input.shadow_color);
     out.shadow_offset = input.shadow_offset * globals.scale;
     out.shadow_blur_radius = input.shadow_blur_radius * globals.scale;
+    out.shadow_inset = input.shadow_inset;
 
     return out;
 }
@@ -67,7 +74,20 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
 fn solid_fs_main(
     input: SolidVertexOutput
 ) -> @location(0) vec4<f32> {
-    var mixed_color: vec4<f32> = input.color;
+    var background: vec4<f32> = input.color;
+
+    if input.shadow_inset != 0u && input.shadow_color.a > 0.0 {
+        var shadow_dist: f32 = rounded_box_sdf(
+            -(input.position.xy - input.pos - input.shadow_offset - input.scale/2.0) * 2.0,
+            input.scale,
+            input.border_radius * 2.0
+        ) / 2.0;
+        let shadow_alpha = smoothstep(-input.shadow_blur_radius - 0.5, input.shadow_blur_radius + 0.5, shadow_dist);
+
+        background = input.shadow_color * shadow_alpha + background * (1.0 - input.shadow_color.a * shadow_alpha);
+    }
+
+    var mixed_color: vec4<f32> = background;
 
     var dist = rounded_box_sdf(
         -(input.position.xy - input.pos - input.scale * 0.5) * 2.0,
@@ -77,7 +97,7 @@ fn solid_fs_main(
 
     if (input.border_width > 0.0) {
         mixed_color = mix(
-            input.color,
+            background,
             input.border_color,
             clamp(0.5 + dist + input.border_width, 0.0, 1.0)
         );
@@ -87,7 +107,7 @@ fn solid_fs_main(
 
     let quad_color = mixed_color * quad_alpha;
 
-    if input.shadow_color.a > 0.0 {
+    if input.shadow_color.a > 0.0 && input.shadow_inset == 0u {
         var shadow_dist: f32 = rounded_box_sdf(
             -(input.position.xy - input.pos - input.shadow_offset - input.scale/2.0) * 2.0,
             input.scale,
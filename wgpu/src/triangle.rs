@@ -836,6 +836,8 @@ mod gradient {
                     "\n",
                     include_str!("shader/triangle/gradient.wgsl"),
                     "\n",
+                    include_str!("shader/gradient.wgsl"),
+                    "\n",
                     include_str!("shader/color.wgsl"),
                     "\n",
                     include_str!("shader/color/linear_rgb.wgsl")
@@ -865,7 +867,9 @@ mod gradient {
                             // Offsets
                             5 => Uint32x4,
                             // Direction
-                            6 => Float32x4
+                            6 => Float32x4,
+                            // Kind
+                            7 => Uint32
                         ),
                     }],
                     compilation_options: wgpu::PipelineCompilationOptions::default(),
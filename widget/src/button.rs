@@ -16,19 +16,23 @@
 //!     button("Press me!").on_press(Message::ButtonPressed).into()
 //! }
 //! ```
+use crate::container;
 use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
 use crate::core::touch;
-use crate::core::widget::Operation;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Rectangle, Shadow,
-    Shell, Size, Theme, Vector, Widget,
+    Shell, Size, Theme, Vector, Widget, widget,
 };
 
 /// A generic widget that produces a message when pressed.
@@ -73,6 +77,7 @@ where
     Renderer: crate::core::Renderer,
     Theme: Catalog,
 {
+    id: Option<widget::Id>,
     content: Element<'a, Message, Theme, Renderer>,
     on_press: Option<OnPress<'a, Message>>,
     width: Length,
@@ -108,6 +113,7 @@ where
         let size = content.as_widget().size_hint();
 
         Button {
+            id: None,
             content,
             on_press: None,
             width: size.width.fluid(),
@@ -119,6 +125,12 @@ where
         }
     }
 
+    /// Sets the [`widget::Id`] of the [`Button`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the width of the [`Button`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -196,6 +208,22 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_pressed: bool,
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_pressed = false;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -248,7 +276,23 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
-        operation.container(None, layout.bounds());
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.container(self.id.as_ref(), layout.bounds());
+        operation.accessible(
+            self.id.as_ref(),
+            layout.bounds(),
+            &Accessible::new(Role::Button)
+                .disabled(self.on_press.is_none())
+                .focused(state.is_focused),
+        );
+
+        if self.on_press.is_some() {
+            operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        } else {
+            state.is_focused = false;
+        }
+
         operation.traverse(&mut |operation| {
             self.content.as_widget_mut().operate(
                 &mut tree.children[0],
@@ -288,12 +332,14 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.is_focused = false;
+
                 if self.on_press.is_some() {
                     let bounds = layout.bounds();
 
                     if cursor.is_over(bounds) {
-                        let state = tree.state.downcast_mut::<State>();
-
                         state.is_pressed = true;
 
                         shell.capture_event();
@@ -323,19 +369,48 @@ where
 
                 state.is_pressed = false;
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                repeat: false,
+                ..
+            }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if self.on_press.is_some() && state.is_focused {
+                    state.is_pressed = true;
+
+                    shell.capture_event();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                ..
+            }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if let Some(on_press) = &self.on_press
+                    && state.is_focused
+                    && state.is_pressed
+                {
+                    state.is_pressed = false;
+
+                    shell.publish(on_press.get());
+                    shell.capture_event();
+                }
+            }
             _ => {}
         }
 
+        let state = tree.state.downcast_ref::<State>();
+
         let current_status = if self.on_press.is_none() {
             Status::Disabled
+        } else if state.is_pressed && (state.is_focused || cursor.is_over(layout.bounds())) {
+            Status::Pressed
+        } else if state.is_focused {
+            Status::Focused
         } else if cursor.is_over(layout.bounds()) {
-            let state = tree.state.downcast_ref::<State>();
-
-            if state.is_pressed {
-                Status::Pressed
-            } else {
-                Status::Hovered
-            }
+            Status::Hovered
         } else {
             Status::Active
         };
@@ -361,8 +436,13 @@ where
         let content_layout = layout.children().next().unwrap();
         let style = theme.style(&self.class, self.status.unwrap_or(Status::Disabled));
 
-        if style.background.is_some() || style.border.width > 0.0 || style.shadow.color.a > 0.0 {
-            renderer.fill_quad(
+        if style.background.is_some()
+            || style.border.width > 0.0
+            || style.shadow.color.a > 0.0
+            || !style.shadows.is_empty()
+        {
+            container::fill_quad(
+                renderer,
                 renderer::Quad {
                     bounds,
                     border: style.border,
@@ -372,6 +452,7 @@ where
                 style
                     .background
                     .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                &style.shadows,
             );
         }
 
@@ -458,6 +539,8 @@ pub enum Status {
     Hovered,
     /// The [`Button`] is being pressed.
     Pressed,
+    /// The [`Button`] can be pressed and it has keyboard focus.
+    Focused,
     /// The [`Button`] cannot be pressed.
     Disabled,
 }
@@ -466,7 +549,7 @@ pub enum Status {
 ///
 /// If not specified with [`Button::style`]
 /// the theme will provide the style.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the button.
     pub background: Option<Background>,
@@ -476,6 +559,11 @@ pub struct Style {
     pub border: Border,
     /// The [`Shadow`] of the button.
     pub shadow: Shadow,
+    /// Additional [`Shadow`]s of the button.
+    ///
+    /// Outer shadows are layered below [`shadow`](Self::shadow) and inset
+    /// shadows on top of it, in order.
+    pub shadows: Vec<Shadow>,
     /// Whether the button should be snapped to the pixel grid.
     pub snap: bool,
 }
@@ -497,6 +585,7 @@ impl Default for Style {
             text_color: Color::BLACK,
             border: Border::default(),
             shadow: Shadow::default(),
+            shadows: Vec::new(),
             snap: renderer::CRISP,
         }
     }
@@ -588,6 +677,7 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.primary.strong.color)),
             ..base
         },
+        Status::Focused => focused(base, palette),
         Status::Disabled => disabled(base),
     }
 }
@@ -603,6 +693,7 @@ pub fn secondary(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.secondary.strong.color)),
             ..base
         },
+        Status::Focused => focused(base, palette),
         Status::Disabled => disabled(base),
     }
 }
@@ -618,6 +709,7 @@ pub fn success(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.success.strong.color)),
             ..base
         },
+        Status::Focused => focused(base, palette),
         Status::Disabled => disabled(base),
     }
 }
@@ -633,6 +725,7 @@ pub fn warning(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.warning.strong.color)),
             ..base
         },
+        Status::Focused => focused(base, palette),
         Status::Disabled => disabled(base),
     }
 }
@@ -648,6 +741,7 @@ pub fn danger(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.danger.strong.color)),
             ..base
         },
+        Status::Focused => focused(base, palette),
         Status::Disabled => disabled(base),
     }
 }
@@ -667,6 +761,7 @@ pub fn text(theme: &Theme, status: Status) -> Style {
             text_color: palette.background.base.text.scale_alpha(0.8),
             ..base
         },
+        Status::Focused => focused(base, palette),
         Status::Disabled => disabled(base),
     }
 }
@@ -686,6 +781,7 @@ pub fn background(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.background.weak.color)),
             ..base
         },
+        Status::Focused => focused(base, palette),
         Status::Disabled => disabled(base),
     }
 }
@@ -705,6 +801,7 @@ pub fn subtle(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.background.weaker.color)),
             ..base
         },
+        Status::Focused => focused(base, palette),
         Status::Disabled => disabled(base),
     }
 }
@@ -718,6 +815,17 @@ fn styled(pair: palette::Pair) -> Style {
     }
 }
 
+fn focused(style: Style, palette: &palette::Extended) -> Style {
+    Style {
+        border: Border {
+            color: palette.background.base.text,
+            width: 2.0,
+            ..style.border
+        },
+        ..style
+    }
+}
+
 fn disabled(style: Style) -> Style {
     Style {
         background: style
@@ -32,6 +32,8 @@
 //! ```
 //! ![Checkbox drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/checkbox.png?raw=true)
 use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -39,6 +41,8 @@ use crate::core::text;
 use crate::core::theme::palette;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -84,6 +88,7 @@ where
     Renderer: text::Renderer,
     Theme: Catalog,
 {
+    id: Option<widget::Id>,
     is_checked: bool,
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     label: Option<text::Fragment<'a>>,
@@ -114,6 +119,7 @@ where
     ///   * a boolean describing whether the [`Checkbox`] is checked or not
     pub fn new(is_checked: bool) -> Self {
         Checkbox {
+            id: None,
             is_checked,
             on_toggle: None,
             label: None,
@@ -137,6 +143,12 @@ where
         }
     }
 
+    /// Sets the [`widget::Id`] of the [`Checkbox`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the label of the [`Checkbox`].
     pub fn label(mut self, label: impl text::IntoFragment<'a>) -> Self {
         self.label = Some(label.into_fragment());
@@ -243,6 +255,27 @@ where
     }
 }
 
+struct State<P: text::Paragraph> {
+    is_focused: bool,
+    is_pressed: bool,
+    text: widget::text::State<P>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_pressed = false;
+    }
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Checkbox<'_, Message, Theme, Renderer>
 where
@@ -250,11 +283,15 @@ where
     Theme: Catalog,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph> {
+            is_focused: false,
+            is_pressed: false,
+            text: widget::text::State::default(),
+        })
     }
 
     fn size(&self) -> Size<Length> {
@@ -280,12 +317,10 @@ where
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                        .state
-                        .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                     widget::text::layout(
-                        state,
+                        &mut state.text,
                         renderer,
                         limits,
                         label,
@@ -310,7 +345,7 @@ where
 
     fn update(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -319,16 +354,43 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let mouse_over = cursor.is_over(layout.bounds());
 
+                state.is_focused = false;
+
                 if mouse_over && let Some(on_toggle) = &self.on_toggle {
                     shell.publish((on_toggle)(!self.is_checked));
                     shell.capture_event();
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                repeat: false,
+                ..
+            }) if state.is_focused && self.on_toggle.is_some() => {
+                state.is_pressed = true;
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                ..
+            }) => {
+                if state.is_focused
+                    && state.is_pressed
+                    && let Some(on_toggle) = &self.on_toggle
+                {
+                    state.is_pressed = false;
+
+                    shell.publish((on_toggle)(!self.is_checked));
+                    shell.capture_event();
+                }
+            }
             _ => {}
         }
 
@@ -339,6 +401,8 @@ where
 
             if is_disabled {
                 Status::Disabled { is_checked }
+            } else if state.is_focused {
+                Status::Focused { is_checked }
             } else if is_mouse_over {
                 Status::Hovered { is_checked }
             } else {
@@ -439,13 +503,13 @@ where
 
         {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> = tree.state.downcast_ref();
+            let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout.bounds(),
-                state.raw(),
+                state.text.raw(),
                 crate::text::Style {
                     color: style.text_color,
                 },
@@ -456,11 +520,30 @@ where
 
     fn operate(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         layout: Layout<'_>,
         _renderer: &Renderer,
-        operation: &mut dyn widget::Operation,
+        operation: &mut dyn Operation,
     ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let mut accessible = Accessible::new(Role::CheckBox)
+            .checked(self.is_checked)
+            .disabled(self.on_toggle.is_none())
+            .focused(state.is_focused);
+
+        if let Some(label) = self.label.as_deref() {
+            accessible = accessible.label(label);
+        }
+
+        operation.accessible(self.id.as_ref(), layout.bounds(), &accessible);
+
+        if self.on_toggle.is_some() {
+            operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        } else {
+            state.is_focused = false;
+        }
+
         if let Some(label) = self.label.as_deref() {
             operation.text(None, layout.bounds(), label);
         }
@@ -509,6 +592,11 @@ pub enum Status {
         /// Indicates if the [`Checkbox`] is currently checked.
         is_checked: bool,
     },
+    /// The [`Checkbox`] can be interacted with and it has keyboard focus.
+    Focused {
+        /// Indicates if the [`Checkbox`] is currently checked.
+        is_checked: bool,
+    },
     /// The [`Checkbox`] cannot be interacted with.
     Disabled {
         /// Indicates if the [`Checkbox`] is currently checked.
@@ -517,7 +605,7 @@ pub enum Status {
 }
 
 /// The style of a checkbox.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the checkbox.
     pub background: Background,
@@ -577,6 +665,16 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
             palette.primary.strong,
             is_checked,
         ),
+        Status::Focused { is_checked } => focused(
+            styled(
+                palette.background.strong.color,
+                palette.background.weak,
+                palette.primary.base.text,
+                palette.primary.strong,
+                is_checked,
+            ),
+            palette,
+        ),
         Status::Disabled { is_checked } => styled(
             palette.background.weak.color,
             palette.background.weaker,
@@ -606,6 +704,16 @@ pub fn secondary(theme: &Theme, status: Status) -> Style {
             palette.background.strong,
             is_checked,
         ),
+        Status::Focused { is_checked } => focused(
+            styled(
+                palette.background.strong.color,
+                palette.background.weak,
+                palette.background.base.text,
+                palette.background.strong,
+                is_checked,
+            ),
+            palette,
+        ),
         Status::Disabled { is_checked } => styled(
             palette.background.weak.color,
             palette.background.weak,
@@ -635,6 +743,16 @@ pub fn success(theme: &Theme, status: Status) -> Style {
             palette.success.strong,
             is_checked,
         ),
+        Status::Focused { is_checked } => focused(
+            styled(
+                palette.background.strong.color,
+                palette.background.weak,
+                palette.success.base.text,
+                palette.success.strong,
+                is_checked,
+            ),
+            palette,
+        ),
         Status::Disabled { is_checked } => styled(
             palette.background.weak.color,
             palette.background.weak,
@@ -664,6 +782,16 @@ pub fn danger(theme: &Theme, status: Status) -> Style {
             palette.danger.strong,
             is_checked,
         ),
+        Status::Focused { is_checked } => focused(
+            styled(
+                palette.background.strong.color,
+                palette.background.weak,
+                palette.danger.base.text,
+                palette.danger.strong,
+                is_checked,
+            ),
+            palette,
+        ),
         Status::Disabled { is_checked } => styled(
             palette.background.weak.color,
             palette.background.weak,
@@ -674,6 +802,17 @@ pub fn danger(theme: &Theme, status: Status) -> Style {
     }
 }
 
+fn focused(style: Style, palette: &palette::Extended) -> Style {
+    Style {
+        border: Border {
+            color: palette.background.base.text,
+            width: 2.0,
+            ..style.border
+        },
+        ..style
+    }
+}
+
 fn styled(
     border_color: Color,
     base: palette::Pair,
@@ -698,3 +837,73 @@ fn styled(
         text_color: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::clipboard;
+
+    #[test]
+    fn focused_checkboxes_toggle_when_space_is_released() {
+        let mut checkbox: Element<'_, bool, Theme, ()> =
+            Checkbox::new(false).on_toggle(|is_on| is_on).into();
+
+        let mut tree = Tree::new(&checkbox);
+        let bounds = Rectangle::with_size(Size::new(100.0, 100.0));
+
+        let node = checkbox.as_widget_mut().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, bounds.size()),
+        );
+
+        let space = keyboard::Key::Named(key::Named::Space);
+        let physical_key = key::Physical::Unidentified(key::NativeCode::Unidentified);
+
+        let pressed = Event::Keyboard(keyboard::Event::KeyPressed {
+            key: space.clone(),
+            modified_key: space.clone(),
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: Some(" ".into()),
+            repeat: false,
+        });
+
+        let released = Event::Keyboard(keyboard::Event::KeyReleased {
+            key: space.clone(),
+            modified_key: space,
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+        });
+
+        let mut send = |tree: &mut Tree, event: &Event| {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            checkbox.as_widget_mut().update(
+                tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &bounds,
+            );
+
+            messages
+        };
+
+        assert_eq!(send(&mut tree, &pressed), []);
+        assert_eq!(send(&mut tree, &released), []);
+
+        tree.state.downcast_mut::<State<()>>().is_focused = true;
+
+        // The checkbox toggles on release, so holding the key does nothing
+        assert_eq!(send(&mut tree, &pressed), []);
+        assert_eq!(send(&mut tree, &released), [true]);
+    }
+}
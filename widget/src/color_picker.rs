@@ -0,0 +1,1307 @@
+//! Color pickers let users choose a [`Color`].
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::Color; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::Color;
+//! use iced::widget::color_picker;
+//!
+//! struct State {
+//!    color: Color,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ColorChanged(Color),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     color_picker(state.color, Message::ColorChanged)
+//!         .swatches([Color::BLACK, Color::WHITE])
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::ColorChanged(color) => {
+//!             state.color = color;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::gradient;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Radians,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget, widget,
+};
+use crate::text_input::{self, TextInput};
+
+use std::f32::consts::PI;
+
+/// The default width of a [`ColorPicker`].
+const DEFAULT_WIDTH: f32 = 240.0;
+
+/// The default height of the saturation and value area of a [`ColorPicker`].
+const DEFAULT_AREA_HEIGHT: f32 = 160.0;
+
+/// The height of the hue and alpha sliders.
+const SLIDER_HEIGHT: f32 = 14.0;
+
+/// The size of a swatch.
+const SWATCH_SIZE: f32 = 20.0;
+
+/// The spacing between the parts of a [`ColorPicker`].
+const SPACING: f32 = 8.0;
+
+/// The spacing between swatches.
+const SWATCH_SPACING: f32 = 4.0;
+
+/// The radius of the handle of the saturation and value area.
+const HANDLE_RADIUS: f32 = 6.0;
+
+/// A widget to pick a [`Color`].
+///
+/// It is made of an area to pick the saturation and value of the color,
+/// sliders for its hue and alpha, a text entry and an optional list of
+/// swatches.
+///
+/// The text entry accepts hexadecimal (`#ff8000`), RGB (`rgb(255, 128, 0)`)
+/// and HSL (`hsl(30, 100%, 50%)`) notations, with an optional alpha channel.
+/// The button next to it switches the notation used to display the color.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::Color; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::Color;
+/// use iced::widget::color_picker;
+///
+/// struct State {
+///    background: Color,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     BackgroundChanged(Color),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     color_picker(state.background, Message::BackgroundChanged)
+///         .alpha(false)
+///         .into()
+/// }
+/// ```
+pub struct ColorPicker<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    text_input: TextInput<'a, TextInputEvent, Theme, Renderer>,
+    width: Length,
+    area_height: f32,
+    has_alpha: bool,
+    swatches: Vec<Color>,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`ColorPicker`] with the current [`Color`] and the
+    /// message to produce when it changes.
+    pub fn new(color: Color, on_change: impl Fn(Color) -> Message + 'a) -> Self {
+        Self {
+            color,
+            on_change: Box::new(on_change),
+            text_input: TextInput::new("", "")
+                .on_input(TextInputEvent::TextChanged)
+                .class(Theme::default_input()),
+            width: Length::Shrink,
+            area_height: DEFAULT_AREA_HEIGHT,
+            has_alpha: true,
+            swatches: Vec::new(),
+            text_size: None,
+            font: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the text entry of the [`ColorPicker`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the saturation and value area of the
+    /// [`ColorPicker`].
+    pub fn area_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.area_height = height.into().0;
+        self
+    }
+
+    /// Sets whether the [`ColorPicker`] lets users change the alpha channel
+    /// of the [`Color`].
+    ///
+    /// Enabled by default.
+    pub fn alpha(mut self, has_alpha: bool) -> Self {
+        self.has_alpha = has_alpha;
+        self
+    }
+
+    /// Sets the swatches of the [`ColorPicker`]; a list of predefined colors
+    /// that can be picked with a single click.
+    pub fn swatches(mut self, swatches: impl IntoIterator<Item = Color>) -> Self {
+        self.swatches = swatches.into_iter().collect();
+        self
+    }
+
+    /// Sets the text size of the [`ColorPicker`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_input = self.text_input.size(size);
+        self.text_size = Some(size);
+
+        self
+    }
+
+    /// Sets the [`Renderer::Font`] of the [`ColorPicker`].
+    ///
+    /// [`Renderer::Font`]: text::Renderer
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the style of the text entry of the [`ColorPicker`].
+    #[must_use]
+    pub fn input_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        <Theme as text_input::Catalog>::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the text entry of the [`ColorPicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn input_class(
+        mut self,
+        class: impl Into<<Theme as text_input::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.text_input = self.text_input.class(class);
+        self
+    }
+
+    /// Sets the style class of the [`ColorPicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn text(&self, state: &State) -> String {
+        state
+            .text
+            .clone()
+            .unwrap_or_else(|| state.notation.format(self.color))
+    }
+
+    fn change(&self, state: &mut State, hsva: Hsva, shell: &mut Shell<'_, Message>) {
+        let color = hsva.to_color();
+
+        state.hsva = hsva;
+        state.color = color;
+        state.text = None;
+
+        if color != self.color {
+            shell.publish((self.on_change)(color));
+        }
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TextInputEvent {
+    TextChanged(String),
+}
+
+/// A notation of a [`Color`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Notation {
+    #[default]
+    Hex,
+    Rgb,
+    Hsl,
+}
+
+impl Notation {
+    fn next(self) -> Self {
+        match self {
+            Self::Hex => Self::Rgb,
+            Self::Rgb => Self::Hsl,
+            Self::Hsl => Self::Hex,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Hex => "HEX",
+            Self::Rgb => "RGB",
+            Self::Hsl => "HSL",
+        }
+    }
+
+    fn format(self, color: Color) -> String {
+        let is_opaque = color.a >= 1.0;
+
+        match self {
+            Self::Hex => color.to_string(),
+            Self::Rgb => {
+                let [r, g, b, _] = color.into_rgba8();
+
+                if is_opaque {
+                    format!("rgb({r}, {g}, {b})")
+                } else {
+                    format!("rgba({r}, {g}, {b}, {:.2})", color.a)
+                }
+            }
+            Self::Hsl => {
+                let [h, s, l] = to_hsl(color);
+                let (h, s, l) = (h.round(), (s * 100.0).round(), (l * 100.0).round());
+
+                if is_opaque {
+                    format!("hsl({h}, {s}%, {l}%)")
+                } else {
+                    format!("hsla({h}, {s}%, {l}%, {:.2})", color.a)
+                }
+            }
+        }
+    }
+}
+
+/// A draggable part of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Area,
+    Hue,
+    Alpha,
+}
+
+#[derive(Debug)]
+struct State {
+    color: Color,
+    hsva: Hsva,
+    text: Option<String>,
+    notation: Notation,
+    dragging: Option<Part>,
+    is_notation_hovered: bool,
+}
+
+impl State {
+    fn new(color: Color) -> Self {
+        Self {
+            color,
+            hsva: Hsva::from_color(color, 0.0),
+            text: None,
+            notation: Notation::default(),
+            dragging: None,
+            is_notation_hovered: false,
+        }
+    }
+
+    /// Returns the [`Hsva`] of the given color, keeping the current hue
+    /// and saturation when they cannot be derived from it.
+    fn hsva(&self, color: Color) -> Hsva {
+        if color == self.color {
+            self.hsva
+        } else {
+            let hsva = Hsva::from_color(color, self.hsva.h);
+
+            if hsva.v == 0.0 {
+                Hsva {
+                    s: self.hsva.s,
+                    ..hsva
+                }
+            } else {
+                hsva
+            }
+        }
+    }
+
+    fn sync(&mut self, color: Color) {
+        self.hsva = self.hsva(color);
+        self.color = color;
+    }
+}
+
+/// The layouts of the parts of a [`ColorPicker`].
+struct Parts<'a> {
+    area: Rectangle,
+    hue: Rectangle,
+    alpha: Option<Rectangle>,
+    preview: Rectangle,
+    notation: Rectangle,
+    input: Layout<'a>,
+    swatches: Layout<'a>,
+}
+
+impl<'a> Parts<'a> {
+    fn new(layout: Layout<'a>, has_alpha: bool) -> Self {
+        let mut children = layout.children();
+        let mut next = || children.next().expect("Color picker part");
+
+        Self {
+            area: next().bounds(),
+            hue: next().bounds(),
+            alpha: has_alpha.then(|| next().bounds()),
+            preview: next().bounds(),
+            notation: next().bounds(),
+            input: next(),
+            swatches: next(),
+        }
+    }
+
+    fn swatch(&self, cursor: mouse::Cursor) -> Option<usize> {
+        self.swatches
+            .children()
+            .position(|swatch| cursor.is_over(swatch.bounds()))
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorPicker<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.color))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        Widget::<TextInputEvent, Theme, Renderer>::diff(&self.text_input, &mut tree.children[0]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        state.sync(self.color);
+
+        self.text_input.set_value(&self.text(state));
+
+        let width = limits
+            .resolve(self.width, Length::Shrink, Size::new(DEFAULT_WIDTH, 0.0))
+            .width;
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let mut y = 0.0;
+        let mut children = Vec::new();
+
+        let mut push = |height: f32, children: &mut Vec<layout::Node>| {
+            children.push(layout::Node::new(Size::new(width, height)).move_to(Point::new(0.0, y)));
+            y += height + SPACING;
+        };
+
+        push(self.area_height, &mut children);
+        push(SLIDER_HEIGHT, &mut children);
+
+        if self.has_alpha {
+            push(SLIDER_HEIGHT, &mut children);
+        }
+
+        let notation_width = text_size.0 * 3.5;
+
+        let preview_width = text_size.0 * 2.0;
+        let input_width = (width - preview_width - notation_width - SPACING * 2.0).max(0.0);
+
+        let input = self.text_input.layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(input_width, f32::INFINITY)),
+            None,
+        );
+
+        let row_height = input.size().height;
+
+        children.push(
+            layout::Node::new(Size::new(preview_width, row_height)).move_to(Point::new(0.0, y)),
+        );
+        children.push(
+            layout::Node::new(Size::new(notation_width, row_height))
+                .move_to(Point::new(preview_width + SPACING, y)),
+        );
+        children.push(input.move_to(Point::new(
+            preview_width + notation_width + SPACING * 2.0,
+            y,
+        )));
+
+        y += row_height;
+
+        let columns = ((width + SWATCH_SPACING) / (SWATCH_SIZE + SWATCH_SPACING))
+            .floor()
+            .max(1.0) as usize;
+
+        let swatches: Vec<_> = (0..self.swatches.len())
+            .map(|index| {
+                layout::Node::new(Size::new(SWATCH_SIZE, SWATCH_SIZE)).move_to(Point::new(
+                    (index % columns) as f32 * (SWATCH_SIZE + SWATCH_SPACING),
+                    (index / columns) as f32 * (SWATCH_SIZE + SWATCH_SPACING),
+                ))
+            })
+            .collect();
+
+        let swatches_height = if swatches.is_empty() {
+            0.0
+        } else {
+            y += SPACING;
+
+            self.swatches.len().div_ceil(columns) as f32 * (SWATCH_SIZE + SWATCH_SPACING)
+                - SWATCH_SPACING
+        };
+
+        children.push(
+            layout::Node::with_children(Size::new(width, swatches_height), swatches)
+                .move_to(Point::new(0.0, y)),
+        );
+
+        layout::Node::with_children(Size::new(width, y + swatches_height), children)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let parts = Parts::new(layout, self.has_alpha);
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            Widget::<TextInputEvent, Theme, Renderer>::operate(
+                &mut self.text_input,
+                &mut tree.children[0],
+                parts.input,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let parts = Parts::new(layout, self.has_alpha);
+        let state = tree.state.downcast_mut::<State>();
+        state.sync(self.color);
+
+        let was_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        self.text_input.set_value(&self.text(state));
+
+        let mut local_messages = Vec::new();
+        let mut local_shell = Shell::new(&mut local_messages);
+
+        self.text_input.update(
+            &mut tree.children[0],
+            event,
+            parts.input,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        for operation in local_shell.take_operations() {
+            shell.request_operation(operation);
+        }
+
+        for message in local_messages {
+            let TextInputEvent::TextChanged(text) = message;
+
+            if let Some(color) = parse(&text) {
+                let color = if self.has_alpha {
+                    color
+                } else {
+                    Color { a: 1.0, ..color }
+                };
+
+                state.sync(color);
+
+                if color != self.color {
+                    shell.publish((self.on_change)(color));
+                }
+            }
+
+            state.text = Some(text);
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        if was_focused && !is_focused {
+            state.text = None;
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+            let is_notation_hovered = cursor.is_over(parts.notation);
+
+            if state.is_notation_hovered != is_notation_hovered {
+                state.is_notation_hovered = is_notation_hovered;
+                shell.request_redraw();
+            }
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let value = |part: Part, position: Point| {
+            let bounds = match part {
+                Part::Area => parts.area,
+                Part::Hue => parts.hue,
+                Part::Alpha => parts.alpha.unwrap_or(parts.hue),
+            };
+
+            let x = ((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+            let y = ((position.y - bounds.y) / bounds.height).clamp(0.0, 1.0);
+
+            let hsva = state.hsva;
+
+            match part {
+                Part::Area => Hsva {
+                    s: x,
+                    v: 1.0 - y,
+                    ..hsva
+                },
+                Part::Hue => Hsva {
+                    h: x * 360.0,
+                    ..hsva
+                },
+                Part::Alpha => Hsva { a: x, ..hsva },
+            }
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    return;
+                };
+
+                let part = if parts.area.contains(position) {
+                    Some(Part::Area)
+                } else if parts.hue.contains(position) {
+                    Some(Part::Hue)
+                } else if parts.alpha.is_some_and(|alpha| alpha.contains(position)) {
+                    Some(Part::Alpha)
+                } else {
+                    None
+                };
+
+                if let Some(part) = part {
+                    let hsva = value(part, position);
+
+                    state.dragging = Some(part);
+                    self.change(state, hsva, shell);
+                } else if parts.notation.contains(position) {
+                    state.notation = state.notation.next();
+                    state.text = None;
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                } else if let Some(index) = parts.swatch(cursor) {
+                    let color = self.swatches[index];
+                    let hsva = state.hsva(color);
+
+                    self.change(state, hsva, shell);
+                } else {
+                    return;
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(part) = state.dragging
+                    && let Some(position) = cursor.position()
+                {
+                    let hsva = value(part, position);
+
+                    self.change(state, hsva, shell);
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging.is_some() =>
+            {
+                state.dragging = None;
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let parts = Parts::new(layout, self.has_alpha);
+
+        if state.dragging == Some(Part::Area) || cursor.is_over(parts.area) {
+            mouse::Interaction::Crosshair
+        } else if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(parts.hue)
+            || parts.alpha.is_some_and(|alpha| cursor.is_over(alpha))
+            || cursor.is_over(parts.notation)
+            || parts.swatch(cursor).is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            self.text_input.mouse_interaction(
+                &tree.children[0],
+                parts.input,
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let parts = Parts::new(layout, self.has_alpha);
+        let style = <Theme as Catalog>::style(theme, &self.class);
+
+        let hsva = state.hsva(self.color);
+        let hue = Hsva {
+            s: 1.0,
+            v: 1.0,
+            a: 1.0,
+            ..hsva
+        }
+        .to_color();
+        let opaque = Color {
+            a: 1.0,
+            ..self.color
+        };
+        let radius = style.border.radius;
+
+        // Saturation and value
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: parts.area,
+                border: border::rounded(radius),
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians(PI / 2.0))
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(1.0, hue),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: parts.area,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians(PI))
+                .add_stop(0.0, Color::TRANSPARENT)
+                .add_stop(1.0, Color::BLACK),
+        );
+
+        draw_handle(
+            renderer,
+            &style,
+            Point::new(
+                parts.area.x + hsva.s * parts.area.width,
+                parts.area.y + (1.0 - hsva.v) * parts.area.height,
+            ),
+            opaque,
+        );
+
+        // Hue
+        let mut spectrum = gradient::Linear::new(Radians(PI / 2.0));
+
+        for step in 0..=6 {
+            let h = step as f32 * 60.0;
+
+            spectrum = spectrum.add_stop(
+                step as f32 / 6.0,
+                Hsva {
+                    h,
+                    s: 1.0,
+                    v: 1.0,
+                    a: 1.0,
+                }
+                .to_color(),
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: parts.hue,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            spectrum,
+        );
+
+        draw_slider_handle(renderer, &style, parts.hue, hsva.h / 360.0);
+
+        // Alpha
+        if let Some(alpha) = parts.alpha {
+            draw_checkerboard(renderer, &style, alpha);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: alpha,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                gradient::Linear::new(Radians(PI / 2.0))
+                    .add_stop(0.0, Color { a: 0.0, ..opaque })
+                    .add_stop(1.0, opaque),
+            );
+
+            draw_slider_handle(renderer, &style, alpha, self.color.a);
+        }
+
+        // Preview
+        draw_checkerboard(renderer, &style, parts.preview);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: parts.preview,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            self.color,
+        );
+
+        // Notation
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: parts.notation,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            if state.is_notation_hovered {
+                style.button_hovered_background.clone()
+            } else {
+                style.button_background.clone()
+            },
+        );
+
+        renderer.fill_text(
+            Text {
+                content: state.notation.label().to_owned(),
+                bounds: parts.notation.size(),
+                size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                line_height: text::LineHeight::default(),
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::None,
+                hint_factor: renderer.scale_factor(),
+            },
+            parts.notation.center(),
+            style.button_text_color,
+            parts.notation,
+        );
+
+        // Text entry
+        let text = text_input::Value::new(&self.text(state));
+
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            parts.input,
+            cursor,
+            Some(&text),
+            viewport,
+        );
+
+        // Swatches
+        for (color, swatch) in self.swatches.iter().zip(parts.swatches.children()) {
+            let bounds = swatch.bounds();
+
+            if color.a < 1.0 {
+                draw_checkerboard(renderer, &style, bounds);
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        width: if *color == self.color {
+                            style.border.width.max(1.0) * 2.0
+                        } else {
+                            style.border.width
+                        },
+                        ..style.border
+                    },
+                    ..renderer::Quad::default()
+                },
+                *color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ColorPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(color_picker: ColorPicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(color_picker)
+    }
+}
+
+fn draw_handle<Renderer>(renderer: &mut Renderer, style: &Style, center: Point, color: Color)
+where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle::new(
+                center - Vector::new(HANDLE_RADIUS, HANDLE_RADIUS),
+                Size::new(HANDLE_RADIUS * 2.0, HANDLE_RADIUS * 2.0),
+            ),
+            border: Border {
+                color: style.handle_color,
+                width: 2.0,
+                radius: HANDLE_RADIUS.into(),
+            },
+            shadow: style.handle_shadow,
+            ..renderer::Quad::default()
+        },
+        color,
+    );
+}
+
+fn draw_slider_handle<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle, x: f32)
+where
+    Renderer: renderer::Renderer,
+{
+    let width = 6.0;
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: bounds.x + x * (bounds.width - width),
+                y: bounds.y - 2.0,
+                width,
+                height: bounds.height + 4.0,
+            },
+            border: Border {
+                color: style.handle_color,
+                width: 2.0,
+                radius: 2.0.into(),
+            },
+            shadow: style.handle_shadow,
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+fn draw_checkerboard<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle)
+where
+    Renderer: renderer::Renderer,
+{
+    let [light, dark] = style.checkerboard;
+    let size = (bounds.height / 2.0).clamp(1.0, 8.0);
+
+    renderer.with_layer(bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: border::rounded(style.border.radius),
+                ..renderer::Quad::default()
+            },
+            light,
+        );
+
+        let columns = (bounds.width / size).ceil() as usize;
+        let rows = (bounds.height / size).ceil() as usize;
+
+        for row in 0..rows {
+            for column in (row % 2..columns).step_by(2) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + column as f32 * size,
+                            y: bounds.y + row as f32 * size,
+                            width: size,
+                            height: size,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    dark,
+                );
+            }
+        }
+    });
+}
+
+/// A color in the HSV color space, with an alpha channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hsva {
+    /// The hue, in degrees.
+    h: f32,
+    s: f32,
+    v: f32,
+    a: f32,
+}
+
+impl Hsva {
+    /// Converts a [`Color`] to HSV, using the given hue for grays.
+    fn from_color(color: Color, fallback_hue: f32) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let delta = max - min;
+
+        let h = if delta == 0.0 {
+            fallback_hue
+        } else if max == color.r {
+            60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+        } else if max == color.g {
+            60.0 * ((color.b - color.r) / delta + 2.0)
+        } else {
+            60.0 * ((color.r - color.g) / delta + 4.0)
+        };
+
+        Self {
+            h,
+            s: if max == 0.0 { 0.0 } else { delta / max },
+            v: max,
+            a: color.a,
+        }
+    }
+
+    fn to_color(self) -> Color {
+        let c = self.v * self.s;
+        let [r, g, b] = hue_to_rgb(self.h, c);
+        let m = self.v - c;
+
+        Color::from_rgba(r + m, g + m, b + m, self.a)
+    }
+}
+
+/// Returns the RGB components of a hue with the given chroma.
+fn hue_to_rgb(hue: f32, chroma: f32) -> [f32; 3] {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+
+    match h as u32 {
+        0 => [chroma, x, 0.0],
+        1 => [x, chroma, 0.0],
+        2 => [0.0, chroma, x],
+        3 => [0.0, x, chroma],
+        4 => [x, 0.0, chroma],
+        _ => [chroma, 0.0, x],
+    }
+}
+
+/// Converts a [`Color`] to HSL.
+fn to_hsl(color: Color) -> [f32; 3] {
+    let hsva = Hsva::from_color(color, 0.0);
+    let l = hsva.v * (1.0 - hsva.s / 2.0);
+    let s = if l == 0.0 || l == 1.0 {
+        0.0
+    } else {
+        (hsva.v - l) / l.min(1.0 - l)
+    };
+
+    [hsva.h, s, l]
+}
+
+/// Converts a color in HSL to a [`Color`].
+fn from_hsl(h: f32, s: f32, l: f32, a: f32) -> Color {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let [r, g, b] = hue_to_rgb(h, c);
+    let m = l - c / 2.0;
+
+    Color::from_rgba(r + m, g + m, b + m, a)
+}
+
+/// Parses a [`Color`] in hexadecimal, RGB or HSL notation.
+fn parse(text: &str) -> Option<Color> {
+    let text = text.trim().to_lowercase();
+
+    let arguments = |prefixes: [&str; 2]| {
+        let arguments = prefixes
+            .iter()
+            .find_map(|prefix| text.strip_prefix(prefix))?
+            .trim_start()
+            .strip_prefix('(')?
+            .strip_suffix(')')?;
+
+        let arguments: Vec<_> = arguments
+            .split([',', ' ', '/'])
+            .filter(|argument| !argument.is_empty())
+            .collect();
+
+        (3..=4).contains(&arguments.len()).then_some(arguments)
+    };
+
+    let number = |argument: &str, max: f32| -> Option<f32> {
+        let value = match argument.strip_suffix('%') {
+            Some(percentage) => percentage.parse::<f32>().ok()? / 100.0 * max,
+            None => argument.parse().ok()?,
+        };
+
+        value.is_finite().then(|| value.clamp(0.0, max))
+    };
+
+    let alpha = |arguments: &[&str]| match arguments.get(3) {
+        Some(alpha) => number(alpha, 1.0),
+        None => Some(1.0),
+    };
+
+    if let Some(arguments) = arguments(["rgba", "rgb"]) {
+        Some(Color::from_rgba(
+            number(arguments[0], 255.0)? / 255.0,
+            number(arguments[1], 255.0)? / 255.0,
+            number(arguments[2], 255.0)? / 255.0,
+            alpha(&arguments)?,
+        ))
+    } else if let Some(arguments) = arguments(["hsla", "hsl"]) {
+        let h = arguments[0].strip_suffix("deg").unwrap_or(arguments[0]);
+
+        Some(from_hsl(
+            h.parse::<f32>().ok().filter(|h| h.is_finite())?,
+            number(arguments[1], 1.0)?,
+            number(arguments[2], 1.0)?,
+            alpha(&arguments)?,
+        ))
+    } else {
+        text.parse().ok()
+    }
+}
+
+/// The appearance of a [`ColorPicker`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The [`Border`] of the area, the sliders, the preview and the swatches.
+    pub border: Border,
+    /// The [`Color`] of the handles.
+    pub handle_color: Color,
+    /// The [`Shadow`] of the handles.
+    pub handle_shadow: Shadow,
+    /// The [`Background`] of the notation button.
+    pub button_background: Background,
+    /// The [`Background`] of the notation button when hovered.
+    pub button_hovered_background: Background,
+    /// The text [`Color`] of the notation button.
+    pub button_text_color: Color,
+    /// The light and dark colors of the checkerboard displayed behind
+    /// translucent colors.
+    pub checkerboard: [Color; 2],
+}
+
+/// The theme catalog of a [`ColorPicker`].
+pub trait Catalog: text_input::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the text entry of the [`Catalog`].
+    fn default_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        <Self as text_input::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ColorPicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ColorPicker`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        handle_color: Color::WHITE,
+        handle_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.5),
+            blur_radius: 2.0,
+            ..Shadow::default()
+        },
+        button_background: palette.background.weak.color.into(),
+        button_hovered_background: palette.background.strong.color.into(),
+        button_text_color: palette.background.weak.text,
+        checkerboard: [Color::WHITE, Color::from_rgb8(0xCC, 0xCC, 0xCC)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_notations() {
+        let orange = Color::from_rgb8(255, 128, 0);
+
+        assert_eq!(parse("#ff8000"), Some(orange));
+        assert_eq!(parse("rgb(255, 128, 0)"), Some(orange));
+        assert_eq!(
+            parse("RGBA(255 128 0 / 50%)"),
+            Some(Color { a: 0.5, ..orange })
+        );
+
+        let hsl = parse("hsl(30, 100%, 50%)").unwrap();
+
+        assert!((hsl.r - 1.0).abs() < 1e-4);
+        assert!((hsl.g - 0.5).abs() < 1e-4);
+        assert!(hsl.b.abs() < 1e-4);
+
+        assert_eq!(parse("rgb(255, 128)"), None);
+        assert_eq!(parse("hsl(nan, 1, 1)"), None);
+        assert_eq!(Notation::Hsl.format(orange), "hsl(30, 100%, 50%)");
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        for color in [
+            Color::from_rgb8(255, 128, 0),
+            Color::from_rgb8(12, 200, 90),
+            Color::from_rgba8(30, 60, 240, 0.25),
+        ] {
+            let converted = Hsva::from_color(color, 0.0).to_color();
+
+            assert_eq!(converted.into_rgba8(), color.into_rgba8());
+        }
+    }
+}
@@ -62,6 +62,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::time::Instant;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
 use crate::core::widget::{self, Widget};
 use crate::core::{
     Clipboard, Element, Event, Length, Padding, Pixels, Rectangle, Shell, Size, Theme, Vector,
@@ -531,6 +532,37 @@ where
         // do nothing so the children don't get cleared
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        operation.container(None, layout.bounds());
+        operation.accessible(
+            None,
+            layout.bounds(),
+            &Accessible::new(Role::ComboBox)
+                .value(self.selection.to_string())
+                .expanded(is_focused),
+        );
+        operation.traverse(&mut |operation| {
+            Widget::<TextInputEvent, Theme, Renderer>::operate(
+                &mut self.text_input,
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
     fn update(
         &mut self,
         tree: &mut widget::Tree,
@@ -578,6 +610,10 @@ where
         shell.request_redraw_at(local_shell.redraw_request());
         shell.request_input_method(local_shell.input_method());
 
+        for operation in local_shell.take_operations() {
+            shell.request_operation(operation);
+        }
+
         // Then finally react to them here
         for message in local_messages {
             let TextInputEvent::TextChanged(new_value) = message;
@@ -725,6 +761,10 @@ where
                     viewport,
                 );
                 shell.request_input_method(local_shell.input_method());
+
+                for operation in local_shell.take_operations() {
+                    shell.request_operation(operation);
+                }
             }
         });
 
@@ -30,8 +30,8 @@ use crate::core::theme;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::{self, Operation};
 use crate::core::{
-    self, Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Rectangle,
-    Shadow, Shell, Size, Theme, Vector, Widget, color,
+    self, Backdrop, Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget, color,
 };
 
 /// A widget that aligns its contents inside of its boundaries.
@@ -419,8 +419,17 @@ pub fn draw_background<Renderer>(renderer: &mut Renderer, style: &Style, bounds:
 where
     Renderer: core::Renderer,
 {
-    if style.background.is_some() || style.border.width > 0.0 || style.shadow.color.a > 0.0 {
-        renderer.fill_quad(
+    if let Some(backdrop) = style.backdrop {
+        renderer.draw_backdrop(bounds, style.border.radius, backdrop);
+    }
+
+    if style.background.is_some()
+        || style.border.width > 0.0
+        || style.shadow.color.a > 0.0
+        || !style.shadows.is_empty()
+    {
+        fill_quad(
+            renderer,
             renderer::Quad {
                 bounds,
                 border: style.border,
@@ -429,22 +438,94 @@ where
             },
             style
                 .background
+                .clone()
                 .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            &style.shadows,
+        );
+    }
+}
+
+/// Fills a [`renderer::Quad`] with the given [`Background`]; together with
+/// some additional [`Shadow`]s.
+///
+/// Outer shadows are drawn below the quad and its own shadow, while inset
+/// shadows are drawn on top of its background and its own shadow.
+pub fn fill_quad<Renderer>(
+    renderer: &mut Renderer,
+    quad: renderer::Quad,
+    background: Background,
+    shadows: &[Shadow],
+) where
+    Renderer: core::Renderer,
+{
+    for shadow in shadows.iter().filter(|shadow| !shadow.inset) {
+        renderer.fill_quad(
+            renderer::Quad {
+                border: Border {
+                    width: 0.0,
+                    ..quad.border
+                },
+                shadow: *shadow,
+                ..quad
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
+    // Inset shadows are drawn in a separate quad, so they end up on top
+    // of any kind of background
+    let inset = std::iter::once(quad.shadow)
+        .filter(|shadow| shadow.inset)
+        .chain(shadows.iter().copied().filter(|shadow| shadow.inset));
+
+    renderer.fill_quad(
+        renderer::Quad {
+            shadow: if quad.shadow.inset {
+                Shadow::default()
+            } else {
+                quad.shadow
+            },
+            ..quad
+        },
+        background,
+    );
+
+    for shadow in inset {
+        renderer.fill_quad(
+            renderer::Quad {
+                border: Border {
+                    color: Color::TRANSPARENT,
+                    ..quad.border
+                },
+                shadow,
+                ..quad
+            },
+            Color::TRANSPARENT,
         );
     }
 }
 
 /// The appearance of a container.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The text [`Color`] of the container.
     pub text_color: Option<Color>,
     /// The [`Background`] of the container.
     pub background: Option<Background>,
+    /// The [`Backdrop`] filter of the container.
+    ///
+    /// It is applied to anything drawn behind the container, before its
+    /// [`Background`].
+    pub backdrop: Option<Backdrop>,
     /// The [`Border`] of the container.
     pub border: Border,
     /// The [`Shadow`] of the container.
     pub shadow: Shadow,
+    /// Additional [`Shadow`]s of the container.
+    ///
+    /// Outer shadows are layered below [`shadow`](Self::shadow) and inset
+    /// shadows on top of it, in order.
+    pub shadows: Vec<Shadow>,
     /// Whether the container should be snapped to the pixel grid.
     pub snap: bool,
 }
@@ -454,8 +535,10 @@ impl Default for Style {
         Self {
             text_color: None,
             background: None,
+            backdrop: None,
             border: Border::default(),
             shadow: Shadow::default(),
+            shadows: Vec::new(),
             snap: renderer::CRISP,
         }
     }
@@ -486,6 +569,14 @@ impl Style {
         }
     }
 
+    /// Updates the backdrop filter of the [`Style`].
+    pub fn backdrop(self, backdrop: impl Into<Backdrop>) -> Self {
+        Self {
+            backdrop: Some(backdrop.into()),
+            ..self
+        }
+    }
+
     /// Updates the shadow of the [`Style`].
     pub fn shadow(self, shadow: impl Into<Shadow>) -> Self {
         Self {
@@ -493,6 +584,14 @@ impl Style {
             ..self
         }
     }
+
+    /// Updates the additional shadows of the [`Style`].
+    pub fn shadows(self, shadows: impl IntoIterator<Item = Shadow>) -> Self {
+        Self {
+            shadows: shadows.into_iter().collect(),
+            ..self
+        }
+    }
 }
 
 impl From<Color> for Style {
@@ -513,6 +612,18 @@ impl From<gradient::Linear> for Style {
     }
 }
 
+impl From<gradient::Radial> for Style {
+    fn from(gradient: gradient::Radial) -> Self {
+        Self::default().background(gradient)
+    }
+}
+
+impl From<gradient::Conic> for Style {
+    fn from(gradient: gradient::Conic) -> Self {
+        Self::default().background(gradient)
+    }
+}
+
 /// The theme catalog of a [`Container`].
 pub trait Catalog {
     /// The item class of the [`Catalog`].
@@ -530,7 +641,7 @@ pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
 
 impl<Theme> From<Style> for StyleFn<'_, Theme> {
     fn from(style: Style) -> Self {
-        Box::new(move |_theme| style)
+        Box::new(move |_theme| style.clone())
     }
 }
 
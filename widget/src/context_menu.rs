@@ -0,0 +1,346 @@
+//! Context menus display a menu of actions when right-clicking some content.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{container, context_menu};
+//! use iced::widget::menu_bar::Item;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Copy,
+//!     Paste,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     context_menu(
+//!         container("Right-click me!").padding(20),
+//!         [
+//!             Item::new("Copy").on_press(Message::Copy).shortcut("Ctrl+C"),
+//!             Item::new("Paste").on_press(Message::Paste).shortcut("Ctrl+V"),
+//!         ],
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+use crate::menu_bar::{self, Catalog, Dropdown, Item, Style, StyleFn};
+
+/// A widget that displays a menu when its content is right-clicked.
+///
+/// The menu can also be opened with the context menu key of the keyboard
+/// while the content is hovered. Once open, it can be navigated just like
+/// the menus of a [`MenuBar`].
+///
+/// [`MenuBar`]: crate::MenuBar
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{container, context_menu};
+/// use iced::widget::menu_bar::Item;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+///     Paste,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     context_menu(
+///         container("Right-click me!").padding(20),
+///         [
+///             Item::new("Copy").on_press(Message::Copy).shortcut("Ctrl+C"),
+///             Item::new("Paste").on_press(Message::Paste).shortcut("Ctrl+V"),
+///         ],
+///     )
+///     .into()
+/// }
+/// ```
+pub struct ContextMenu<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    items: Vec<Item<Message>>,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`ContextMenu`] with the given content and menu items.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the text size of the menu of the [`ContextMenu`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the menu of the [`ContextMenu`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the menu of the [`ContextMenu`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the menu of the [`ContextMenu`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    position: Point,
+    is_open: bool,
+    path: Vec<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContextMenu<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() || self.items.is_empty() {
+            return;
+        }
+
+        let is_pressed = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => true,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::ContextMenu),
+                ..
+            }) => false,
+            _ => return,
+        };
+
+        let bounds = layout.bounds();
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        state.position = Point::ORIGIN + (position - bounds.position());
+        state.is_open = true;
+        state.path.clear();
+
+        if !is_pressed && let Some(first) = menu_bar::first(&self.items) {
+            state.path.push(first);
+        }
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+        shell.capture_event();
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let menu = state.is_open.then(|| {
+            Dropdown {
+                items: &self.items,
+                path: &mut state.path,
+                is_open: &mut state.is_open,
+                bar: None,
+                position: layout.position() + (state.position - Point::ORIGIN) + translation,
+                target_height: 0.0,
+                text_size: self.text_size,
+                font: self.font,
+                class: &self.class,
+            }
+            .overlay()
+        });
+
+        match (content, menu) {
+            (Some(content), Some(menu)) => {
+                Some(overlay::Group::with_children(vec![content, menu]).overlay())
+            }
+            (content, menu) => content.or(menu),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ContextMenu<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(context_menu)
+    }
+}
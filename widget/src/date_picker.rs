@@ -0,0 +1,1423 @@
+//! Date pickers let users choose a date, or a range of dates, from a calendar.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::date_picker;
+//! use iced::widget::date_picker::Date;
+//!
+//! struct State {
+//!    birthday: Option<Date>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     BirthdaySelected(Date),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     date_picker("Birthday", state.birthday, Message::BirthdaySelected)
+//!         .max(Date::today())
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::BirthdaySelected(birthday) => {
+//!             state.birthday = Some(birthday);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::time::SystemTime;
+use crate::core::touch;
+use crate::core::widget::operation::Operation;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget, widget,
+};
+use crate::text_input::{self, TextInput};
+
+use std::fmt;
+
+/// The padding around the calendar of a [`DatePicker`].
+const PADDING: f32 = 8.0;
+
+/// The size of a cell of the calendar, relative to its text size.
+const CELL_FACTOR: f32 = 2.0;
+
+/// The English names of the months, used by default.
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The English abbreviations of the days of the week, starting on Monday.
+const WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// A text input with a dropdown calendar to pick a date or a range of dates.
+///
+/// The date can either be typed in ISO 8601 format (e.g. `2024-03-15`) or
+/// picked from the calendar, which opens while the input is focused. The
+/// calendar can be navigated with the mouse or the keyboard:
+///
+/// - The up and down arrows move the highlighted day by a week.
+/// - The left and right arrows move it by a day while holding `Alt`.
+/// - Page up and page down move it by a month, or by a year while holding `Shift`.
+/// - Enter picks the highlighted day.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{date_picker, date_range_picker};
+/// use iced::widget::date_picker::{Date, Weekday};
+///
+/// struct State {
+///    trip: Option<date_picker::Range>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TripSelected(date_picker::Range),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     date_range_picker("Trip dates", state.trip, Message::TripSelected)
+///         .min(Date::today())
+///         .first_weekday(Weekday::Sunday)
+///         .into()
+/// }
+/// ```
+pub struct DatePicker<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    text_input: TextInput<'a, TextInputEvent, Theme, Renderer>,
+    selection: Option<Range>,
+    is_range: bool,
+    on_select: Box<dyn Fn(Range) -> Message + 'a>,
+    min: Option<Date>,
+    max: Option<Date>,
+    is_disabled: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    first_weekday: Weekday,
+    month_names: [&'a str; 12],
+    weekday_names: [&'a str; 7],
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`DatePicker`] with the given placeholder, the current
+    /// date, and the message to produce when a date is picked.
+    pub fn new(
+        placeholder: &str,
+        value: Option<Date>,
+        on_select: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        Self::with_selection(
+            placeholder,
+            value.map(|date| Range::new(date, date)),
+            false,
+            Box::new(move |range| on_select(range.start)),
+        )
+    }
+
+    /// Creates a new [`DatePicker`] that picks a [`Range`] of dates, with the
+    /// given placeholder, the current range, and the message to produce when
+    /// a range is picked.
+    ///
+    /// The first click on the calendar picks the start of the range, while
+    /// the second one completes it. A range can also be typed as two dates
+    /// separated by `..` or a dash (e.g. `2024-03-15 - 2024-03-20`).
+    pub fn range(
+        placeholder: &str,
+        value: Option<Range>,
+        on_select: impl Fn(Range) -> Message + 'a,
+    ) -> Self {
+        Self::with_selection(placeholder, value, true, Box::new(on_select))
+    }
+
+    fn with_selection(
+        placeholder: &str,
+        selection: Option<Range>,
+        is_range: bool,
+        on_select: Box<dyn Fn(Range) -> Message + 'a>,
+    ) -> Self {
+        let value = match selection {
+            Some(range) if is_range => range.to_string(),
+            Some(range) => range.start.to_string(),
+            None => String::new(),
+        };
+
+        let text_input = TextInput::new(placeholder, &value)
+            .on_input(TextInputEvent::TextChanged)
+            .class(Theme::default_input());
+
+        Self {
+            text_input,
+            selection,
+            is_range,
+            on_select,
+            min: None,
+            max: None,
+            is_disabled: None,
+            first_weekday: Weekday::Monday,
+            month_names: MONTH_NAMES,
+            weekday_names: WEEKDAY_NAMES,
+            text_size: None,
+            font: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`DatePicker`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the earliest [`Date`] that can be picked.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be picked.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets a function that decides whether a [`Date`] is disabled and,
+    /// therefore, cannot be picked.
+    pub fn disabled(mut self, is_disabled: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets the first day of the week of the calendar.
+    ///
+    /// By default, weeks start on [`Weekday::Monday`].
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Sets the names of the months displayed in the calendar, starting
+    /// with January.
+    pub fn month_names(mut self, names: [&'a str; 12]) -> Self {
+        self.month_names = names;
+        self
+    }
+
+    /// Sets the names of the days of the week displayed in the calendar,
+    /// starting with Monday.
+    ///
+    /// Short names, like `Mo` or `Tu`, fit best.
+    pub fn weekday_names(mut self, names: [&'a str; 7]) -> Self {
+        self.weekday_names = names;
+        self
+    }
+
+    /// Sets the width of the [`DatePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DatePicker`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_input = self.text_input.size(size);
+        self.text_size = Some(size);
+
+        self
+    }
+
+    /// Sets the [`Renderer::Font`] of the [`DatePicker`].
+    ///
+    /// [`Renderer::Font`]: text::Renderer
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the [`text_input::Icon`] of the [`DatePicker`].
+    pub fn icon(mut self, icon: text_input::Icon<Renderer::Font>) -> Self {
+        self.text_input = self.text_input.icon(icon);
+        self
+    }
+
+    /// Sets the style of the input of the [`DatePicker`].
+    #[must_use]
+    pub fn input_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        <Theme as text_input::Catalog>::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the calendar of the [`DatePicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the input of the [`DatePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn input_class(
+        mut self,
+        class: impl Into<<Theme as text_input::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.text_input = self.text_input.class(class);
+        self
+    }
+
+    /// Sets the style class of the calendar of the [`DatePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_enabled(&self, date: Date) -> bool {
+        self.min.is_none_or(|min| date >= min)
+            && self.max.is_none_or(|max| date <= max)
+            && !self
+                .is_disabled
+                .as_ref()
+                .is_some_and(|is_disabled| is_disabled(date))
+    }
+
+    fn parse(&self, text: &str) -> Option<Range> {
+        let range = if self.is_range {
+            Range::parse(text)?
+        } else {
+            let date = Date::parse(text)?;
+
+            Range::new(date, date)
+        };
+
+        (self.is_enabled(range.start) && self.is_enabled(range.end)).then_some(range)
+    }
+
+    fn cell_size(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size()).0 * CELL_FACTOR
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TextInputEvent {
+    TextChanged(String),
+}
+
+#[derive(Debug)]
+struct State {
+    is_open: bool,
+    text: Option<String>,
+    month: Date,
+    cursor: Option<Date>,
+    anchor: Option<Date>,
+    hovered: Option<Target>,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            is_open: false,
+            text: None,
+            month: Date::today().first_of_month(),
+            cursor: None,
+            anchor: None,
+            hovered: None,
+        }
+    }
+
+    fn open(&mut self, selection: Option<Range>) {
+        self.is_open = true;
+        self.cursor = selection.map(|range| range.start);
+        self.month = self.cursor.unwrap_or_else(Date::today).first_of_month();
+        self.anchor = None;
+    }
+
+    fn close(&mut self) {
+        self.is_open = false;
+        self.text = None;
+        self.cursor = None;
+        self.anchor = None;
+        self.hovered = None;
+    }
+
+    fn sync(&mut self, is_focused: bool, selection: Option<Range>) {
+        if is_focused && !self.is_open {
+            self.open(selection);
+        } else if !is_focused && self.is_open {
+            self.close();
+        }
+    }
+}
+
+fn is_focused<Renderer: text::Renderer>(tree: &Tree) -> bool {
+    tree.state
+        .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+        .is_focused()
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DatePicker<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        Widget::<TextInputEvent, Theme, Renderer>::diff(&self.text_input, &mut tree.children[0]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<TextInputEvent, Theme, Renderer>::size(&self.text_input)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(text) = &state.text {
+            self.text_input.set_value(text);
+        }
+
+        self.text_input
+            .layout(&mut tree.children[0], renderer, limits, None)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let is_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        let value = match self.selection {
+            Some(range) if self.is_range => range.to_string(),
+            Some(range) => range.start.to_string(),
+            None => String::new(),
+        };
+
+        operation.container(None, layout.bounds());
+        operation.accessible(
+            None,
+            layout.bounds(),
+            &Accessible::new(Role::ComboBox)
+                .value(value)
+                .expanded(is_focused),
+        );
+        operation.traverse(&mut |operation| {
+            Widget::<TextInputEvent, Theme, Renderer>::operate(
+                &mut self.text_input,
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let started_focused = is_focused::<Renderer>(&tree.children[0]);
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(text) = &state.text {
+            self.text_input.set_value(text);
+        }
+
+        let mut local_messages = Vec::new();
+        let mut local_shell = Shell::new(&mut local_messages);
+
+        self.text_input.update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        for operation in local_shell.take_operations() {
+            shell.request_operation(operation);
+        }
+
+        for message in local_messages {
+            let TextInputEvent::TextChanged(text) = message;
+
+            let range = self.parse(&text);
+            state.text = Some(text);
+
+            if let Some(range) = range {
+                state.month = range.start.first_of_month();
+                state.cursor = Some(range.start);
+                state.anchor = None;
+
+                if self.selection != Some(range) {
+                    shell.publish((self.on_select)(range));
+                }
+            }
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        let is_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        if started_focused != is_focused {
+            state.sync(is_focused, self.selection);
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.text_input
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let text = state.text.as_deref().map(text_input::Value::new);
+
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor,
+            text.as_ref(),
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let is_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+        state.sync(is_focused, self.selection);
+
+        if !is_focused {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(
+            Calendar {
+                picker: self,
+                state,
+                input: children[0]
+                    .state
+                    .downcast_mut::<text_input::State<Renderer::Paragraph>>(),
+                position: layout.position() + translation,
+                target_height: bounds.height,
+            }
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DatePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(date_picker: DatePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(date_picker)
+    }
+}
+
+/// An interactive part of the calendar of a [`DatePicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    PreviousYear,
+    PreviousMonth,
+    NextMonth,
+    NextYear,
+    Day(Date),
+}
+
+/// The header buttons of the calendar, with their column and symbol.
+const HEADER: [(usize, &str, Target); 4] = [
+    (0, "«", Target::PreviousYear),
+    (1, "‹", Target::PreviousMonth),
+    (5, "›", Target::NextMonth),
+    (6, "»", Target::NextYear),
+];
+
+struct Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    picker: &'a DatePicker<'b, Message, Theme, Renderer>,
+    state: &'a mut State,
+    input: &'a mut text_input::State<Renderer::Paragraph>,
+    position: Point,
+    target_height: f32,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(self))
+    }
+
+    /// Returns the first date displayed in the grid of days.
+    fn start(&self) -> Date {
+        let month = self.state.month;
+        let offset = (month.weekday().index() + 7 - self.picker.first_weekday.index()) % 7;
+
+        month.add_days(-(offset as i64))
+    }
+
+    fn target(&self, bounds: Rectangle, cell: f32, point: Point) -> Option<Target> {
+        let column = ((point.x - bounds.x - PADDING) / cell).floor();
+        let row = ((point.y - bounds.y - PADDING) / cell).floor();
+
+        if !(0.0..7.0).contains(&column) || !(0.0..8.0).contains(&row) {
+            return None;
+        }
+
+        let (row, column) = (row as usize, column as usize);
+
+        match row {
+            0 => HEADER
+                .iter()
+                .find(|(header, _, _)| *header == column)
+                .map(|(_, _, target)| *target),
+            1 => None,
+            _ => Some(Target::Day(
+                self.start().add_days(((row - 2) * 7 + column) as i64),
+            )),
+        }
+    }
+
+    fn is_enabled(&self, target: Target) -> bool {
+        match target {
+            Target::Day(date) => self.picker.is_enabled(date),
+            _ => true,
+        }
+    }
+
+    fn scroll(&mut self, months: i32) {
+        self.state.month = self.state.month.add_months(months);
+    }
+
+    fn show(&mut self, date: Date) {
+        self.state.cursor = Some(date);
+        self.state.month = date.first_of_month();
+    }
+
+    fn pick(&mut self, date: Date, shell: &mut Shell<'_, Message>) {
+        if self.picker.is_range && self.state.anchor.is_none() {
+            self.state.anchor = Some(date);
+            self.state.cursor = Some(date);
+        } else {
+            let range = match self.state.anchor.take() {
+                Some(anchor) => Range::new(anchor, date),
+                None => Range::new(date, date),
+            };
+
+            shell.publish((self.picker.on_select)(range));
+
+            self.input.unfocus();
+            self.state.close();
+        }
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn close(&mut self, shell: &mut Shell<'_, Message>) {
+        self.input.unfocus();
+        self.state.close();
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let cell = self.picker.cell_size(renderer);
+        let size = Size::new(cell * 7.0 + PADDING * 2.0, cell * 8.0 + PADDING * 2.0);
+
+        layout::Node::new(size).move_to(dropdown_position(
+            self.position,
+            self.target_height,
+            size,
+            bounds,
+        ))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+        let cell = self.picker.cell_size(renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let hovered = cursor
+                    .position_over(bounds)
+                    .and_then(|position| self.target(bounds, cell, position));
+
+                if self.state.hovered != hovered {
+                    self.state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                match self.target(bounds, cell, position) {
+                    Some(Target::PreviousYear) => self.scroll(-12),
+                    Some(Target::PreviousMonth) => self.scroll(-1),
+                    Some(Target::NextMonth) => self.scroll(1),
+                    Some(Target::NextYear) => self.scroll(12),
+                    Some(Target::Day(date)) if self.picker.is_enabled(date) => {
+                        self.pick(date, shell);
+                    }
+                    _ => {}
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modifiers,
+                ..
+            }) => {
+                let current = self.state.cursor.unwrap_or(self.state.month);
+                let months = if modifiers.shift() { 12 } else { 1 };
+
+                match named {
+                    key::Named::ArrowUp => self.show(current.add_days(-7)),
+                    key::Named::ArrowDown => self.show(current.add_days(7)),
+                    key::Named::ArrowLeft if modifiers.alt() => self.show(current.add_days(-1)),
+                    key::Named::ArrowRight if modifiers.alt() => self.show(current.add_days(1)),
+                    key::Named::PageUp => self.show(current.add_months(-months)),
+                    key::Named::PageDown => self.show(current.add_months(months)),
+                    key::Named::Enter => match self.state.cursor {
+                        Some(date) if self.picker.is_enabled(date) => self.pick(date, shell),
+                        Some(_) => {}
+                        None => self.close(shell),
+                    },
+                    _ => return,
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::None;
+        };
+
+        match self.target(bounds, self.picker.cell_size(renderer), position) {
+            Some(target) if self.is_enabled(target) => mouse::Interaction::Pointer,
+            Some(_) => mouse::Interaction::NotAllowed,
+            None => mouse::Interaction::Idle,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = <Theme as Catalog>::style(theme, &self.picker.class);
+        let bounds = layout.bounds();
+
+        let text_size = self
+            .picker
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self.picker.font.unwrap_or_else(|| renderer.default_font());
+        let cell = self.picker.cell_size(renderer);
+
+        let cell_bounds = |row: usize, column: usize| Rectangle {
+            x: bounds.x + PADDING + column as f32 * cell,
+            y: bounds.y + PADDING + row as f32 * cell,
+            width: cell,
+            height: cell,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            style.background.clone(),
+        );
+
+        let highlight = |renderer: &mut Renderer, bounds: Rectangle, background: Background| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds.shrink(1.0),
+                    border: border::rounded(style.border.radius),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        };
+
+        for (column, symbol, target) in HEADER {
+            let bounds = cell_bounds(0, column);
+
+            if self.state.hovered == Some(target) {
+                highlight(renderer, bounds, style.hovered_background.clone());
+            }
+
+            fill_label(renderer, symbol, bounds, text_size, font, style.text_color);
+        }
+
+        let month = self.state.month;
+        let title = cell_bounds(0, 2).union(&cell_bounds(0, 4));
+
+        fill_label(
+            renderer,
+            &format!(
+                "{} {}",
+                self.picker.month_names[month.month() as usize - 1],
+                month.year()
+            ),
+            title,
+            text_size,
+            font,
+            style.text_color,
+        );
+
+        for column in 0..7 {
+            let weekday = (self.picker.first_weekday.index() + column) % 7;
+
+            fill_label(
+                renderer,
+                self.picker.weekday_names[weekday],
+                cell_bounds(1, column),
+                text_size,
+                font,
+                style.secondary_text_color,
+            );
+        }
+
+        let hovered = match self.state.hovered {
+            Some(Target::Day(date)) => Some(date),
+            _ => None,
+        };
+
+        let range = match self.state.anchor {
+            Some(anchor) => Some(Range::new(
+                anchor,
+                hovered.or(self.state.cursor).unwrap_or(anchor),
+            )),
+            None => self.picker.selection,
+        };
+
+        let today = Date::today();
+        let start = self.start();
+
+        for index in 0..42 {
+            let date = start.add_days(index as i64);
+            let bounds = cell_bounds(2 + index / 7, index % 7);
+
+            let is_enabled = self.picker.is_enabled(date);
+            let is_endpoint = range.is_some_and(|range| range.start == date || range.end == date);
+            let is_highlighted =
+                is_enabled && (hovered == Some(date) || self.state.cursor == Some(date));
+
+            if is_endpoint {
+                highlight(renderer, bounds, style.selected_background.clone());
+            } else if range.is_some_and(|range| range.contains(date)) {
+                highlight(renderer, bounds, style.range_background.clone());
+            } else if is_highlighted {
+                highlight(renderer, bounds, style.hovered_background.clone());
+            }
+
+            if date == today && !is_endpoint {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bounds.shrink(1.0),
+                        border: Border {
+                            color: style.today_color,
+                            width: 1.0,
+                            radius: style.border.radius,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            let text_color = if is_endpoint {
+                style.selected_text_color
+            } else if !is_enabled {
+                style.disabled_text_color
+            } else if date.month() != month.month() {
+                style.secondary_text_color
+            } else {
+                style.text_color
+            };
+
+            fill_label(
+                renderer,
+                &date.day().to_string(),
+                bounds,
+                text_size,
+                font,
+                text_color,
+            );
+        }
+    }
+}
+
+/// Positions a dropdown of the given size below its target, or above it
+/// if there is not enough space.
+pub(crate) fn dropdown_position(
+    position: Point,
+    target_height: f32,
+    size: Size,
+    bounds: Size,
+) -> Point {
+    let space_below = bounds.height - (position.y + target_height);
+
+    Point::new(
+        position.x.min(bounds.width - size.width).max(0.0),
+        if space_below >= size.height || space_below >= position.y {
+            position.y + target_height
+        } else {
+            (position.y - size.height).max(0.0)
+        },
+    )
+}
+
+/// Draws some text centered in the given bounds.
+pub(crate) fn fill_label<Renderer>(
+    renderer: &mut Renderer,
+    content: &str,
+    bounds: Rectangle,
+    size: Pixels,
+    font: Renderer::Font,
+    color: Color,
+) where
+    Renderer: text::Renderer,
+{
+    let hint_factor = renderer.scale_factor();
+
+    renderer.fill_text(
+        Text {
+            content: content.to_owned(),
+            bounds: bounds.size(),
+            size,
+            line_height: text::LineHeight::default(),
+            font,
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor,
+        },
+        bounds.center(),
+        color,
+        bounds,
+    );
+}
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new [`Date`] from its year, month and day, if valid.
+    ///
+    /// Months and days start at 1.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        ((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day))
+            .then_some(Self { year, month, day })
+    }
+
+    /// Returns the current [`Date`] in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Parses a [`Date`] in ISO 8601 format (e.g. `2024-03-15`).
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().splitn(3, '-');
+
+        let year = parts.next()?.trim().parse().ok()?;
+        let month = parts.next()?.trim().parse().ok()?;
+        let day = parts.next()?.trim().parse().ok()?;
+
+        Self::from_ymd(year, month, day)
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], starting at 1.
+    pub fn month(self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at 1.
+    pub fn day(self) -> u32 {
+        self.day
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::ALL[(self.to_days() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Returns the number of days in the month of the [`Date`].
+    pub fn days_in_month(self) -> u32 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Returns the first day of the month of the [`Date`].
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// Adds the given amount of days to the [`Date`].
+    ///
+    /// The amount can be negative.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Adds the given amount of months to the [`Date`], clamping its day
+    /// to the length of the resulting month.
+    ///
+    /// The amount can be negative.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = i64::from(self.year) * 12 + i64::from(self.month) - 1 + i64::from(months);
+
+        let year = index.div_euclid(12) as i32;
+        let month = index.rem_euclid(12) as u32 + 1;
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Returns the amount of days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i64::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the [`Date`] that is the given amount of days after 1970-01-01.
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+
+        Self {
+            year: (year_of_era + era * 400 + i64::from(month <= 2)) as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// All the days of the week, starting with Monday.
+    pub const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// An inclusive range of dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Range {
+    /// The first [`Date`] of the [`Range`].
+    pub start: Date,
+    /// The last [`Date`] of the [`Range`].
+    pub end: Date,
+}
+
+impl Range {
+    /// Creates a new [`Range`] between two dates, in any order.
+    pub fn new(a: Date, b: Date) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// Parses a [`Range`] of two ISO 8601 dates separated by `..`, an en dash
+    /// or a spaced hyphen (e.g. `2024-03-15 - 2024-03-20`).
+    pub fn parse(text: &str) -> Option<Self> {
+        let (start, end) = text
+            .split_once("..")
+            .or_else(|| text.split_once('–'))
+            .or_else(|| text.split_once(" - "))?;
+
+        Some(Self::new(Date::parse(start)?, Date::parse(end)?))
+    }
+
+    /// Returns true if the [`Range`] contains the given [`Date`].
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} – {}", self.start, self.end)
+    }
+}
+
+/// The appearance of the dropdown of a [`DatePicker`] or a [`TimePicker`].
+///
+/// [`TimePicker`]: crate::TimePicker
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the dropdown.
+    pub background: Background,
+    /// The [`Border`] of the dropdown.
+    pub border: Border,
+    /// The [`Shadow`] of the dropdown.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the dropdown.
+    pub text_color: Color,
+    /// The text [`Color`] of the days of the week and the days outside the
+    /// current month.
+    pub secondary_text_color: Color,
+    /// The text [`Color`] of a disabled value.
+    pub disabled_text_color: Color,
+    /// The [`Background`] of a hovered value.
+    pub hovered_background: Background,
+    /// The [`Background`] of a selected value.
+    pub selected_background: Background,
+    /// The text [`Color`] of a selected value.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the days inside a selected range.
+    pub range_background: Background,
+    /// The [`Color`] of the outline of the current day.
+    pub today_color: Color,
+}
+
+/// The theme catalog of a [`DatePicker`] and a [`TimePicker`].
+///
+/// [`TimePicker`]: crate::TimePicker
+pub trait Catalog: text_input::Catalog {
+    /// The item class of the dropdown of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class for the dropdown of the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the text input of the [`Catalog`].
+    fn default_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        <Self as text_input::Catalog>::default()
+    }
+
+    /// The [`Style`] of the dropdown of a class.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+}
+
+/// A styling function for the dropdown of a [`DatePicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of the dropdown of a [`DatePicker`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+            ..Shadow::default()
+        },
+        text_color: palette.background.base.text,
+        secondary_text_color: palette.background.base.text.scale_alpha(0.6),
+        disabled_text_color: palette.background.base.text.scale_alpha(0.3),
+        hovered_background: palette.background.weak.color.into(),
+        selected_background: palette.primary.base.color.into(),
+        selected_text_color: palette.primary.base.text,
+        range_background: palette.primary.base.color.scale_alpha(0.2).into(),
+        today_color: palette.primary.base.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_arithmetic() {
+        let date = Date::from_ymd(2024, 2, 28).unwrap();
+
+        assert_eq!(date.add_days(1), Date::from_ymd(2024, 2, 29).unwrap());
+        assert_eq!(date.add_days(2), Date::from_ymd(2024, 3, 1).unwrap());
+        assert_eq!(date.add_days(-59), Date::from_ymd(2023, 12, 31).unwrap());
+        assert_eq!(Date::from_days(0), Date::from_ymd(1970, 1, 1).unwrap());
+        assert_eq!(Date::from_days(date.to_days()), date);
+
+        let date = Date::from_ymd(2024, 1, 31).unwrap();
+
+        assert_eq!(date.add_months(1), Date::from_ymd(2024, 2, 29).unwrap());
+        assert_eq!(date.add_months(-13), Date::from_ymd(2022, 12, 31).unwrap());
+        assert_eq!(date.weekday(), Weekday::Wednesday);
+
+        assert_eq!(Date::from_ymd(2023, 2, 29), None);
+        assert_eq!(Date::parse(" 2024-03-15 "), Date::from_ymd(2024, 3, 15));
+        assert_eq!(
+            Range::parse("2024-03-20 - 2024-03-15"),
+            Some(Range::new(
+                Date::from_ymd(2024, 3, 15).unwrap(),
+                Date::from_ymd(2024, 3, 20).unwrap()
+            ))
+        );
+    }
+}
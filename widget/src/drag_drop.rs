@@ -0,0 +1,1154 @@
+//! Drag items from one widget and drop them onto another.
+//!
+//! A [`DragSource`] turns any widget into a draggable item carrying a
+//! payload of your choice, while a [`DropTarget`] receives the payloads
+//! dropped onto it. Both keep track of the drag in the widget tree, so
+//! your application only hears about it through messages.
+//!
+//! Sources and targets interact with each other when their payloads have
+//! the same type. Use different payload types to keep separate drag and
+//! drop interactions apart.
+//!
+//! While an item is being dragged, a preview of it follows the cursor and
+//! any [`Scrollable`](crate::Scrollable) is scrolled automatically when the
+//! cursor approaches one of its edges. Pressing `Escape` cancels the drag.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, drag_source, drop_target, row, text};
+//!
+//! struct State {
+//!     todo: Vec<String>,
+//!     done: Vec<String>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Completed(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let todo = column(
+//!         state
+//!             .todo
+//!             .iter()
+//!             .enumerate()
+//!             .map(|(index, task)| drag_source(index, text(task)).into()),
+//!     );
+//!
+//!     let done = drop_target(column(state.done.iter().map(|task| text(task).into())))
+//!         .on_drop(Message::Completed);
+//!
+//!     row![todo, done].spacing(20).into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Completed(index) => {
+//!             let task = state.todo.remove(index);
+//!             state.done.push(task);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::border::Border;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::operation::Outcome;
+use crate::core::widget::operation::scrollable::{AbsoluteOffset, Scrollable};
+use crate::core::widget::{self, Operation, Tree, tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Theme, Vector, Widget,
+};
+
+use std::any::Any;
+use std::marker::PhantomData;
+
+const DRAG_DEADBAND_DISTANCE: f32 = 5.0;
+const AUTO_SCROLL_MARGIN: f32 = 40.0;
+const AUTO_SCROLL_SPEED: f32 = 800.0;
+
+/// A widget that can be dragged around, carrying a payload that can be
+/// dropped onto a [`DropTarget`].
+///
+/// A drag starts once the cursor moves a few pixels away from where the
+/// widget was pressed. Interactive content—like a button—keeps its own
+/// behavior; only presses that the content ignores can start a drag.
+#[allow(missing_debug_implementations)]
+pub struct DragSource<'a, P, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    payload: P,
+    content: Element<'a, Message, Theme, Renderer>,
+    preview: Option<Element<'a, Message, Theme, Renderer>>,
+    on_drag: Option<Message>,
+    on_cancel: Option<Message>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, P, Message, Theme, Renderer> DragSource<'a, P, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`DragSource`] with the given payload and content.
+    pub fn new(payload: P, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            payload,
+            content: content.into(),
+            preview: None,
+            on_drag: None,
+            on_cancel: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the element displayed under the cursor while the
+    /// [`DragSource`] is being dragged.
+    ///
+    /// By default, the content of the [`DragSource`] itself is displayed.
+    pub fn preview(mut self, preview: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.preview = Some(preview.into());
+        self
+    }
+
+    /// Sets the message that will be produced when the [`DragSource`]
+    /// starts being dragged.
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the drag of the
+    /// [`DragSource`] is cancelled by pressing `Escape`.
+    pub fn on_cancel(mut self, message: Message) -> Self {
+        self.on_cancel = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`DragSource`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DragSource`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The state of a [`DragSource`].
+#[derive(Debug, Clone, Default)]
+struct Source {
+    interaction: Interaction,
+    /// The visible bounds of the scrollables that were being scrolled
+    /// automatically on the last frame.
+    scrollables: Vec<Rectangle>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum Interaction {
+    #[default]
+    Idle,
+    Pressed {
+        origin: Point,
+    },
+    Dragging {
+        grab: Vector,
+        last_frame: Option<Instant>,
+    },
+}
+
+impl<P, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragSource<'_, P, Message, Theme, Renderer>
+where
+    P: Clone + Send + 'static,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Source>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Source::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.preview)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(&self.preview)
+            .map(Element::as_widget)
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.custom(None, layout.bounds(), tree.state.downcast_mut::<Source>());
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let source = tree.state.downcast_mut::<Source>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !shell.is_event_captured()
+                    && let Some(origin) = cursor.position_over(layout.bounds())
+                {
+                    source.interaction = Interaction::Pressed { origin };
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => match source.interaction {
+                Interaction::Pressed { origin } => {
+                    if let Some(position) = cursor.position()
+                        && position.distance(origin) > DRAG_DEADBAND_DISTANCE
+                    {
+                        source.interaction = Interaction::Dragging {
+                            grab: origin - layout.position(),
+                            last_frame: None,
+                        };
+
+                        if let Some(on_drag) = &self.on_drag {
+                            shell.publish(on_drag.clone());
+                        }
+
+                        shell.request_operation(drag(Some(self.payload.clone())));
+                        shell.capture_event();
+                        shell.request_redraw();
+                    }
+                }
+                Interaction::Dragging { .. } => {
+                    // Targets may show up while dragging
+                    shell.request_operation(drag(Some(self.payload.clone())));
+                }
+                Interaction::Idle => {}
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Interaction::Dragging { .. } = source.interaction {
+                    shell.request_operation(drag::<P>(None));
+                    shell.request_redraw();
+                }
+
+                *source = Source::default();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if let Interaction::Dragging { .. } = tree.state.downcast_ref::<Source>().interaction {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::None && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let status =
+            if let Interaction::Dragging { .. } = tree.state.downcast_ref::<Source>().interaction {
+                Status::Dragged
+            } else {
+                Status::Idle
+            };
+
+        draw_highlight(renderer, &theme.style(&self.class, status), layout.bounds());
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let source = tree.state.downcast_mut::<Source>();
+        let (content_tree, preview_tree) = tree.children.split_at_mut(1);
+
+        if let Interaction::Dragging { .. } = source.interaction {
+            return Some(overlay::Element::new(Box::new(Preview {
+                source,
+                content: &self.content,
+                content_tree: &content_tree[0],
+                layout,
+                position: layout.position() + translation,
+                preview: self.preview.as_mut().zip(preview_tree.first_mut()),
+                on_cancel: self.on_cancel.as_ref(),
+                payload: PhantomData::<P>,
+            })));
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut content_tree[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, P, Message, Theme, Renderer> From<DragSource<'a, P, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    P: Clone + Send + 'static,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(source: DragSource<'a, P, Message, Theme, Renderer>) -> Self {
+        Element::new(source)
+    }
+}
+
+/// The preview of a [`DragSource`] that follows the cursor while dragging.
+struct Preview<'a, 'b, P, Message, Theme, Renderer> {
+    source: &'b mut Source,
+    content: &'b Element<'a, Message, Theme, Renderer>,
+    content_tree: &'b Tree,
+    layout: Layout<'b>,
+    position: Point,
+    preview: Option<(&'b mut Element<'a, Message, Theme, Renderer>, &'b mut Tree)>,
+    on_cancel: Option<&'b Message>,
+    payload: PhantomData<P>,
+}
+
+impl<P, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Preview<'_, '_, P, Message, Theme, Renderer>
+where
+    P: Clone + Send + 'static,
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let node = match &mut self.preview {
+            Some((preview, tree)) => {
+                let node = preview.as_widget_mut().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, bounds),
+                );
+
+                layout::Node::with_children(node.size(), vec![node])
+            }
+            None => layout::Node::new(self.layout.bounds().size()),
+        };
+
+        node.move_to(self.position)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        _layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Interaction::Dragging { grab, last_frame } = self.source.interaction else {
+            return;
+        };
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                *self.source = Source::default();
+
+                if let Some(on_cancel) = self.on_cancel {
+                    shell.publish(on_cancel.clone());
+                }
+
+                shell.request_operation(drag::<P>(None));
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                shell.request_redraw();
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                let elapsed = last_frame.map(|last_frame| *now - last_frame);
+
+                shell.request_operation(auto_scroll(position, elapsed.unwrap_or_default()));
+
+                // Scrolling needs a new frame even if the cursor stays still
+                let is_scrolling = self.source.scrollables.iter().any(|bounds| {
+                    bounds.contains(position)
+                        && auto_scroll_velocity(*bounds, position) != Vector::ZERO
+                });
+
+                if is_scrolling {
+                    shell.request_redraw();
+                }
+
+                self.source.interaction = Interaction::Dragging {
+                    grab,
+                    last_frame: is_scrolling.then_some(*now),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let (Interaction::Dragging { grab, .. }, Some(position)) =
+            (self.source.interaction, cursor.position())
+        else {
+            return;
+        };
+
+        let origin = position - grab;
+        let viewport = Rectangle::with_size(Size::INFINITE);
+
+        match &self.preview {
+            Some((preview, tree)) => {
+                let layout = layout.children().next().unwrap();
+
+                renderer.with_translation(origin - layout.position(), |renderer| {
+                    preview.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        mouse::Cursor::Unavailable,
+                        &viewport,
+                    );
+                });
+            }
+            None => {
+                renderer.with_translation(origin - self.layout.position(), |renderer| {
+                    self.content.as_widget().draw(
+                        self.content_tree,
+                        renderer,
+                        theme,
+                        style,
+                        self.layout,
+                        mouse::Cursor::Unavailable,
+                        &viewport,
+                    );
+                });
+            }
+        }
+    }
+}
+
+/// A widget that accepts the payloads of the [`DragSource`] widgets
+/// dropped onto it.
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<'a, P, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Option<Box<dyn Fn(P) -> Message + 'a>>,
+    accept: Option<Box<dyn Fn(&P) -> bool + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, P, Message, Theme, Renderer> DropTarget<'a, P, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`DropTarget`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: None,
+            accept: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when a payload is dropped
+    /// onto the [`DropTarget`].
+    ///
+    /// If this method is not called, the [`DropTarget`] will not accept
+    /// any payload.
+    pub fn on_drop(mut self, on_drop: impl Fn(P) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the predicate that decides whether a payload can be dropped onto
+    /// the [`DropTarget`].
+    ///
+    /// By default, every payload is accepted.
+    pub fn accept(mut self, accept: impl Fn(&P) -> bool + 'a) -> Self {
+        self.accept = Some(Box::new(accept));
+        self
+    }
+
+    /// Sets the style of the [`DropTarget`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DropTarget`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_accepted(&self, payload: &P) -> bool {
+        self.on_drop.is_some() && self.accept.as_ref().is_none_or(|accept| accept(payload))
+    }
+}
+
+/// The state of a [`DropTarget`].
+#[derive(Debug, Clone)]
+struct Target<P> {
+    /// The payload being dragged, if any.
+    payload: Option<P>,
+}
+
+impl<P, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropTarget<'_, P, Message, Theme, Renderer>
+where
+    P: 'static,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Target<P>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Target::<P> { payload: None })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.custom(
+            None,
+            layout.bounds(),
+            tree.state.downcast_mut::<Target<P>>(),
+        );
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. }) = event
+        {
+            // Any release ends the drag, even if its source is gone
+            let target = tree.state.downcast_mut::<Target<P>>();
+
+            if let Some(payload) = target.payload.take()
+                && let Some(on_drop) = &self.on_drop
+                && !shell.is_event_captured()
+                && cursor.is_over(layout.bounds())
+                && self.is_accepted(&payload)
+            {
+                shell.publish(on_drop(payload));
+                shell.capture_event();
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let target = tree.state.downcast_ref::<Target<P>>();
+
+        let status = if !target
+            .payload
+            .as_ref()
+            .is_some_and(|payload| self.is_accepted(payload))
+        {
+            Status::Idle
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        draw_highlight(renderer, &theme.style(&self.class, status), layout.bounds());
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, P, Message, Theme, Renderer> From<DropTarget<'a, P, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    P: 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(target: DropTarget<'a, P, Message, Theme, Renderer>) -> Self {
+        Element::new(target)
+    }
+}
+
+/// Draws the highlight of a [`Style`] on top of some content.
+fn draw_highlight<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle)
+where
+    Renderer: renderer::Renderer,
+{
+    if style.background.is_none() && style.border.width == 0.0 {
+        return;
+    }
+
+    renderer.with_layer(bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style
+                .background
+                .clone()
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+    });
+}
+
+/// Produces an [`Operation`] that lets every [`DropTarget`] with the same
+/// payload type know about the payload being dragged, if any.
+fn drag<P>(payload: Option<P>) -> impl Operation
+where
+    P: Clone + Send + 'static,
+{
+    struct Drag<P> {
+        payload: Option<P>,
+    }
+
+    impl<P> Operation for Drag<P>
+    where
+        P: Clone + Send + 'static,
+    {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, _id: Option<&widget::Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if let Some(target) = state.downcast_mut::<Target<P>>() {
+                target.payload = self.payload.clone();
+            }
+        }
+    }
+
+    Drag { payload }
+}
+
+/// Produces an [`Operation`] that scrolls the scrollables whose edges are
+/// close to the cursor, given the time elapsed since the last frame.
+///
+/// The scrollables being scrolled are then stored in the [`DragSource`]
+/// being dragged; so it can keep requesting frames while they scroll.
+fn auto_scroll(cursor: Point, elapsed: Duration) -> impl Operation {
+    struct AutoScroll {
+        cursor: Point,
+        distance: f32,
+        translation: Vector,
+        scroll: Vector,
+        scrollables: Vec<Rectangle>,
+    }
+
+    impl Operation for AutoScroll {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            let translation = self.translation;
+
+            self.translation = translation - std::mem::take(&mut self.scroll);
+
+            operate(self);
+
+            self.translation = translation;
+        }
+
+        fn scrollable(
+            &mut self,
+            _id: Option<&widget::Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            let visible = bounds + self.translation;
+
+            if visible.contains(self.cursor) {
+                let velocity = auto_scroll_velocity(visible, self.cursor);
+
+                if velocity != Vector::ZERO {
+                    state.scroll_by(
+                        AbsoluteOffset {
+                            x: velocity.x * self.distance,
+                            y: velocity.y * self.distance,
+                        },
+                        bounds,
+                        content_bounds,
+                    );
+
+                    self.scrollables.push(visible);
+                }
+            }
+
+            self.scroll = translation;
+        }
+
+        fn finish(&self) -> Outcome<()> {
+            Outcome::Chain(Box::new(store(self.scrollables.clone())))
+        }
+    }
+
+    fn store(scrollables: Vec<Rectangle>) -> impl Operation {
+        struct Store {
+            scrollables: Vec<Rectangle>,
+        }
+
+        impl Operation for Store {
+            fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+                operate(self);
+            }
+
+            fn custom(
+                &mut self,
+                _id: Option<&widget::Id>,
+                _bounds: Rectangle,
+                state: &mut dyn Any,
+            ) {
+                if let Some(source) = state.downcast_mut::<Source>()
+                    && let Interaction::Dragging { .. } = source.interaction
+                {
+                    source.scrollables = std::mem::take(&mut self.scrollables);
+                }
+            }
+        }
+
+        Store { scrollables }
+    }
+
+    AutoScroll {
+        cursor,
+        distance: AUTO_SCROLL_SPEED * elapsed.as_secs_f32(),
+        translation: Vector::ZERO,
+        scroll: Vector::ZERO,
+        scrollables: Vec::new(),
+    }
+}
+
+/// Returns the direction and speed—from -1.0 to 1.0 in each axis—at which
+/// a scrollable with the given visible bounds should scroll, given the
+/// position of the cursor.
+fn auto_scroll_velocity(bounds: Rectangle, cursor: Point) -> Vector {
+    let speed = |start: f32, length: f32, position: f32| {
+        let margin = AUTO_SCROLL_MARGIN.min(length / 4.0);
+
+        if position < start + margin {
+            (position - start) / margin - 1.0
+        } else if position > start + length - margin {
+            1.0 - (start + length - position) / margin
+        } else {
+            0.0
+        }
+    };
+
+    Vector::new(
+        speed(bounds.x, bounds.width, cursor.x),
+        speed(bounds.y, bounds.height, cursor.y),
+    )
+}
+
+/// The possible status of a [`DragSource`] or a [`DropTarget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// No drag is involving the widget.
+    Idle,
+    /// The [`DragSource`] is being dragged.
+    Dragged,
+    /// The [`DropTarget`] accepts the payload being dragged.
+    Active,
+    /// The [`DropTarget`] accepts the payload being dragged and the cursor
+    /// is over it.
+    Hovered,
+}
+
+/// The appearance of a [`DragSource`] or a [`DropTarget`].
+///
+/// The [`Style`] is drawn on top of the content of the widget.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Style {
+    /// The [`Background`] drawn over the content.
+    pub background: Option<Background>,
+    /// The [`Border`] drawn around the content.
+    pub border: Border,
+}
+
+/// The theme catalog of the [`DragSource`] and [`DropTarget`] widgets.
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`DragSource`] or a [`DropTarget`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`DragSource`] or a [`DropTarget`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Idle => Style::default(),
+        Status::Dragged => Style {
+            background: Some(palette.background.base.color.scale_alpha(0.6).into()),
+            border: Border::default(),
+        },
+        Status::Active => Style {
+            background: None,
+            border: Border {
+                color: palette.primary.weak.color,
+                width: 1.0,
+                radius: 2.0.into(),
+            },
+        },
+        Status::Hovered => Style {
+            background: Some(palette.primary.weak.color.scale_alpha(0.2).into()),
+            border: Border {
+                color: palette.primary.strong.color,
+                width: 2.0,
+                radius: 2.0.into(),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Space;
+    use crate::core::clipboard;
+
+    #[test]
+    fn auto_scrolling_speeds_up_near_the_edges() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(400.0, 400.0));
+        let velocity = |x, y| auto_scroll_velocity(bounds, Point::new(x, y));
+
+        assert_eq!(velocity(200.0, 200.0), Vector::ZERO);
+        assert_eq!(velocity(200.0, 20.0), Vector::new(0.0, -0.5));
+        assert_eq!(velocity(390.0, 200.0), Vector::new(0.75, 0.0));
+    }
+
+    #[test]
+    fn payloads_are_dropped_onto_accepting_targets() {
+        let mut target: Element<'_, &str, crate::Theme, ()> =
+            DropTarget::new(Space::new().width(50).height(50))
+                .on_drop(|payload| payload)
+                .accept(|payload: &&str| !payload.is_empty())
+                .into();
+
+        let mut tree = Tree::new(&target);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+
+        let node = target.as_widget_mut().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, bounds.size()),
+        );
+
+        let mut drop = |payload: Option<&'static str>, x, y| {
+            target
+                .as_widget_mut()
+                .operate(&mut tree, Layout::new(&node), &(), &mut drag(payload));
+
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            target.as_widget_mut().update(
+                &mut tree,
+                &Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(x, y)),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &bounds,
+            );
+
+            messages
+        };
+
+        assert_eq!(drop(Some("card"), 25.0, 25.0), ["card"]);
+        assert_eq!(drop(Some(""), 25.0, 25.0), [""; 0]);
+        assert_eq!(drop(Some("card"), 75.0, 75.0), [""; 0]);
+
+        // Releasing without a drag in progress drops nothing
+        assert_eq!(drop(None, 25.0, 25.0), [""; 0]);
+    }
+}
@@ -0,0 +1,264 @@
+//! A focus scope changes how the focus moves among the widgets it contains.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{button, column, focus_scope, text_input};
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     NameChanged(String),
+//!     Submit,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     focus_scope(column![
+//!         text_input("Name", "").on_input(Message::NameChanged),
+//!         button("Submit").on_press(Message::Submit),
+//!     ])
+//!     .trap(true)
+//!     .into()
+//! }
+//! ```
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget;
+use crate::core::widget::operation::focusable::Scope;
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A widget that changes how the focus moves among the widgets it contains.
+///
+/// A [`FocusScope`] can set the tab index of its contents, and it can trap
+/// the focus inside of itself; which is useful for modals.
+///
+/// When a trap shows up, the focus moves to its first focusable widget. When
+/// it goes away, the focus is restored to the widget that was focused before.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{button, column, focus_scope, text_input};
+///
+/// #[derive(Clone)]
+/// enum Message {
+///     NameChanged(String),
+///     Submit,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     focus_scope(column![
+///         text_input("Name", "").on_input(Message::NameChanged),
+///         button("Submit").on_press(Message::Submit),
+///     ])
+///     .trap(true)
+///     .into()
+/// }
+/// ```
+pub struct FocusScope<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    id: Option<widget::Id>,
+    content: Element<'a, Message, Theme, Renderer>,
+    scope: Scope,
+}
+
+impl<'a, Message, Theme, Renderer> FocusScope<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`FocusScope`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            id: None,
+            content: content.into(),
+            scope: Scope::default(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`FocusScope`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the tab index of the focusable widgets inside the [`FocusScope`].
+    ///
+    /// Widgets with a positive tab index are focused first, in ascending
+    /// order. Widgets with a negative tab index cannot be reached with
+    /// the focus operations that move the focus forward or backward.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.scope.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Sets whether the [`FocusScope`] traps the focus.
+    pub fn trap(mut self, trap: bool) -> Self {
+        self.scope.is_trap = trap;
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FocusScope<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<Scope>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(self.scope)
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        *tree.state.downcast_mut::<Scope>() = self.scope;
+        tree.diff_children(&[self.content.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.focus_scope(
+            self.id.as_ref(),
+            layout.bounds(),
+            self.scope,
+            &mut |operation| {
+                self.content.as_widget_mut().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            },
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FocusScope<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(
+        focus_scope: FocusScope<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(focus_scope)
+    }
+}
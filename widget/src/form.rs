@@ -0,0 +1,1037 @@
+//! Validate the fields of a form before submitting it.
+//!
+//! A [`Field`] holds the value of an input in your application state,
+//! together with the [`Validator`]s it must satisfy. A [`Control`] displays
+//! any widget as a field of the form—with an optional label on top and the
+//! current error of the [`Field`] under it.
+//!
+//! When the form is submitted, [`submit`] validates all of its fields and
+//! returns the [`widget::Id`] of the first invalid one, which can be focused
+//! with the [`focus`](crate::core::widget::operation::focusable::focus)
+//! operation.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::form::{self, Field, Validator};
+//! use iced::widget::{button, checkbox, column, text_input};
+//!
+//! struct State {
+//!     email: Field<String>,
+//!     terms: Field<bool>,
+//! }
+//!
+//! impl Default for State {
+//!     fn default() -> Self {
+//!         Self {
+//!             email: Field::new(String::new())
+//!                 .validator(Validator::required("The email is required"))
+//!                 .validator(Validator::custom(|email: &String| {
+//!                     if email.contains('@') {
+//!                         Ok(())
+//!                     } else {
+//!                         Err(String::from("The email is not valid"))
+//!                     }
+//!                 })),
+//!             terms: Field::new(false)
+//!                 .validator(Validator::required("You must accept the terms")),
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     EmailChanged(String),
+//!     TermsToggled(bool),
+//!     Submit,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column![
+//!         form::control(
+//!             &state.email,
+//!             text_input("you@example.com", state.email.value()).on_input(Message::EmailChanged),
+//!         )
+//!         .label("Email"),
+//!         form::control(
+//!             &state.terms,
+//!             checkbox(*state.terms.value())
+//!                 .label("I accept the terms")
+//!                 .on_toggle(Message::TermsToggled),
+//!         ),
+//!         button("Submit").on_press(Message::Submit),
+//!     ]
+//!     .spacing(10)
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::EmailChanged(email) => state.email.update(email),
+//!         Message::TermsToggled(terms) => state.terms.update(terms),
+//!         Message::Submit => match form::submit(&mut [&mut state.email, &mut state.terms]) {
+//!             Ok(()) => {
+//!                 // The form is valid!
+//!             }
+//!             Err(_invalid) => {
+//!                 // Focus the first invalid field with its `widget::Id`
+//!             }
+//!         },
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
+use crate::core::widget;
+use crate::core::widget::operation::{self, Accessible, Focusable, Operation, Scrollable};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Border, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme, Vector, Widget,
+};
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+use std::any::Any;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A rule that the value of a [`Field`] must satisfy.
+pub struct Validator<T> {
+    check: Arc<dyn Fn(&T) -> Result<(), String> + Send + Sync>,
+}
+
+impl<T> Validator<T> {
+    /// Creates a new [`Validator`] from the given function.
+    ///
+    /// The function must return the error message to display when the
+    /// value is not valid.
+    pub fn custom(check: impl Fn(&T) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self {
+            check: Arc::new(check),
+        }
+    }
+
+    /// Creates a [`Validator`] that fails with the given message when the
+    /// value is equal to its default value; like an empty `String`, `None`,
+    /// or an unchecked `bool`.
+    pub fn required(message: impl Into<String>) -> Self
+    where
+        T: Default + PartialEq,
+    {
+        let message = message.into();
+
+        Self::custom(move |value| {
+            if *value == T::default() {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Creates a [`Validator`] that fails with the given message when the
+    /// value is not contained in the given range.
+    pub fn range(range: RangeInclusive<T>, message: impl Into<String>) -> Self
+    where
+        T: PartialOrd + Send + Sync + 'static,
+    {
+        let message = message.into();
+
+        Self::custom(move |value| {
+            if range.contains(value) {
+                Ok(())
+            } else {
+                Err(message.clone())
+            }
+        })
+    }
+
+    /// Creates a [`Validator`] that fails with the given message when the
+    /// text does not match the given regular expression.
+    ///
+    /// Empty text is always valid; combine it with [`Validator::required`]
+    /// if the [`Field`] must not be empty.
+    ///
+    /// It is only available with the `regex` feature.
+    ///
+    /// Fails if the pattern is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str, message: impl Into<String>) -> Result<Self, regex::Error>
+    where
+        T: AsRef<str>,
+    {
+        let regex = Regex::new(pattern)?;
+        let message = message.into();
+
+        Ok(Self::custom(move |value| {
+            let value = value.as_ref();
+
+            if value.is_empty() || regex.is_match(value) {
+                Ok(())
+            } else {
+                Err(message.clone())
+            }
+        }))
+    }
+
+    /// Creates a [`Validator`] that parses the text and checks the parsed
+    /// value with the given [`Validator`].
+    ///
+    /// The [`Validator`] fails with the given message when the text cannot
+    /// be parsed. Empty text is always valid.
+    ///
+    /// This is useful to validate the [`range`](Self::range) of a number
+    /// entered in a text input.
+    pub fn parse<V>(validator: Validator<V>, message: impl Into<String>) -> Self
+    where
+        T: AsRef<str>,
+        V: FromStr + 'static,
+    {
+        let message = message.into();
+
+        Self::custom(move |value| {
+            let value = value.as_ref().trim();
+
+            if value.is_empty() {
+                return Ok(());
+            }
+
+            let parsed = value.parse().map_err(|_| message.clone())?;
+
+            (validator.check)(&parsed)
+        })
+    }
+
+    /// Checks the given value, returning the error message if it is not
+    /// valid.
+    pub fn check(&self, value: &T) -> Result<(), String> {
+        (self.check)(value)
+    }
+}
+
+impl<T> Clone for Validator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            check: self.check.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Validator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Validator").finish_non_exhaustive()
+    }
+}
+
+/// The value of an input of a form, together with its [`Validator`]s.
+///
+/// A [`Field`] does not display its error until it is validated for the
+/// first time—normally, when the form is submitted. From then on, the error
+/// is kept up to date every time the value changes.
+#[derive(Clone)]
+pub struct Field<T> {
+    value: T,
+    validators: Vec<Validator<T>>,
+    id: widget::Id,
+    error: Option<String>,
+    is_touched: bool,
+}
+
+impl<T> Field<T> {
+    /// Creates a new [`Field`] with the given initial value.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            validators: Vec::new(),
+            id: widget::Id::unique(),
+            error: None,
+            is_touched: false,
+        }
+    }
+
+    /// Adds a [`Validator`] to the [`Field`].
+    ///
+    /// The [`Validator`]s run in the order they are added, and only the
+    /// error of the first failing one is displayed.
+    pub fn validator(mut self, validator: Validator<T>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Returns the current value of the [`Field`].
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the [`widget::Id`] of the [`Field`].
+    ///
+    /// A [`Control`] gives this [`widget::Id`] to the first focusable
+    /// widget it contains, unless it already has one.
+    pub fn id(&self) -> &widget::Id {
+        &self.id
+    }
+
+    /// Returns the error of the [`Field`] to display, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns whether the current value of the [`Field`] is valid.
+    ///
+    /// This does not change the displayed error.
+    pub fn is_valid(&self) -> bool {
+        self.check().is_ok()
+    }
+
+    /// Changes the value of the [`Field`].
+    ///
+    /// If the [`Field`] has already been validated, its error is updated.
+    pub fn update(&mut self, value: T) {
+        self.value = value;
+
+        if self.is_touched {
+            self.error = self.check().err();
+        }
+    }
+
+    /// Validates the [`Field`] and displays its error, if any.
+    ///
+    /// Returns whether the [`Field`] is valid.
+    pub fn validate(&mut self) -> bool {
+        self.is_touched = true;
+        self.error = self.check().err();
+
+        self.error.is_none()
+    }
+
+    /// Replaces the value of the [`Field`] and hides its error until it is
+    /// validated again.
+    pub fn reset(&mut self, value: T) {
+        self.value = value;
+        self.error = None;
+        self.is_touched = false;
+    }
+
+    fn check(&self) -> Result<(), String> {
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.check(&self.value))
+    }
+}
+
+impl<T: Default> Default for Field<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Field<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("value", &self.value)
+            .field("id", &self.id)
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A field of a form that can be validated, regardless of the type of its
+/// value.
+pub trait Validate {
+    /// Returns the [`widget::Id`] of the field.
+    fn id(&self) -> &widget::Id;
+
+    /// Returns whether the field is valid, without displaying its error.
+    fn is_valid(&self) -> bool;
+
+    /// Validates the field and displays its error, if any.
+    ///
+    /// Returns whether the field is valid.
+    fn validate(&mut self) -> bool;
+}
+
+impl<T> Validate for Field<T> {
+    fn id(&self) -> &widget::Id {
+        Field::id(self)
+    }
+
+    fn is_valid(&self) -> bool {
+        Field::is_valid(self)
+    }
+
+    fn validate(&mut self) -> bool {
+        Field::validate(self)
+    }
+}
+
+/// Validates all the given fields of a form and displays their errors.
+///
+/// Returns the [`widget::Id`] of the first invalid field, if any; so it can
+/// be focused before submitting the form.
+pub fn submit(fields: &mut [&mut dyn Validate]) -> Result<(), widget::Id> {
+    let mut invalid = None;
+
+    for field in fields {
+        if !field.validate() && invalid.is_none() {
+            invalid = Some(field.id().clone());
+        }
+    }
+
+    invalid.map_or(Ok(()), Err)
+}
+
+/// Returns whether all the given fields of a form are valid, without
+/// displaying their errors.
+///
+/// This is useful to disable the submit button of a form until it is valid.
+pub fn is_valid(fields: &[&dyn Validate]) -> bool {
+    fields.iter().all(|field| field.is_valid())
+}
+
+/// Creates a new [`Control`] that displays the given content as the
+/// [`Field`] of a form.
+pub fn control<'a, T, Message, Theme, Renderer>(
+    field: &Field<T>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Control<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    let control = Control::new(content).id(field.id().clone());
+
+    if let Some(error) = field.error() {
+        control.error(error)
+    } else {
+        control
+    }
+}
+
+/// A widget that displays some content as a field of a form, with a label
+/// on top and an error under it.
+///
+/// A [`Control`] gives its [`widget::Id`] to the first focusable widget of
+/// its content without one; so the invalid fields of a form can be focused.
+pub struct Control<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    content: Element<'a, Message, Theme, Renderer>,
+    label: Option<String>,
+    error: Option<String>,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Control<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default spacing between the label, the content, and the error of
+    /// a [`Control`].
+    pub const DEFAULT_SPACING: f32 = 5.0;
+
+    /// Creates a new [`Control`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            id: None,
+            content: content.into(),
+            label: None,
+            error: None,
+            spacing: Self::DEFAULT_SPACING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`Control`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the label displayed on top of the [`Control`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the error displayed under the [`Control`].
+    ///
+    /// A [`Control`] with an error is [`Status::Invalid`].
+    pub fn error(mut self, error: impl Into<String>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    /// Sets the spacing between the label, the content, and the error of
+    /// the [`Control`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the label and the error of the [`Control`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the label and the error of the [`Control`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Control`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Control`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn status(&self) -> Status {
+        if self.error.is_some() {
+            Status::Invalid
+        } else {
+            Status::Valid
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: paragraph::Plain<P>,
+    error: paragraph::Plain<P>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Control<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        let size = self.content.as_widget().size();
+
+        Size::new(size.width, Length::Shrink)
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let max_width = limits.max().width;
+
+        let text = Text {
+            content: "",
+            bounds: Size::new(max_width, f32::INFINITY),
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::default(),
+            wrapping: text::Wrapping::default(),
+            hint_factor: renderer.scale_factor(),
+        };
+
+        let label = if let Some(label) = &self.label {
+            let _ = state.label.update(Text {
+                content: label,
+                ..text
+            });
+
+            state.label.min_bounds()
+        } else {
+            Size::ZERO
+        };
+
+        let label_height = if self.label.is_some() {
+            label.height + self.spacing
+        } else {
+            0.0
+        };
+
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(0.0, label_height)),
+        );
+
+        let content_size = content.size();
+        let width = content_size.width.max(label.width).min(max_width);
+
+        let error_height = if let Some(error) = &self.error {
+            let _ = state.error.update(Text {
+                content: error,
+                bounds: Size::new(width, f32::INFINITY),
+                ..text
+            });
+
+            self.spacing + state.error.min_height()
+        } else {
+            0.0
+        };
+
+        layout::Node::with_children(
+            Size::new(width, label_height + content_size.height + error_height),
+            vec![content.move_to(Point::new(0.0, label_height))],
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            let content = &mut self.content;
+            let tree = &mut tree.children[0];
+            let layout = layout.children().next().unwrap();
+
+            if let Some(id) = &self.id {
+                let mut is_claimed = false;
+
+                content.as_widget_mut().operate(
+                    tree,
+                    layout,
+                    renderer,
+                    &mut Claim {
+                        id,
+                        operation,
+                        is_claimed: &mut is_claimed,
+                    },
+                );
+            } else {
+                content
+                    .as_widget_mut()
+                    .operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class, self.status());
+
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
+
+        if self.label.is_some() {
+            renderer.fill_paragraph(state.label.raw(), bounds.position(), style.label, *viewport);
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            content_layout,
+            cursor,
+            viewport,
+        );
+
+        if style.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: content_bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        if self.error.is_some() {
+            renderer.fill_paragraph(
+                state.error.raw(),
+                Point::new(
+                    bounds.x,
+                    content_bounds.y + content_bounds.height + self.spacing,
+                ),
+                style.error,
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Control<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(control: Control<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(control)
+    }
+}
+
+/// An [`Operation`] that gives an [`widget::Id`] to the first focusable
+/// widget without one.
+struct Claim<'a, 'b> {
+    id: &'a widget::Id,
+    operation: &'b mut dyn Operation,
+    is_claimed: &'b mut bool,
+}
+
+impl Claim<'_, '_> {
+    fn id<'a>(&'a self, id: Option<&'a widget::Id>) -> Option<&'a widget::Id> {
+        if *self.is_claimed {
+            id
+        } else {
+            id.or(Some(self.id))
+        }
+    }
+}
+
+impl Operation for Claim<'_, '_> {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        let id = self.id;
+        let is_claimed = &mut *self.is_claimed;
+
+        self.operation.traverse(&mut |operation| {
+            operate(&mut Claim {
+                id,
+                operation,
+                is_claimed,
+            });
+        });
+    }
+
+    fn container(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+        self.operation.container(id, bounds);
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+        state: &mut dyn Scrollable,
+    ) {
+        self.operation
+            .scrollable(id, bounds, content_bounds, translation, state);
+    }
+
+    fn focusable(&mut self, id: Option<&widget::Id>, bounds: Rectangle, state: &mut dyn Focusable) {
+        let claimed = self.id(id).cloned();
+        *self.is_claimed = true;
+
+        self.operation.focusable(claimed.as_ref(), bounds, state);
+    }
+
+    fn focus_scope(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        scope: operation::focusable::Scope,
+        operate: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        let claim = self.id;
+        let is_claimed = &mut *self.is_claimed;
+
+        self.operation
+            .focus_scope(id, bounds, scope, &mut |operation| {
+                operate(&mut Claim {
+                    id: claim,
+                    operation,
+                    is_claimed,
+                });
+            });
+    }
+
+    fn text_input(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        state: &mut dyn operation::TextInput,
+    ) {
+        let claimed = self.id(id).cloned();
+
+        self.operation.text_input(claimed.as_ref(), bounds, state);
+    }
+
+    fn text(&mut self, id: Option<&widget::Id>, bounds: Rectangle, text: &str) {
+        self.operation.text(id, bounds, text);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        accessible: &Accessible<'_>,
+    ) {
+        self.operation.accessible(id, bounds, accessible);
+    }
+
+    fn custom(&mut self, id: Option<&widget::Id>, bounds: Rectangle, state: &mut dyn Any) {
+        self.operation.custom(id, bounds, state);
+    }
+}
+
+/// The possible status of a [`Control`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Control`] has no error.
+    Valid,
+    /// The [`Control`] has an error.
+    Invalid,
+}
+
+/// The appearance of a [`Control`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The text [`Color`] of the label.
+    pub label: Color,
+    /// The text [`Color`] of the error.
+    pub error: Color,
+    /// The [`Border`] drawn around the content.
+    pub border: Border,
+}
+
+/// The theme catalog of a [`Control`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Control`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Control`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let valid = Style {
+        label: palette.background.base.text,
+        error: palette.danger.base.color,
+        border: Border::default(),
+    };
+
+    match status {
+        Status::Valid => valid,
+        Status::Invalid => Style {
+            border: Border {
+                color: palette.danger.base.color,
+                width: 1.0,
+                radius: 2.0.into(),
+            },
+            ..valid
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields_display_errors_once_validated() {
+        let mut name = Field::new(String::new())
+            .validator(Validator::required("Required"))
+            .validator(Validator::custom(|name: &String| {
+                if name.chars().all(|c| c.is_ascii_lowercase()) {
+                    Ok(())
+                } else {
+                    Err(String::from("Lowercase only"))
+                }
+            }));
+
+        let mut age = Field::new(String::from("42")).validator(Validator::parse(
+            Validator::range(18..=120, "Out of range"),
+            "Not a number",
+        ));
+
+        assert!(!name.is_valid());
+        assert_eq!(name.error(), None);
+
+        assert_eq!(submit(&mut [&mut name, &mut age]), Err(name.id().clone()));
+        assert_eq!(name.error(), Some("Required"));
+        assert_eq!(age.error(), None);
+
+        name.update(String::from("Iced"));
+        assert_eq!(name.error(), Some("Lowercase only"));
+
+        name.update(String::from("iced"));
+        age.update(String::from("12"));
+        assert_eq!(name.error(), None);
+        assert!(is_valid(&[&name]));
+
+        assert_eq!(submit(&mut [&mut name, &mut age]), Err(age.id().clone()));
+        assert_eq!(age.error(), Some("Out of range"));
+
+        age.update(String::from("forty"));
+        assert_eq!(age.error(), Some("Not a number"));
+
+        age.update(String::from("40"));
+        assert_eq!(submit(&mut [&mut name, &mut age]), Ok(()));
+
+        age.reset(String::new());
+        assert_eq!(age.error(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn regex_validators_match_the_text() {
+        assert!(Validator::<String>::regex("(", "Invalid pattern").is_err());
+
+        let mut email = Field::new(String::new()).validator(
+            Validator::regex(r"^[^@\s]+@[^@\s]+$", "Invalid email").expect("Valid pattern"),
+        );
+
+        assert!(email.is_valid());
+
+        email.update(String::from("iced"));
+        assert_eq!(submit(&mut [&mut email]), Err(email.id().clone()));
+        assert_eq!(email.error(), Some("Invalid email"));
+
+        email.update(String::from("hello@iced.rs"));
+        assert_eq!(email.error(), None);
+        assert_eq!(submit(&mut [&mut email]), Ok(()));
+    }
+}
@@ -1,15 +1,21 @@
 //! Helper functions to create pure widgets.
 use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
+use crate::color_picker::{self, ColorPicker};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::ContextMenu;
 use crate::core;
 use crate::core::theme;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Element, Length, Size, Widget};
+use crate::date_picker::{self, DatePicker};
+use crate::drag_drop::{self, DragSource, DropTarget};
 use crate::float::{self, Float};
 use crate::keyed;
+use crate::menu_bar::{self, MenuBar};
+use crate::multi_pick_list::{self, MultiPickList};
 use crate::overlay;
 use crate::pane_grid::{self, PaneGrid};
 use crate::pick_list::{self, PickList};
@@ -17,13 +23,19 @@ use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::tabs::{self, Tabs};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::time_picker::{self, TimePicker};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
+use crate::tree_view::{self, TreeView};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, Grid, MouseArea, Pin, Responsive, Row, Sensor, Space, Stack, Themer};
+use crate::virtual_list::VirtualList;
+use crate::{
+    Column, FocusScope, Grid, MouseArea, Pin, Responsive, Row, Sensor, Space, Stack, Themer,
+};
 
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
@@ -515,6 +527,37 @@ where
     keyed::Column::with_children(children)
 }
 
+/// Creates a new [`VirtualList`] with the given amount of items and the
+/// function that builds the item at a given index.
+///
+/// Only the visible items of a [`VirtualList`] are built. Place it inside of
+/// a [`Scrollable`] to display very large collections.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{scrollable, text, virtual_list};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     scrollable(virtual_list(1_000_000, |i| text!("Row {i}").into())).into()
+/// }
+/// ```
+pub fn virtual_list<'a, Message, Theme, Renderer>(
+    count: usize,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> VirtualList<'a, usize, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    VirtualList::new(count, view)
+}
+
 /// Creates a new [`Row`] from an iterator.
 ///
 /// Rows distribute their children horizontally.
@@ -1056,6 +1099,139 @@ where
     Button::new(content)
 }
 
+/// Creates a new [`TreeView`] with the given root nodes.
+///
+/// Tree views display hierarchies of items that can be expanded and selected.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{text, tree_view};
+/// use iced::widget::tree_view::Node;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle(&'static str, bool),
+/// }
+///
+/// fn view(is_expanded: bool) -> Element<'static, Message> {
+///     tree_view([Node::new("src", text("src"))
+///         .expanded(is_expanded)
+///         .push(Node::new("main.rs", text("main.rs")))])
+///     .on_toggle(Message::Toggle)
+///     .into()
+/// }
+/// ```
+pub fn tree_view<'a, Key, Message, Theme, Renderer>(
+    roots: impl IntoIterator<Item = tree_view::Node<'a, Key, Message, Theme, Renderer>>,
+) -> TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Theme: tree_view::Catalog,
+    Renderer: core::Renderer,
+{
+    TreeView::new(roots)
+}
+
+/// Creates a new [`MenuBar`] with the given menus.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::menu_bar;
+/// use iced::widget::menu_bar::{Item, Menu};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Quit,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     menu_bar([Menu::new("File", [Item::new("Quit").on_press(Message::Quit)])]).into()
+/// }
+/// ```
+pub fn menu_bar<'a, Message, Theme, Renderer>(
+    menus: impl IntoIterator<Item = menu_bar::Menu<Message>>,
+) -> MenuBar<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: menu_bar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates a new [`ContextMenu`] that displays the given menu items when
+/// its content is right-clicked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::context_menu;
+/// use iced::widget::menu_bar::Item;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Delete,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     context_menu("Right-click me!", [Item::new("Delete").on_press(Message::Delete)]).into()
+/// }
+/// ```
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = menu_bar::Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: menu_bar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Creates a new [`Tabs`] widget with the key of the active tab and its
+/// content.
+///
+/// Tabs let users switch between different views that share the same space.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{tabs, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(u32),
+/// }
+///
+/// fn view(active: u32) -> Element<'static, Message> {
+///     tabs(active, text!("Tab {active}"))
+///         .push(0, "General")
+///         .push(1, "Advanced")
+///         .on_select(Message::Select)
+///         .into()
+/// }
+/// ```
+pub fn tabs<'a, Key, Message, Theme, Renderer>(
+    active: Key,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Tabs<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: tabs::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Tabs::new(active, content)
+}
+
 /// Creates a new [`Tooltip`] for the provided content with the given
 /// [`Element`] and [`tooltip::Position`].
 ///
@@ -1638,6 +1814,87 @@ where
     PickList::new(options, selected, on_selected)
 }
 
+/// Creates a new [`MultiPickList`].
+///
+/// Multi-select pick lists display a dropdown list of options that can be toggled,
+/// producing the full set of selected options on every change.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::multi_pick_list;
+///
+/// struct State {
+///    favorites: Vec<Fruit>,
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Fruit {
+///     Apple,
+///     Orange,
+///     Strawberry,
+///     Tomato,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     FruitsSelected(Vec<Fruit>),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     let fruits = [
+///         Fruit::Apple,
+///         Fruit::Orange,
+///         Fruit::Strawberry,
+///         Fruit::Tomato,
+///     ];
+///
+///     multi_pick_list(
+///         fruits,
+///         state.favorites.as_slice(),
+///         Message::FruitsSelected,
+///     )
+///     .placeholder("Select your favorite fruits...")
+///     .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::FruitsSelected(fruits) => {
+///             state.favorites = fruits;
+///         }
+///     }
+/// }
+///
+/// impl std::fmt::Display for Fruit {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str(match self {
+///             Self::Apple => "Apple",
+///             Self::Orange => "Orange",
+///             Self::Strawberry => "Strawberry",
+///             Self::Tomato => "Tomato",
+///         })
+///     }
+/// }
+/// ```
+pub fn multi_pick_list<'a, T, L, S, Message, Theme, Renderer>(
+    options: L,
+    selected: S,
+    on_change: impl Fn(Vec<T>) -> Message + 'a,
+) -> MultiPickList<'a, T, L, S, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    L: Borrow<[T]> + 'a,
+    S: Borrow<[T]> + 'a,
+    Message: Clone,
+    Theme: multi_pick_list::Catalog + overlay::menu::Catalog,
+    Renderer: core::text::Renderer,
+{
+    MultiPickList::new(options, selected, on_change)
+}
+
 /// Creates a new [`ComboBox`].
 ///
 /// Combo boxes display a dropdown list of searchable and selectable options.
@@ -1710,6 +1967,207 @@ where
     ComboBox::new(state, placeholder, selection, on_selected)
 }
 
+/// Creates a new [`ColorPicker`] with the current [`Color`] and the message
+/// to produce when it changes.
+///
+/// [`Color`]: core::Color
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::Color; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::Color;
+/// use iced::widget::color_picker;
+///
+/// struct State {
+///    accent: Color,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AccentChanged(Color),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     color_picker(state.accent, Message::AccentChanged).into()
+/// }
+/// ```
+pub fn color_picker<'a, Message, Theme, Renderer>(
+    color: core::Color,
+    on_change: impl Fn(core::Color) -> Message + 'a,
+) -> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: color_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    ColorPicker::new(color, on_change)
+}
+
+/// Creates a new [`DatePicker`] with the given placeholder, the current
+/// date, and the message to produce when a date is picked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::date_picker;
+/// use iced::widget::date_picker::Date;
+///
+/// struct State {
+///    deadline: Option<Date>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DeadlineSelected(Date),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     date_picker("Deadline", state.deadline, Message::DeadlineSelected).into()
+/// }
+/// ```
+pub fn date_picker<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: Option<date_picker::Date>,
+    on_select: impl Fn(date_picker::Date) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::new(placeholder, value, on_select)
+}
+
+/// Creates a new [`DatePicker`] that picks a range of dates, with the given
+/// placeholder, the current range, and the message to produce when a range
+/// is picked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{date_picker, date_range_picker};
+///
+/// struct State {
+///    vacation: Option<date_picker::Range>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     VacationSelected(date_picker::Range),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     date_range_picker("Vacation", state.vacation, Message::VacationSelected).into()
+/// }
+/// ```
+pub fn date_range_picker<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: Option<date_picker::Range>,
+    on_select: impl Fn(date_picker::Range) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::range(placeholder, value, on_select)
+}
+
+/// Creates a new [`DragSource`] with the given payload and content.
+///
+/// Dragging the [`DragSource`] onto a [`DropTarget`] with the same payload
+/// type will drop its payload there.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::drag_source;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     drag_source(42, "Drag me!").into()
+/// }
+/// ```
+pub fn drag_source<'a, P, Message, Theme, Renderer>(
+    payload: P,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DragSource<'a, P, Message, Theme, Renderer>
+where
+    Theme: drag_drop::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    DragSource::new(payload, content)
+}
+
+/// Creates a new [`DropTarget`] with the given content.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::drop_target;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     drop_target("Drop here!").on_drop(Message::Dropped).into()
+/// }
+/// ```
+pub fn drop_target<'a, P, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropTarget<'a, P, Message, Theme, Renderer>
+where
+    Theme: drag_drop::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    DropTarget::new(content)
+}
+
+/// Creates a new [`TimePicker`] with the given placeholder, the current
+/// time, and the message to produce when a time is picked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::time_picker;
+/// use iced::widget::time_picker::Time;
+///
+/// struct State {
+///    reminder: Option<Time>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ReminderSelected(Time),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     time_picker("Reminder", state.reminder, Message::ReminderSelected).into()
+/// }
+/// ```
+pub fn time_picker<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: Option<time_picker::Time>,
+    on_select: impl Fn(time_picker::Time) -> Message + 'a,
+) -> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    TimePicker::new(placeholder, value, on_select)
+}
+
 /// Creates some empty [`Space`] with no size.
 ///
 /// This is considered the "identity" widget. It will take
@@ -1965,6 +2423,19 @@ where
     crate::Shader::new(program)
 }
 
+/// Creates a new [`FocusScope`] with the given content.
+///
+/// A [`FocusScope`] changes how the focus moves among the widgets it contains;
+/// like setting their tab index or trapping the focus inside of it.
+pub fn focus_scope<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> FocusScope<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    FocusScope::new(content)
+}
+
 /// Creates a new [`MouseArea`].
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -331,6 +331,7 @@ pub fn draw<Renderer, Handle>(
             rotation: rotation.radians(),
             opacity,
             snap: true,
+            repeat: None,
         },
         drawing_bounds,
         bounds,
@@ -336,6 +336,7 @@ where
                         rotation: Radians(0.0),
                         opacity: 1.0,
                         snap: true,
+                        repeat: None,
                     },
                     drawing_bounds,
                     *viewport - translation,
@@ -333,6 +333,10 @@ where
         shell.request_redraw_at(local_shell.redraw_request());
         shell.request_input_method(local_shell.input_method());
 
+        for operation in local_shell.take_operations() {
+            shell.request_operation(operation);
+        }
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -600,6 +604,10 @@ where
         shell.request_redraw_at(local_shell.redraw_request());
         shell.request_input_method(local_shell.input_method());
 
+        for operation in local_shell.take_operations() {
+            shell.request_operation(operation);
+        }
+
         if !local_messages.is_empty() {
             let mut inner = self.overlay.take().unwrap().0.take().unwrap().into_heads();
             let mut heads = inner.instance.state.take().unwrap().into_heads();
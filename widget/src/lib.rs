@@ -11,6 +11,7 @@ pub use core::widget::Id;
 
 mod action;
 mod column;
+mod focus_scope;
 mod mouse_area;
 mod pin;
 mod responsive;
@@ -19,11 +20,18 @@ mod themer;
 
 pub mod button;
 pub mod checkbox;
+pub mod color_picker;
 pub mod combo_box;
 pub mod container;
+pub mod context_menu;
+pub mod date_picker;
+pub mod drag_drop;
 pub mod float;
+pub mod form;
 pub mod grid;
 pub mod keyed;
+pub mod menu_bar;
+pub mod multi_pick_list;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
@@ -36,12 +44,16 @@ pub mod sensor;
 pub mod slider;
 pub mod space;
 pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggler;
 pub mod tooltip;
+pub mod tree_view;
 pub mod vertical_slider;
+pub mod virtual_list;
 
 mod helpers;
 
@@ -58,18 +70,32 @@ pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use color_picker::ColorPicker;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use drag_drop::{DragSource, DropTarget};
+#[doc(no_inline)]
 pub use float::Float;
 #[doc(no_inline)]
+pub use focus_scope::FocusScope;
+#[doc(no_inline)]
 pub use grid::Grid;
 #[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use multi_pick_list::MultiPickList;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -96,6 +122,8 @@ pub use space::Space;
 #[doc(no_inline)]
 pub use stack::Stack;
 #[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -104,11 +132,17 @@ pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use themer::Themer;
 #[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
 
 #[cfg(feature = "wgpu")]
 pub mod shader;
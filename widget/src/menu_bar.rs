@@ -0,0 +1,1468 @@
+//! Menu bars display the menus of an application.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::menu_bar;
+//! use iced::widget::menu_bar::{Item, Menu};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     New,
+//!     Open,
+//!     ToggleWrap,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     menu_bar([
+//!         Menu::new(
+//!             "File",
+//!             [
+//!                 Item::new("New").on_press(Message::New).shortcut("Ctrl+N"),
+//!                 Item::new("Open...").on_press(Message::Open).shortcut("Ctrl+O"),
+//!                 Item::separator(),
+//!                 Item::submenu("Recent", [Item::new("No recent files")]),
+//!             ],
+//!         ),
+//!         Menu::new(
+//!             "View",
+//!             [Item::new("Word Wrap").checked(true).on_press(Message::ToggleWrap)],
+//!         ),
+//!     ])
+//!     .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget, widget,
+};
+
+/// A horizontal bar of menus with nested submenus.
+///
+/// Menus are opened by clicking their title. Once a menu is open, hovering
+/// another title opens its menu instead.
+///
+/// When focused, the left and right arrows move between menus; and the down
+/// arrow, `Enter` or `Space` open the current one. The items of an open menu
+/// can be navigated with the arrow keys, activated with `Enter` or `Space`,
+/// and closed with `Escape`.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::menu_bar;
+/// use iced::widget::menu_bar::{Item, Menu};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     New,
+///     Open,
+///     ToggleWrap,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     menu_bar([
+///         Menu::new(
+///             "File",
+///             [
+///                 Item::new("New").on_press(Message::New).shortcut("Ctrl+N"),
+///                 Item::new("Open...").on_press(Message::Open).shortcut("Ctrl+O"),
+///                 Item::separator(),
+///                 Item::submenu("Recent", [Item::new("No recent files")]),
+///             ],
+///         ),
+///         Menu::new(
+///             "View",
+///             [Item::new("Word Wrap").checked(true).on_press(Message::ToggleWrap)],
+///         ),
+///     ])
+///     .into()
+/// }
+/// ```
+pub struct MenuBar<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+    hovered: Option<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> MenuBar<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the titles of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        bottom: 4.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(menus: impl IntoIterator<Item = Menu<Message>>) -> Self {
+        Self {
+            id: None,
+            menus: menus.into_iter().collect(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+            hovered: None,
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`MenuBar`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`] and its menus.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`] and its menus.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MenuBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn open(&self, state: &mut State, index: usize, shell: &mut Shell<'_, Message>) {
+        state.active = Some(index);
+        state.is_open = true;
+        state.path.clear();
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+}
+
+/// A menu of a [`MenuBar`].
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    title: String,
+    items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates a new [`Menu`] with the given title and items.
+    pub fn new(title: impl Into<String>, items: impl IntoIterator<Item = Item<Message>>) -> Self {
+        Self {
+            title: title.into(),
+            items: items.into_iter().collect(),
+        }
+    }
+}
+
+/// An item of a menu.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action {
+        label: String,
+        on_press: Option<Message>,
+        shortcut: Option<String>,
+        is_checked: Option<bool>,
+    },
+    Submenu {
+        label: String,
+        items: Vec<Item<Message>>,
+    },
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item`] with the given label.
+    ///
+    /// The [`Item`] will be disabled until [`on_press`] is set.
+    ///
+    /// [`on_press`]: Self::on_press
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            kind: Kind::Action {
+                label: label.into(),
+                on_press: None,
+                shortcut: None,
+                is_checked: None,
+            },
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    pub fn submenu(
+        label: impl Into<String>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            kind: Kind::Submenu {
+                label: label.into(),
+                items: items.into_iter().collect(),
+            },
+        }
+    }
+
+    /// Creates a new [`Item`] that separates groups of items.
+    pub fn separator() -> Self {
+        Self {
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is activated.
+    ///
+    /// It has no effect on submenus and separators.
+    pub fn on_press(self, on_press: Message) -> Self {
+        self.on_press_maybe(Some(on_press))
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is activated,
+    /// if `Some`.
+    ///
+    /// It has no effect on submenus and separators.
+    pub fn on_press_maybe(mut self, on_press: Option<Message>) -> Self {
+        if let Kind::Action {
+            on_press: current, ..
+        } = &mut self.kind
+        {
+            *current = on_press;
+        }
+
+        self
+    }
+
+    /// Sets the keyboard shortcut displayed next to the [`Item`].
+    ///
+    /// The shortcut is only displayed; it is up to your application to
+    /// listen to the actual keyboard events.
+    ///
+    /// It has no effect on submenus and separators.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        if let Kind::Action {
+            shortcut: current, ..
+        } = &mut self.kind
+        {
+            *current = Some(shortcut.into());
+        }
+
+        self
+    }
+
+    /// Turns the [`Item`] into a checkable item with the given state.
+    ///
+    /// It has no effect on submenus and separators.
+    pub fn checked(mut self, is_checked: bool) -> Self {
+        if let Kind::Action {
+            is_checked: current,
+            ..
+        } = &mut self.kind
+        {
+            *current = Some(is_checked);
+        }
+
+        self
+    }
+
+    fn label(&self) -> Option<&str> {
+        match &self.kind {
+            Kind::Action { label, .. } | Kind::Submenu { label, .. } => Some(label),
+            Kind::Separator => None,
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Action { on_press, .. } => on_press.is_some(),
+            Kind::Submenu { items, .. } => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+
+    fn submenu_items(&self) -> Option<&[Item<Message>]> {
+        match &self.kind {
+            Kind::Submenu { items, .. } if !items.is_empty() => Some(items),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    active: Option<usize>,
+    is_open: bool,
+    is_focused: bool,
+    path: Vec<usize>,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let height =
+            f32::from(text::LineHeight::default().to_absolute(text_size)) + self.padding.y();
+
+        let mut x = 0.0;
+
+        let titles: Vec<_> = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = measure(renderer, &menu.title, text_size, font) + self.padding.x();
+                let node = layout::Node::new(Size::new(width, height)).move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size =
+            limits
+                .width(self.width)
+                .resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.accessible(
+            self.id.as_ref(),
+            layout.bounds(),
+            &Accessible::new(Role::MenuBar).focused(state.is_focused),
+        );
+
+        if self.menus.is_empty() {
+            state.is_focused = false;
+        } else {
+            operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        }
+
+        operation.traverse(&mut |operation| {
+            for (index, (menu, layout)) in self.menus.iter().zip(layout.children()).enumerate() {
+                operation.accessible(
+                    None,
+                    layout.bounds(),
+                    &Accessible::new(Role::MenuItem)
+                        .label(menu.title.as_str())
+                        .expanded(state.is_open && state.active == Some(index)),
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let hovered = layout
+            .children()
+            .position(|layout| cursor.is_over(layout.bounds()));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
+                if let Some(index) = hovered {
+                    if state.is_open && state.active == Some(index) {
+                        state.is_open = false;
+                        state.path.clear();
+
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                    } else {
+                        self.open(state, index, shell);
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if state.is_open
+                    && let Some(index) = hovered
+                    && state.active != Some(index)
+                {
+                    self.open(state, index, shell);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if state.is_focused && !state.is_open && !self.menus.is_empty() => {
+                let count = self.menus.len();
+                let active = state.active.unwrap_or(0).min(count - 1);
+
+                match named {
+                    key::Named::ArrowLeft => {
+                        state.active = Some((active + count - 1) % count);
+                    }
+                    key::Named::ArrowRight => {
+                        state.active = Some((active + 1) % count);
+                    }
+                    key::Named::ArrowDown | key::Named::Enter | key::Named::Space => {
+                        self.open(state, active, shell);
+
+                        if let Some(first) = first(&self.menus[active].items) {
+                            state.path.push(first);
+                        }
+                    }
+                    _ => return,
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            _ => {}
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.hovered = hovered;
+        } else if self.hovered != hovered {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|layout| cursor.is_over(layout.bounds()))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        if let Some(background) = style.background.clone() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        for (index, (menu, layout)) in self.menus.iter().zip(layout.children()).enumerate() {
+            let bounds = layout.bounds();
+            let is_active = state.active.unwrap_or(0) == index;
+
+            let (background, text_color) = if state.is_open && is_active {
+                (
+                    Some(style.selected_background.clone()),
+                    style.selected_text_color,
+                )
+            } else if cursor.is_over(bounds) || (state.is_focused && is_active) {
+                (Some(style.hovered_background.clone()), style.text_color)
+            } else {
+                (None, style.text_color)
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: border::rounded(style.menu_border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: menu.title.clone(),
+                    bounds: bounds.size(),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                    hint_factor: renderer.scale_factor(),
+                },
+                bounds.center(),
+                text_color,
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let index = state.active?;
+        let menu = self.menus.get(index)?;
+        let title = layout.children().nth(index)?.bounds();
+
+        if menu.items.is_empty() {
+            return None;
+        }
+
+        Some(
+            Dropdown {
+                items: &menu.items,
+                path: &mut state.path,
+                is_open: &mut state.is_open,
+                bar: Some(Bar {
+                    active: &mut state.active,
+                    count: self.menus.len(),
+                    bounds: layout.bounds() + translation,
+                }),
+                position: title.position() + translation,
+                target_height: title.height,
+                text_size: self.text_size,
+                font: self.font,
+                class: &self.class,
+            }
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(menu_bar)
+    }
+}
+
+const MENU_PADDING: f32 = 4.0;
+const MENU_MIN_WIDTH: f32 = 120.0;
+const SEPARATOR_HEIGHT: f32 = 9.0;
+const SHORTCUT_SPACING: f32 = 24.0;
+const SUBMENU_ARROW: &str = "›";
+
+const ITEM_PADDING: Padding = Padding {
+    top: 4.0,
+    bottom: 4.0,
+    right: 10.0,
+    left: 10.0,
+};
+
+fn measure<Renderer>(renderer: &Renderer, content: &str, size: Pixels, font: Renderer::Font) -> f32
+where
+    Renderer: text::Renderer,
+{
+    let _ = renderer;
+
+    Renderer::Paragraph::with_text(Text {
+        content,
+        bounds: Size::INFINITE,
+        size,
+        line_height: text::LineHeight::default(),
+        font,
+        align_x: text::Alignment::Default,
+        align_y: alignment::Vertical::Top,
+        shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::None,
+        hint_factor: None,
+    })
+    .min_width()
+}
+
+/// Returns the index of the first item that can be highlighted.
+pub(crate) fn first<Message>(items: &[Item<Message>]) -> Option<usize> {
+    next(items, None, true)
+}
+
+/// Returns the index of the next enabled item after the given one; or the
+/// previous one, if not `forward`.
+fn next<Message>(items: &[Item<Message>], current: Option<usize>, forward: bool) -> Option<usize> {
+    let count = items.len();
+
+    (1..=count)
+        .map(|step| match current {
+            Some(current) if forward => (current + step) % count,
+            Some(current) => (current + count - step % count) % count,
+            None if forward => step - 1,
+            None => count - step,
+        })
+        .find(|index| !matches!(items[*index].kind, Kind::Separator))
+}
+
+/// The menu bar a [`Dropdown`] belongs to, if any.
+pub(crate) struct Bar<'a> {
+    pub active: &'a mut Option<usize>,
+    pub count: usize,
+    pub bounds: Rectangle,
+}
+
+/// An overlay that displays a menu and its open submenus.
+pub(crate) struct Dropdown<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub items: &'a [Item<Message>],
+    pub path: &'a mut Vec<usize>,
+    pub is_open: &'a mut bool,
+    pub bar: Option<Bar<'a>>,
+    pub position: Point,
+    pub target_height: f32,
+    pub text_size: Option<Pixels>,
+    pub font: Option<Renderer::Font>,
+    pub class: &'a Theme::Class<'b>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Dropdown<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    /// Turns the [`Dropdown`] into an overlay [`Element`].
+    ///
+    /// [`Element`]: overlay::Element
+    pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(self))
+    }
+
+    /// Returns the items of every open level of the [`Dropdown`].
+    fn levels(&self) -> Vec<&'a [Item<Message>]> {
+        let mut levels = vec![self.items];
+
+        for index in self.path.iter() {
+            let Some(items) = levels
+                .last()
+                .and_then(|items| items.get(*index))
+                .and_then(Item::submenu_items)
+            else {
+                break;
+            };
+
+            levels.push(items);
+        }
+
+        levels
+    }
+
+    fn close(&mut self, shell: &mut Shell<'_, Message>) {
+        *self.is_open = false;
+        self.path.clear();
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    fn switch(&mut self, forward: bool, shell: &mut Shell<'_, Message>) -> bool {
+        let Some(bar) = &mut self.bar else {
+            return false;
+        };
+
+        let Some(active) = *bar.active else {
+            return false;
+        };
+
+        *bar.active = Some(if forward {
+            (active + 1) % bar.count
+        } else {
+            (active + bar.count - 1) % bar.count
+        });
+
+        self.path.clear();
+        self.path.push(0);
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+
+        true
+    }
+
+    fn activate(&mut self, levels: &[&'a [Item<Message>]], shell: &mut Shell<'_, Message>) {
+        let depth = self.path.len() - 1;
+
+        let Some(item) = levels
+            .get(depth)
+            .and_then(|items| items.get(self.path[depth]))
+        else {
+            return;
+        };
+
+        match &item.kind {
+            Kind::Action {
+                on_press: Some(on_press),
+                ..
+            } => {
+                shell.publish(on_press.clone());
+                self.close(shell);
+            }
+            Kind::Submenu { items, .. } => {
+                if let Some(first) = first(items) {
+                    self.path.push(first);
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn layout_level(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<Message>],
+        text_size: Pixels,
+        font: Renderer::Font,
+    ) -> layout::Node {
+        let line_height = f32::from(text::LineHeight::default().to_absolute(text_size));
+
+        let mut label_width: f32 = 0.0;
+        let mut shortcut_width: f32 = 0.0;
+        let mut has_submenus = false;
+
+        for item in items {
+            if let Some(label) = item.label() {
+                label_width = label_width.max(measure(renderer, label, text_size, font));
+            }
+
+            match &item.kind {
+                Kind::Action {
+                    shortcut: Some(shortcut),
+                    ..
+                } => {
+                    shortcut_width =
+                        shortcut_width.max(measure(renderer, shortcut, text_size, font));
+                }
+                Kind::Submenu { .. } => {
+                    has_submenus = true;
+                }
+                _ => {}
+            }
+        }
+
+        let width = (ITEM_PADDING.x()
+            + check_width(items, text_size)
+            + label_width
+            + if shortcut_width > 0.0 {
+                SHORTCUT_SPACING + shortcut_width
+            } else {
+                0.0
+            }
+            + if has_submenus {
+                SHORTCUT_SPACING / 2.0 + text_size.0
+            } else {
+                0.0
+            })
+        .max(MENU_MIN_WIDTH);
+
+        let mut y = MENU_PADDING;
+
+        let children = items
+            .iter()
+            .map(|item| {
+                let height = match item.kind {
+                    Kind::Separator => SEPARATOR_HEIGHT,
+                    _ => line_height + ITEM_PADDING.y(),
+                };
+
+                let node = layout::Node::new(Size::new(width, height)).move_to(Point::new(0.0, y));
+
+                y += height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(width, y + MENU_PADDING), children)
+    }
+
+    /// Returns the level and the index of the item under the cursor, if any.
+    fn hovered(layout: Layout<'_>, cursor: mouse::Cursor) -> Option<(usize, Option<usize>)> {
+        layout
+            .children()
+            .enumerate()
+            .rev()
+            .find(|(_, level)| cursor.is_over(level.bounds()))
+            .map(|(depth, level)| {
+                (
+                    depth,
+                    level
+                        .children()
+                        .position(|item| cursor.is_over(item.bounds())),
+                )
+            })
+    }
+}
+
+fn check_width<Message>(items: &[Item<Message>], text_size: Pixels) -> f32 {
+    let has_checks = items.iter().any(|item| {
+        matches!(
+            item.kind,
+            Kind::Action {
+                is_checked: Some(_),
+                ..
+            }
+        )
+    });
+
+    if has_checks { text_size.0 + 6.0 } else { 0.0 }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Dropdown<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let mut levels: Vec<layout::Node> = Vec::new();
+
+        for (depth, items) in self.levels().into_iter().enumerate() {
+            let level = self.layout_level(renderer, items, text_size, font);
+            let size = level.size();
+
+            let position = if let Some(parent) = depth.checked_sub(1).map(|depth| &levels[depth]) {
+                let parent_bounds = parent.bounds();
+                let item = parent.children()[self.path[depth - 1]].bounds();
+
+                let right = parent_bounds.x + parent_bounds.width;
+
+                Point::new(
+                    if right + size.width <= bounds.width {
+                        right
+                    } else {
+                        (parent_bounds.x - size.width).max(0.0)
+                    },
+                    (parent_bounds.y + item.y - MENU_PADDING)
+                        .min(bounds.height - size.height)
+                        .max(0.0),
+                )
+            } else {
+                let space_below = bounds.height - (self.position.y + self.target_height);
+
+                Point::new(
+                    self.position.x.min(bounds.width - size.width).max(0.0),
+                    if space_below >= size.height || space_below >= self.position.y {
+                        self.position.y + self.target_height
+                    } else {
+                        (self.position.y - size.height).max(0.0)
+                    },
+                )
+            };
+
+            levels.push(level.move_to(position));
+        }
+
+        layout::Node::with_children(bounds, levels)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let levels = self.levels();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((depth, Some(index))) = Self::hovered(layout, cursor)
+                    && (self.path.get(depth) != Some(&index) || self.path.len() > depth + 1)
+                {
+                    self.path.truncate(depth);
+                    self.path.push(index);
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match Self::hovered(layout, cursor) {
+                    Some((depth, Some(index))) => {
+                        self.path.truncate(depth);
+                        self.path.push(index);
+
+                        if matches!(
+                            event,
+                            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                                | Event::Touch(_)
+                        ) {
+                            self.activate(&levels, shell);
+                        }
+
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                    Some((_, None)) => {
+                        shell.capture_event();
+                    }
+                    None => {
+                        let is_over_bar = self
+                            .bar
+                            .as_ref()
+                            .is_some_and(|bar| cursor.is_over(bar.bounds));
+
+                        if !is_over_bar {
+                            self.close(shell);
+                        }
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) => {
+                let depth = self.path.len().saturating_sub(1);
+                let current = self.path.get(depth).copied();
+                let items = levels.get(depth).copied().unwrap_or(self.items);
+
+                match named {
+                    key::Named::ArrowDown | key::Named::ArrowUp => {
+                        if let Some(index) = next(items, current, *named == key::Named::ArrowDown) {
+                            self.path.truncate(depth);
+                            self.path.push(index);
+                        }
+                    }
+                    key::Named::ArrowRight => {
+                        let is_submenu = current
+                            .and_then(|index| items.get(index))
+                            .and_then(Item::submenu_items)
+                            .is_some();
+
+                        if is_submenu {
+                            self.activate(&levels, shell);
+                        } else {
+                            let _ = self.switch(true, shell);
+                        }
+                    }
+                    key::Named::ArrowLeft => {
+                        if self.path.len() > 1 {
+                            let _ = self.path.pop();
+                        } else {
+                            let _ = self.switch(false, shell);
+                        }
+                    }
+                    key::Named::Enter | key::Named::Space => {
+                        if current.is_some() {
+                            self.activate(&levels, shell);
+                        }
+                    }
+                    key::Named::Escape => {
+                        if self.path.len() > 1 {
+                            let _ = self.path.pop();
+                        } else {
+                            self.close(shell);
+                        }
+                    }
+                    _ => return,
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Window(window::Event::Unfocused) => {
+                self.close(shell);
+            }
+            _ => {}
+        }
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, _renderer: &Renderer, operation: &mut dyn Operation) {
+        let levels = self.levels();
+
+        for (depth, (items, level)) in levels.into_iter().zip(layout.children()).enumerate() {
+            operation.accessible(None, level.bounds(), &Accessible::new(Role::Menu));
+
+            operation.traverse(&mut |operation| {
+                for (index, (item, layout)) in items.iter().zip(level.children()).enumerate() {
+                    let Some(label) = item.label() else {
+                        continue;
+                    };
+
+                    let mut accessible = Accessible::new(Role::MenuItem)
+                        .label(label)
+                        .disabled(!item.is_enabled());
+
+                    match &item.kind {
+                        Kind::Action {
+                            is_checked: Some(is_checked),
+                            ..
+                        } => {
+                            accessible = accessible.checked(*is_checked);
+                        }
+                        Kind::Submenu { .. } => {
+                            accessible = accessible.expanded(self.path.get(depth) == Some(&index));
+                        }
+                        _ => {}
+                    }
+
+                    operation.accessible(None, layout.bounds(), &accessible);
+                }
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let levels = self.levels();
+
+        match Self::hovered(layout, cursor) {
+            Some((depth, Some(index))) if levels[depth][index].is_enabled() => {
+                mouse::Interaction::Pointer
+            }
+            Some(_) => mouse::Interaction::Idle,
+            None => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let hint_factor = renderer.scale_factor();
+
+        for (depth, (items, level)) in self.levels().into_iter().zip(layout.children()).enumerate()
+        {
+            let bounds = level.bounds();
+            let check_width = check_width(items, text_size);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.menu_border,
+                    shadow: style.menu_shadow,
+                    ..renderer::Quad::default()
+                },
+                style.menu_background.clone(),
+            );
+
+            for (index, (item, layout)) in items.iter().zip(level.children()).enumerate() {
+                let bounds = layout.bounds();
+
+                let label = match &item.kind {
+                    Kind::Separator => {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + ITEM_PADDING.left,
+                                    y: bounds.center_y().floor(),
+                                    width: bounds.width - ITEM_PADDING.x(),
+                                    height: 1.0,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.separator_color,
+                        );
+
+                        continue;
+                    }
+                    Kind::Action { label, .. } | Kind::Submenu { label, .. } => label,
+                };
+
+                let is_highlighted = self.path.get(depth) == Some(&index);
+
+                let (text_color, secondary_color) = if !item.is_enabled() {
+                    (style.disabled_text_color, style.disabled_text_color)
+                } else if is_highlighted {
+                    (style.selected_text_color, style.selected_text_color)
+                } else {
+                    (style.text_color, style.secondary_text_color)
+                };
+
+                if is_highlighted {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: bounds
+                                .shrink(Padding::ZERO.left(MENU_PADDING).right(MENU_PADDING)),
+                            border: border::rounded(style.menu_border.radius),
+                            ..renderer::Quad::default()
+                        },
+                        style.selected_background.clone(),
+                    );
+                }
+
+                let text = |content: String, font, align_x| Text {
+                    content,
+                    bounds: Size::new(bounds.width, bounds.height),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    align_x,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                    hint_factor,
+                };
+
+                if let Kind::Action {
+                    is_checked: Some(true),
+                    ..
+                } = &item.kind
+                {
+                    renderer.fill_text(
+                        text(
+                            Renderer::CHECKMARK_ICON.to_string(),
+                            Renderer::ICON_FONT,
+                            text::Alignment::Default,
+                        ),
+                        Point::new(bounds.x + ITEM_PADDING.left, bounds.center_y()),
+                        text_color,
+                        bounds,
+                    );
+                }
+
+                renderer.fill_text(
+                    text(label.clone(), font, text::Alignment::Default),
+                    Point::new(
+                        bounds.x + ITEM_PADDING.left + check_width,
+                        bounds.center_y(),
+                    ),
+                    text_color,
+                    bounds,
+                );
+
+                let right = bounds.x + bounds.width - ITEM_PADDING.right;
+
+                match &item.kind {
+                    Kind::Action {
+                        shortcut: Some(shortcut),
+                        ..
+                    } => {
+                        renderer.fill_text(
+                            text(shortcut.clone(), font, text::Alignment::Right),
+                            Point::new(right, bounds.center_y()),
+                            secondary_color,
+                            bounds,
+                        );
+                    }
+                    Kind::Submenu { .. } => {
+                        renderer.fill_text(
+                            text(SUBMENU_ARROW.to_owned(), font, text::Alignment::Right),
+                            Point::new(right, bounds.center_y()),
+                            text_color,
+                            bounds,
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// The appearance of a [`MenuBar`] and its menus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the bar.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the titles and the items.
+    pub text_color: Color,
+    /// The [`Background`] of a hovered title.
+    pub hovered_background: Background,
+    /// The [`Background`] of an open title and a selected item.
+    pub selected_background: Background,
+    /// The text [`Color`] of an open title and a selected item.
+    pub selected_text_color: Color,
+    /// The text [`Color`] of a disabled item.
+    pub disabled_text_color: Color,
+    /// The text [`Color`] of the keyboard shortcuts of the items.
+    pub secondary_text_color: Color,
+    /// The [`Color`] of the separators.
+    pub separator_color: Color,
+    /// The [`Background`] of the menus.
+    pub menu_background: Background,
+    /// The [`Border`] of the menus.
+    pub menu_border: Border,
+    /// The [`Shadow`] of the menus.
+    pub menu_shadow: Shadow,
+}
+
+/// The theme catalog of a [`MenuBar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`MenuBar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`MenuBar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.weak.color.into()),
+        text_color: palette.background.weak.text,
+        hovered_background: palette.background.strong.color.into(),
+        selected_background: palette.primary.strong.color.into(),
+        selected_text_color: palette.primary.strong.text,
+        disabled_text_color: palette.background.weak.text.scale_alpha(0.5),
+        secondary_text_color: palette.background.weak.text.scale_alpha(0.7),
+        separator_color: palette.background.strong.color,
+        menu_background: palette.background.weak.color.into(),
+        menu_border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        menu_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+            ..Shadow::default()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrow_keys_skip_separators_and_wrap_around() {
+        let items: [Item<()>; 4] = [
+            Item::new("Open"),
+            Item::separator(),
+            Item::new("Save"),
+            Item::separator(),
+        ];
+
+        assert_eq!(next(&items, None, true), Some(0));
+        assert_eq!(next(&items, Some(0), true), Some(2));
+        assert_eq!(next(&items, Some(2), true), Some(0));
+
+        assert_eq!(next(&items, None, false), Some(2));
+        assert_eq!(next(&items, Some(0), false), Some(2));
+
+        assert_eq!(next::<()>(&[Item::separator()], None, true), None);
+    }
+
+    #[test]
+    fn submenus_are_hovered_over_their_parents() {
+        let level = |x: f32, items: usize| {
+            layout::Node::with_children(
+                Size::new(100.0, items as f32 * 20.0),
+                (0..items)
+                    .map(|index| {
+                        layout::Node::new(Size::new(100.0, 20.0))
+                            .move_to(Point::new(0.0, index as f32 * 20.0))
+                    })
+                    .collect(),
+            )
+            .move_to(Point::new(x, 0.0))
+        };
+
+        // A submenu overlapping the right edge of its parent
+        let levels = layout::Node::with_children(
+            Size::new(800.0, 600.0),
+            vec![level(0.0, 3), level(90.0, 2)],
+        );
+
+        let hovered = |x, y| {
+            Dropdown::<(), Theme, ()>::hovered(
+                Layout::new(&levels),
+                mouse::Cursor::Available(Point::new(x, y)),
+            )
+        };
+
+        assert_eq!(hovered(50.0, 30.0), Some((0, Some(1))));
+        assert_eq!(hovered(95.0, 10.0), Some((1, Some(0))));
+        assert_eq!(hovered(95.0, 50.0), Some((0, Some(2))));
+        assert_eq!(hovered(500.0, 10.0), None);
+    }
+}
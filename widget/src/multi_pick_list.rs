@@ -0,0 +1,1038 @@
+//! Multi-select pick lists display a dropdown list of options that can be toggled.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::multi_pick_list;
+//!
+//! struct State {
+//!    favorites: Vec<Fruit>,
+//! }
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum Fruit {
+//!     Apple,
+//!     Orange,
+//!     Strawberry,
+//!     Tomato,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     FruitsSelected(Vec<Fruit>),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let fruits = [
+//!         Fruit::Apple,
+//!         Fruit::Orange,
+//!         Fruit::Strawberry,
+//!         Fruit::Tomato,
+//!     ];
+//!
+//!     multi_pick_list(
+//!         fruits,
+//!         state.favorites.as_slice(),
+//!         Message::FruitsSelected,
+//!     )
+//!     .placeholder("Select your favorite fruits...")
+//!     .select_all("All fruits")
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::FruitsSelected(fruits) => {
+//!             state.favorites = fruits;
+//!         }
+//!     }
+//! }
+//!
+//! impl std::fmt::Display for Fruit {
+//!     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+//!         f.write_str(match self {
+//!             Self::Apple => "Apple",
+//!             Self::Orange => "Orange",
+//!             Self::Strawberry => "Strawberry",
+//!             Self::Tomato => "Tomato",
+//!         })
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::paragraph;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::Operation;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::overlay::menu::{self, Menu};
+use crate::pick_list;
+
+use std::borrow::Borrow;
+use std::f32;
+use std::fmt;
+
+pub use crate::pick_list::{Handle, Icon, Status};
+
+/// A widget for selecting any number of values from a list of options.
+///
+/// The menu of a [`MultiPickList`] stays open while options are toggled,
+/// and every change produces the full set of selected options, in the
+/// order of the list of options.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::multi_pick_list;
+///
+/// struct State {
+///    toppings: Vec<Topping>,
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Topping {
+///     Cheese,
+///     Mushrooms,
+///     Olives,
+///     Pineapple,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ToppingsChanged(Vec<Topping>),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     let toppings = [
+///         Topping::Cheese,
+///         Topping::Mushrooms,
+///         Topping::Olives,
+///         Topping::Pineapple,
+///     ];
+///
+///     multi_pick_list(
+///         toppings,
+///         state.toppings.as_slice(),
+///         Message::ToppingsChanged,
+///     )
+///     .placeholder("No toppings")
+///     .chips()
+///     .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::ToppingsChanged(toppings) => {
+///             state.toppings = toppings;
+///         }
+///     }
+/// }
+///
+/// impl std::fmt::Display for Topping {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str(match self {
+///             Self::Cheese => "Cheese",
+///             Self::Mushrooms => "Mushrooms",
+///             Self::Olives => "Olives",
+///             Self::Pineapple => "Pineapple",
+///         })
+///     }
+/// }
+/// ```
+pub struct MultiPickList<'a, T, L, S, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    L: Borrow<[T]> + 'a,
+    S: Borrow<[T]> + 'a,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    on_change: Box<dyn Fn(Vec<T>) -> Message + 'a>,
+    on_open: Option<Message>,
+    on_close: Option<Message>,
+    options: L,
+    selected: S,
+    placeholder: Option<String>,
+    summary: Option<Box<dyn Fn(&[T]) -> String + 'a>>,
+    select_all: Option<String>,
+    chips: bool,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    handle: Handle<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+    last_status: Option<Status>,
+    menu_height: Length,
+    entries: Vec<Entry<T>>,
+}
+
+impl<'a, T, L, S, Message, Theme, Renderer> MultiPickList<'a, T, L, S, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    L: Borrow<[T]> + 'a,
+    S: Borrow<[T]> + 'a,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`MultiPickList`] with the given list of options, the
+    /// currently selected values, and the message to produce when the
+    /// selection changes.
+    pub fn new(options: L, selected: S, on_change: impl Fn(Vec<T>) -> Message + 'a) -> Self {
+        Self {
+            on_change: Box::new(on_change),
+            on_open: None,
+            on_close: None,
+            options,
+            selected,
+            placeholder: None,
+            summary: None,
+            select_all: None,
+            chips: false,
+            width: Length::Shrink,
+            padding: crate::button::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::default(),
+            font: None,
+            handle: Handle::default(),
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+            last_status: None,
+            menu_height: Length::Shrink,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Sets the placeholder of the [`MultiPickList`], displayed when
+    /// nothing is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the function used to summarize the selection of a closed
+    /// [`MultiPickList`].
+    ///
+    /// By default, the selected options are listed separated by commas.
+    pub fn summary(mut self, summary: impl Fn(&[T]) -> String + 'a) -> Self {
+        self.summary = Some(Box::new(summary));
+        self
+    }
+
+    /// Adds an entry with the given label at the top of the menu of the
+    /// [`MultiPickList`] that selects all of the options—or none, if they
+    /// are already selected.
+    pub fn select_all(mut self, label: impl Into<String>) -> Self {
+        self.select_all = Some(label.into());
+        self
+    }
+
+    /// Displays the selection of a closed [`MultiPickList`] as chips that
+    /// can be individually removed, instead of a summary.
+    pub fn chips(mut self) -> Self {
+        self.chips = true;
+        self
+    }
+
+    /// Sets the width of the [`MultiPickList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Menu`].
+    pub fn menu_height(mut self, menu_height: impl Into<Length>) -> Self {
+        self.menu_height = menu_height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`MultiPickList`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MultiPickList`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`MultiPickList`].
+    pub fn text_line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`MultiPickList`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`MultiPickList`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Handle`] of the [`MultiPickList`].
+    pub fn handle(mut self, handle: Handle<Renderer::Font>) -> Self {
+        self.handle = handle;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`MultiPickList`] is opened.
+    pub fn on_open(mut self, on_open: Message) -> Self {
+        self.on_open = Some(on_open);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`MultiPickList`] is closed.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets the style of the [`MultiPickList`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the [`Menu`].
+    #[must_use]
+    pub fn menu_style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MultiPickList`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the [`Menu`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(mut self, class: impl Into<<Theme as menu::Catalog>::Class<'a>>) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    /// Returns the label of the current selection, if any.
+    fn label(&self) -> Option<String> {
+        let selected = self.selected.borrow();
+
+        if selected.is_empty() {
+            return None;
+        }
+
+        Some(match &self.summary {
+            Some(summary) => summary(selected),
+            None => selected
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        })
+    }
+
+    /// Lays out the chips of the selected options inside the given bounds.
+    ///
+    /// Returns the index of the option of each chip that fits, together with
+    /// its bounds, and the amount of chips that do not fit.
+    fn chips_layout(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        bounds: Rectangle,
+        text_size: Pixels,
+    ) -> (Vec<(usize, Rectangle)>, usize) {
+        let selected = self.selected.borrow();
+        let height = f32::from(self.text_line_height.to_absolute(text_size));
+        let spacing = text_size.0 * 0.3;
+        let right = bounds.x + bounds.width - self.padding.right - text_size.0;
+
+        let indices: Vec<usize> = self
+            .options
+            .borrow()
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| selected.contains(option))
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut chips = Vec::with_capacity(indices.len());
+        let mut x = bounds.x + self.padding.left;
+
+        for (n, &index) in indices.iter().enumerate() {
+            let label = state
+                .options
+                .get(index)
+                .map(paragraph::Plain::min_width)
+                .unwrap_or_default();
+
+            let width = label + text_size.0 * 1.6;
+            let is_last = n + 1 == indices.len();
+            let reserved = if is_last { 0.0 } else { text_size.0 * 2.0 };
+
+            if x + width + reserved > right {
+                return (chips, indices.len() - n);
+            }
+
+            chips.push((
+                index,
+                Rectangle {
+                    x,
+                    y: bounds.center_y() - height / 2.0,
+                    width,
+                    height,
+                },
+            ));
+
+            x += width + spacing;
+        }
+
+        (chips, 0)
+    }
+}
+
+impl<'a, T, L, S, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MultiPickList<'a, T, L, S, Message, Theme, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'a,
+    L: Borrow<[T]>,
+    S: Borrow<[T]>,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let options = self.options.borrow();
+
+        state.options.resize_with(options.len(), Default::default);
+
+        let option_text = Text {
+            content: "",
+            bounds: Size::new(
+                f32::INFINITY,
+                self.text_line_height.to_absolute(text_size).into(),
+            ),
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::default(),
+            hint_factor: renderer.scale_factor(),
+        };
+
+        for (option, paragraph) in options.iter().zip(state.options.iter_mut()) {
+            let label = option.to_string();
+
+            let _ = paragraph.update(Text {
+                content: &label,
+                ..option_text
+            });
+        }
+
+        if let Some(placeholder) = &self.placeholder {
+            let _ = state.placeholder.update(Text {
+                content: placeholder,
+                ..option_text
+            });
+        }
+
+        let max_width = match self.width {
+            Length::Shrink => {
+                let labels_width = state.options.iter().fold(0.0, |width, paragraph| {
+                    f32::max(width, paragraph.min_width())
+                });
+
+                labels_width.max(
+                    self.placeholder
+                        .as_ref()
+                        .map(|_| state.placeholder.min_width())
+                        .unwrap_or(0.0),
+                )
+            }
+            _ => 0.0,
+        };
+
+        let size = {
+            let intrinsic = Size::new(
+                max_width + text_size.0 + self.padding.left,
+                f32::from(self.text_line_height.to_absolute(text_size)),
+            );
+
+            limits
+                .width(self.width)
+                .shrink(self.padding)
+                .resolve(self.width, Length::Shrink, intrinsic)
+                .expand(self.padding)
+        };
+
+        layout::Node::new(size)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let mut accessible = Accessible::new(Role::ComboBox).expanded(state.is_open);
+
+        if let Some(placeholder) = &self.placeholder {
+            accessible = accessible.label(placeholder.as_str());
+        }
+
+        if let Some(label) = self.label() {
+            accessible = accessible.value(label);
+        }
+
+        operation.accessible(None, layout.bounds(), &accessible);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if state.is_open {
+                // Event wasn't processed by overlay, so cursor was clicked either outside its
+                // bounds or on the drop-down, either way we close the overlay.
+                state.is_open = false;
+
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close.clone());
+                }
+
+                shell.capture_event();
+            } else if cursor.is_over(layout.bounds()) {
+                let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+                let removed = if self.chips {
+                    let (chips, _) = self.chips_layout(state, layout.bounds(), text_size);
+
+                    chips
+                        .into_iter()
+                        .find(|(_, chip)| cursor.is_over(close_bounds(*chip, text_size)))
+                        .map(|(index, _)| index)
+                } else {
+                    None
+                };
+
+                if let Some(removed) = removed {
+                    let options = self.options.borrow();
+                    let selected = self.selected.borrow();
+
+                    shell.publish((self.on_change)(
+                        options
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, option)| *i != removed && selected.contains(option))
+                            .map(|(_, option)| option.clone())
+                            .collect(),
+                    ));
+                } else {
+                    state.is_open = true;
+                    state.hovered_option = None;
+
+                    if let Some(on_open) = &self.on_open {
+                        shell.publish(on_open.clone());
+                    }
+                }
+
+                shell.capture_event();
+            }
+        }
+
+        let status = {
+            let is_hovered = cursor.is_over(layout.bounds());
+
+            if state.is_open {
+                Status::Opened { is_hovered }
+            } else if is_hovered {
+                Status::Hovered
+            } else {
+                Status::Active
+            }
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_status = Some(status);
+        } else if self
+            .last_status
+            .is_some_and(|last_status| last_status != status)
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let is_mouse_over = cursor.is_over(bounds);
+
+        if is_mouse_over {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let bounds = layout.bounds();
+
+        let style = Catalog::style(
+            theme,
+            &self.class,
+            self.last_status.unwrap_or(Status::Active),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        pick_list::draw_handle(
+            renderer,
+            &self.handle,
+            state.is_open,
+            bounds,
+            self.padding,
+            style.handle_color,
+            viewport,
+        );
+
+        let hint_factor = renderer.scale_factor();
+
+        let text = |content: String, width: f32| Text {
+            content,
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            bounds: Size::new(
+                width,
+                f32::from(self.text_line_height.to_absolute(text_size)),
+            ),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::None,
+            hint_factor,
+        };
+
+        let clip_bounds = Rectangle {
+            width: bounds.width - self.padding.right - text_size.0,
+            ..bounds
+        }
+        .intersection(viewport)
+        .unwrap_or(*viewport);
+
+        let label = self.label();
+
+        if self.chips && label.is_some() {
+            let options = self.options.borrow();
+            let (chips, hidden) = self.chips_layout(state, bounds, text_size);
+
+            for (index, chip) in &chips {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: *chip,
+                        border: style.chip_border,
+                        ..renderer::Quad::default()
+                    },
+                    style.chip_background.clone(),
+                );
+
+                renderer.fill_text(
+                    text(options[*index].to_string(), chip.width),
+                    Point::new(chip.x + text_size.0 * 0.4, chip.center_y()),
+                    style.chip_text_color,
+                    clip_bounds,
+                );
+
+                let close = close_bounds(*chip, text_size);
+
+                renderer.fill_text(
+                    Text {
+                        align_x: text::Alignment::Center,
+                        ..text("×".to_owned(), close.width)
+                    },
+                    close.center(),
+                    style.chip_text_color,
+                    clip_bounds,
+                );
+            }
+
+            if hidden > 0 {
+                let x = chips
+                    .last()
+                    .map(|(_, chip)| chip.x + chip.width + text_size.0 * 0.3)
+                    .unwrap_or(bounds.x + self.padding.left);
+
+                renderer.fill_text(
+                    text(format!("+{hidden}"), f32::INFINITY),
+                    Point::new(x, bounds.center_y()),
+                    style.text_color,
+                    clip_bounds,
+                );
+            }
+        } else if let Some(content) = label.clone().or_else(|| self.placeholder.clone()) {
+            renderer.fill_text(
+                text(content, bounds.width - self.padding.x()),
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                if label.is_some() {
+                    style.text_color
+                } else {
+                    style.placeholder_color
+                },
+                clip_bounds,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        if !state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        self.entries = self
+            .select_all
+            .iter()
+            .map(|label| Entry::All(label.clone()))
+            .chain(self.options.borrow().iter().cloned().map(Entry::Option))
+            .collect();
+
+        let options = self.options.borrow();
+        let selected = self.selected.borrow();
+        let on_change = &self.on_change;
+
+        let is_all_selected = is_all_selected(options, selected);
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &self.entries,
+            &mut state.hovered_option,
+            move |entry| on_change(toggle(options, selected, entry)),
+            None,
+            &self.menu_class,
+        )
+        .checked(move |entry| match entry {
+            Entry::All(_) => is_all_selected,
+            Entry::Option(option) => selected.contains(option),
+        })
+        .width(bounds.width)
+        .padding(self.padding)
+        .font(font)
+        .text_shaping(self.text_shaping);
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(
+            layout.position() + translation,
+            *viewport,
+            bounds.height,
+            self.menu_height,
+        ))
+    }
+}
+
+impl<'a, T, L, S, Message, Theme, Renderer>
+    From<MultiPickList<'a, T, L, S, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'a,
+    L: Borrow<[T]> + 'a,
+    S: Borrow<[T]> + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(multi_pick_list: MultiPickList<'a, T, L, S, Message, Theme, Renderer>) -> Self {
+        Self::new(multi_pick_list)
+    }
+}
+
+/// An entry of the menu of a [`MultiPickList`].
+#[derive(Debug, Clone)]
+enum Entry<T> {
+    All(String),
+    Option(T),
+}
+
+impl<T: ToString> fmt::Display for Entry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All(label) => f.write_str(label),
+            Self::Option(option) => f.write_str(&option.to_string()),
+        }
+    }
+}
+
+/// Returns the bounds of the close button of a chip.
+fn is_all_selected<T: PartialEq>(options: &[T], selected: &[T]) -> bool {
+    !options.is_empty() && options.iter().all(|option| selected.contains(option))
+}
+
+/// Returns the selection that results from picking the given [`Entry`]
+/// of the menu.
+fn toggle<T: Clone + PartialEq>(options: &[T], selected: &[T], entry: Entry<T>) -> Vec<T> {
+    match entry {
+        Entry::All(_) if is_all_selected(options, selected) => Vec::new(),
+        Entry::All(_) => options.to_vec(),
+        Entry::Option(toggled) => options
+            .iter()
+            .filter(|option| (**option == toggled) != selected.contains(option))
+            .cloned()
+            .collect(),
+    }
+}
+
+fn close_bounds(chip: Rectangle, text_size: Pixels) -> Rectangle {
+    let size = text_size.0 * 0.8;
+
+    Rectangle {
+        x: chip.x + chip.width - text_size.0 * 0.2 - size,
+        y: chip.center_y() - size / 2.0,
+        width: size,
+        height: size,
+    }
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    menu: menu::State,
+    is_open: bool,
+    hovered_option: Option<usize>,
+    options: Vec<paragraph::Plain<P>>,
+    placeholder: paragraph::Plain<P>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    /// Creates a new [`State`] for a [`MultiPickList`].
+    fn new() -> Self {
+        Self {
+            menu: menu::State::default(),
+            is_open: bool::default(),
+            hovered_option: Option::default(),
+            options: Vec::new(),
+            placeholder: paragraph::Plain::default(),
+        }
+    }
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The appearance of a multi-select pick list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The text [`Color`] of the pick list.
+    pub text_color: Color,
+    /// The placeholder [`Color`] of the pick list.
+    pub placeholder_color: Color,
+    /// The handle [`Color`] of the pick list.
+    pub handle_color: Color,
+    /// The [`Background`] of the pick list.
+    pub background: Background,
+    /// The [`Border`] of the pick list.
+    pub border: Border,
+    /// The [`Background`] of a chip.
+    pub chip_background: Background,
+    /// The text [`Color`] of a chip.
+    pub chip_text_color: Color,
+    /// The [`Border`] of a chip.
+    pub chip_border: Border,
+}
+
+/// The theme catalog of a [`MultiPickList`].
+pub trait Catalog: menu::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the menu of the [`MultiPickList`].
+    fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        <Self as menu::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`MultiPickList`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of the field of a [`MultiPickList`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let field = pick_list::default(theme, status);
+
+    Style {
+        text_color: field.text_color,
+        placeholder_color: field.placeholder_color,
+        handle_color: field.handle_color,
+        background: field.background,
+        border: field.border,
+        chip_background: palette.primary.weak.color.into(),
+        chip_text_color: palette.primary.weak.text,
+        chip_border: Border {
+            radius: 4.0.into(),
+            ..Border::default()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_all_toggles_every_option() {
+        let options = [1, 2, 3];
+        let all = || Entry::All(String::from("All"));
+
+        assert_eq!(toggle(&options, &[], all()), [1, 2, 3]);
+        assert_eq!(toggle(&options, &[2], all()), [1, 2, 3]);
+        assert_eq!(toggle(&options, &[3, 1, 2], all()), []);
+        assert_eq!(toggle(&[], &[], all()), [0; 0]);
+    }
+
+    #[test]
+    fn options_are_toggled_in_order() {
+        let options = [1, 2, 3];
+
+        assert_eq!(toggle(&options, &[3], Entry::Option(1)), [1, 3]);
+        assert_eq!(toggle(&options, &[1, 3], Entry::Option(1)), [3]);
+
+        // Stale selections are dropped
+        assert_eq!(toggle(&options, &[4], Entry::Option(2)), [2]);
+    }
+}
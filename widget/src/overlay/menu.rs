@@ -28,6 +28,7 @@ where
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    checked: Option<Box<dyn Fn(&T) -> bool + 'a>>,
     width: f32,
     padding: Padding,
     text_size: Option<Pixels>,
@@ -61,6 +62,7 @@ where
             hovered_option,
             on_selected: Box::new(on_selected),
             on_option_hovered,
+            checked: None,
             width: 0.0,
             padding: Padding::ZERO,
             text_size: None,
@@ -107,6 +109,16 @@ where
         self
     }
 
+    /// Displays a checkbox next to every option of the [`Menu`], checked
+    /// whenever the given closure returns `true`.
+    ///
+    /// The checkboxes widen the [`Menu`] by one and a half times its
+    /// text size.
+    pub fn checked(mut self, checked: impl Fn(&T) -> bool + 'a) -> Self {
+        self.checked = Some(Box::new(checked));
+        self
+    }
+
     /// Turns the [`Menu`] into an overlay [`Element`] at the given target
     /// position.
     ///
@@ -161,6 +173,7 @@ where
     tree: &'a mut Tree,
     list: Scrollable<'a, Message, Theme, Renderer>,
     width: f32,
+    check_size: Option<Option<Pixels>>,
     target_height: f32,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
@@ -188,6 +201,7 @@ where
             hovered_option,
             on_selected,
             on_option_hovered,
+            checked,
             width,
             padding,
             font,
@@ -197,11 +211,14 @@ where
             class,
         } = menu;
 
+        let check_size = checked.is_some().then_some(text_size);
+
         let list = Scrollable::new(List {
             options,
             hovered_option,
             on_selected,
             on_option_hovered,
+            checked,
             font,
             text_size,
             text_line_height,
@@ -219,6 +236,7 @@ where
             tree: &mut state.tree,
             list,
             width,
+            check_size,
             target_height,
             class,
         }
@@ -235,6 +253,13 @@ where
         let space_below = bounds.height - (self.position.y + self.target_height);
         let space_above = self.position.y;
 
+        // The labels are shifted to make room for the checkboxes
+        let width = self.width
+            + self
+                .check_size
+                .map(|size| size.unwrap_or_else(|| renderer.default_size()).0 * 1.5)
+                .unwrap_or(0.0);
+
         let limits = layout::Limits::new(
             Size::ZERO,
             Size::new(
@@ -246,7 +271,7 @@ where
                 },
             ),
         )
-        .width(self.width);
+        .width(width);
 
         let node = self.list.layout(self.tree, renderer, &limits);
         let size = node.size();
@@ -321,6 +346,7 @@ where
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    checked: Option<Box<dyn Fn(&T) -> bool + 'a>>,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
@@ -516,10 +542,64 @@ where
                         border: border::rounded(style.border.radius),
                         ..renderer::Quad::default()
                     },
-                    style.selected_background,
+                    style.selected_background.clone(),
                 );
             }
 
+            let text_color = if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            let text_x = if let Some(checked) = &self.checked {
+                let size = text_size.0;
+
+                let checkbox = Rectangle {
+                    x: bounds.x + self.padding.left,
+                    y: bounds.center_y() - size / 2.0,
+                    width: size,
+                    height: size,
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: checkbox,
+                        border: Border {
+                            width: 1.0,
+                            radius: (size / 5.0).into(),
+                            color: text_color,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+
+                if checked(option) {
+                    renderer.fill_text(
+                        Text {
+                            content: Renderer::CHECKMARK_ICON.to_string(),
+                            bounds: checkbox.size(),
+                            size: Pixels(size * 0.7),
+                            line_height: text::LineHeight::default(),
+                            font: Renderer::ICON_FONT,
+                            align_x: text::Alignment::Center,
+                            align_y: alignment::Vertical::Center,
+                            shaping: text::Shaping::Basic,
+                            wrapping: text::Wrapping::default(),
+                            hint_factor: None,
+                        },
+                        checkbox.center(),
+                        text_color,
+                        *viewport,
+                    );
+                }
+
+                checkbox.x + size + size / 2.0
+            } else {
+                bounds.x + self.padding.left
+            };
+
             renderer.fill_text(
                 Text {
                     content: option.to_string(),
@@ -533,12 +613,8 @@ where
                     wrapping: text::Wrapping::default(),
                     hint_factor: renderer.scale_factor(),
                 },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                if is_selected {
-                    style.selected_text_color
-                } else {
-                    style.text_color
-                },
+                Point::new(text_x, bounds.center_y()),
+                text_color,
                 *viewport,
             );
         }
@@ -560,7 +636,7 @@ where
 }
 
 /// The appearance of a [`Menu`].
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the menu.
     pub background: Background,
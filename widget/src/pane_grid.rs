@@ -12,6 +12,7 @@
 //! * Tracking of the last active pane
 //! * Mouse-based resizing
 //! * Drag and drop to reorganize panes
+//! * Tab groups of stacked panes
 //! * Hotkey support
 //! * Configurable modifier keys
 //! * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -110,6 +111,7 @@ const THICKNESS_RATIO: f32 = 25.0;
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Tab groups of stacked panes
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -162,6 +164,7 @@ where
     on_click: Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
     on_resize: Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    stacking: bool,
     class: <Theme as Catalog>::Class<'a>,
     last_mouse_interaction: Option<mouse::Interaction>,
 }
@@ -200,6 +203,7 @@ where
             on_click: None,
             on_drag: None,
             on_resize: None,
+            stacking: false,
             class: <Theme as Catalog>::default(),
             last_mouse_interaction: None,
         }
@@ -270,6 +274,18 @@ where
         self
     }
 
+    /// Sets whether panes can be stacked as tabs by dropping them onto the
+    /// [`TitleBar`] of another [`Pane`].
+    ///
+    /// When enabled, dropping a [`Pane`] onto a [`TitleBar`] produces a
+    /// [`DragEvent::Dropped`] with a [`Region::Tab`] target.
+    ///
+    /// By default, panes cannot be stacked.
+    pub fn stacking(mut self, stacking: bool) -> Self {
+        self.stacking = stacking;
+        self
+    }
+
     /// Sets the style of the [`PaneGrid`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -296,6 +312,23 @@ where
         }
     }
 
+    fn drop_region(
+        &self,
+        content: &Content<'_, Message, Theme, Renderer>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<Region> {
+        if self.stacking
+            && content
+                .title_bar_bounds(layout)
+                .is_some_and(|bounds| bounds.contains(cursor_position))
+        {
+            return Some(Region::Tab);
+        }
+
+        layout_region(layout, cursor_position)
+    }
+
     fn grid_interaction(
         &self,
         action: &state::Action,
@@ -416,11 +449,7 @@ where
             .zip(&mut self.contents)
             .zip(tree.children.iter_mut())
             .filter_map(|((pane, content), tree)| {
-                if self
-                    .internal
-                    .maximized()
-                    .is_some_and(|maximized| maximized != *pane)
-                {
+                if !self.internal.is_visible(*pane) {
                     return Some(layout::Node::new(Size::ZERO));
                 }
 
@@ -450,11 +479,7 @@ where
                 .zip(&mut self.contents)
                 .zip(&mut tree.children)
                 .zip(layout.children())
-                .filter(|(((pane, _), _), _)| {
-                    self.internal
-                        .maximized()
-                        .is_none_or(|maximized| **pane == maximized)
-                })
+                .filter(|(((pane, _), _), _)| self.internal.is_visible(**pane))
                 .for_each(|(((_, content), state), layout)| {
                     content.operate(state, layout, renderer, operation);
                 });
@@ -490,11 +515,7 @@ where
             .zip(&mut self.contents)
             .zip(&mut tree.children)
             .zip(layout.children())
-            .filter(|(((pane, _), _), _)| {
-                self.internal
-                    .maximized()
-                    .is_none_or(|maximized| *pane == maximized)
-            })
+            .filter(|(((pane, _), _), _)| self.internal.is_visible(*pane))
         {
             let is_picked = picked_pane == Some(pane);
 
@@ -577,18 +598,16 @@ where
                                 .copied()
                                 .zip(&self.contents)
                                 .zip(layout.children())
-                                .find_map(|(target, layout)| {
-                                    layout_region(layout, cursor_position)
+                                .find_map(|((target, content), layout)| {
+                                    self.drop_region(content, layout, cursor_position)
                                         .map(|region| (target, region))
                                 });
 
                             match dropped_region {
-                                Some(((target, _), region)) if pane != target => {
-                                    DragEvent::Dropped {
-                                        pane,
-                                        target: Target::Pane(target, region),
-                                    }
-                                }
+                                Some((target, region)) if pane != target => DragEvent::Dropped {
+                                    pane,
+                                    target: Target::Pane(target, region),
+                                },
                                 _ => DragEvent::Canceled { pane },
                             }
                         };
@@ -645,11 +664,7 @@ where
                         .iter()
                         .zip(&self.contents)
                         .zip(layout.children())
-                        .filter(|((pane, _content), _layout)| {
-                            self.internal
-                                .maximized()
-                                .is_none_or(|maximized| **pane == maximized)
-                        })
+                        .filter(|((pane, _content), _layout)| self.internal.is_visible(**pane))
                         .find_map(|((_pane, content), layout)| {
                             content.grid_interaction(layout, cursor, on_drag.is_some())
                         })
@@ -687,11 +702,7 @@ where
             .zip(&self.contents)
             .zip(&tree.children)
             .zip(layout.children())
-            .filter(|(((pane, _), _), _)| {
-                self.internal
-                    .maximized()
-                    .is_none_or(|maximized| *pane == maximized)
-            })
+            .filter(|(((pane, _), _), _)| self.internal.is_visible(*pane))
             .map(|(((_, content), tree), layout)| {
                 content.mouse_interaction(
                     tree,
@@ -784,17 +795,14 @@ where
             .zip(&self.contents)
             .zip(&tree.children)
             .zip(layout.children())
-            .filter(|(((pane, _), _), _)| {
-                self.internal
-                    .maximized()
-                    .is_none_or(|maximized| maximized == *pane)
-            })
+            .filter(|(((pane, _), _), _)| self.internal.is_visible(*pane))
         {
             match picked_pane {
                 Some((dragging, origin)) if id == dragging => {
-                    render_picked_pane = Some(((content, tree), origin, pane_layout));
+                    render_picked_pane =
+                        Some(((content, tree), origin, pane_layout, pane_layout.bounds()));
                 }
-                Some((dragging, _)) if id != dragging => {
+                Some((dragging, origin)) if id != dragging => {
                     content.draw(
                         tree,
                         renderer,
@@ -805,13 +813,25 @@ where
                         viewport,
                     );
 
+                    // A hidden pane is dragged by its tab, so the tab is picked instead
+                    if !self.internal.is_visible(dragging)
+                        && let Some(tab) = content.tab_bounds(pane_layout, dragging)
+                    {
+                        render_picked_pane = Some(((content, tree), origin, pane_layout, tab));
+                    }
+
                     if picked_pane.is_some()
                         && pane_in_edge.is_none()
-                        && let Some(region) = cursor
-                            .position()
-                            .and_then(|cursor_position| layout_region(pane_layout, cursor_position))
+                        && let Some(region) = cursor.position().and_then(|cursor_position| {
+                            self.drop_region(content, pane_layout, cursor_position)
+                        })
                     {
-                        let bounds = layout_region_bounds(pane_layout, region);
+                        let bounds = match region {
+                            Region::Tab => content
+                                .title_bar_bounds(pane_layout)
+                                .unwrap_or(pane_layout.bounds()),
+                            _ => layout_region_bounds(pane_layout, region),
+                        };
 
                         renderer.fill_quad(
                             renderer::Quad {
@@ -819,7 +839,7 @@ where
                                 border: style.hovered_region.border,
                                 ..renderer::Quad::default()
                             },
-                            style.hovered_region.background,
+                            style.hovered_region.background.clone(),
                         );
                     }
                 }
@@ -837,6 +857,33 @@ where
             }
         }
 
+        for ((id, content), pane_layout) in self
+            .panes
+            .iter()
+            .copied()
+            .zip(&self.contents)
+            .zip(layout.children())
+            .filter(|((pane, _), _)| self.internal.is_visible(*pane))
+        {
+            if picked_pane.is_some_and(|(dragging, _)| dragging == id) {
+                continue;
+            }
+
+            if let Some(tab) = content.tab_bounds(pane_layout, id) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: tab.y + tab.height - style.active_tab.width,
+                            height: style.active_tab.width,
+                            ..tab
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.active_tab.color,
+                );
+            }
+        }
+
         if let Some(edge) = pane_in_edge {
             let bounds = edge_bounds(layout, edge);
 
@@ -846,16 +893,14 @@ where
                     border: style.hovered_region.border,
                     ..renderer::Quad::default()
                 },
-                style.hovered_region.background,
+                style.hovered_region.background.clone(),
             );
         }
 
         // Render picked pane last
-        if let Some(((content, tree), origin, layout)) = render_picked_pane
+        if let Some(((content, tree), origin, layout, bounds)) = render_picked_pane
             && let Some(cursor_position) = cursor.position()
         {
-            let bounds = layout.bounds();
-
             let translation = cursor_position - Point::new(origin.x, origin.y);
 
             renderer.with_translation(translation, |renderer| {
@@ -923,11 +968,7 @@ where
             .zip(&mut tree.children)
             .zip(layout.children())
             .filter_map(|(((pane, content), state), layout)| {
-                if self
-                    .internal
-                    .maximized()
-                    .is_some_and(|maximized| maximized != pane)
-                {
+                if !self.internal.is_visible(pane) {
                     return None;
                 }
 
@@ -991,12 +1032,15 @@ fn click_pane<'a, Message, T>(
         .filter(|(_, layout)| layout.bounds().contains(cursor_position));
 
     if let Some(((pane, content), layout)) = clicked_region.next() {
+        let tab = content.tab_at(layout, cursor_position);
+        let pane = tab.unwrap_or(pane);
+
         if let Some(on_click) = &on_click {
             shell.publish(on_click(pane));
         }
 
         if let Some(on_drag) = &on_drag
-            && content.can_be_dragged_at(layout, cursor_position)
+            && (tab.is_some() || content.can_be_dragged_at(layout, cursor_position))
         {
             *action = state::Action::Dragging {
                 pane,
@@ -1062,7 +1106,7 @@ fn layout_region_bounds(layout: Layout<'_>, region: Region) -> Rectangle {
     let bounds = layout.bounds();
 
     match region {
-        Region::Center => bounds,
+        Region::Center | Region::Tab => bounds,
         Region::Edge(edge) => match edge {
             Edge::Top => Rectangle {
                 height: bounds.height / 2.0,
@@ -1129,6 +1173,10 @@ pub enum Region {
     Center,
     /// Edge region.
     Edge(Edge),
+    /// Title bar region, where panes are stacked as tabs.
+    ///
+    /// It is only available when [`PaneGrid::stacking`] is enabled.
+    Tab,
 }
 
 /// The edges of an area.
@@ -1177,7 +1225,7 @@ fn hovered_split<'a>(
 }
 
 /// The appearance of a [`PaneGrid`].
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The appearance of a hovered region highlight.
     pub hovered_region: Highlight,
@@ -1185,10 +1233,12 @@ pub struct Style {
     pub picked_split: Line,
     /// The appearance of a hovered split.
     pub hovered_split: Line,
+    /// The appearance of the line under the active tab of a group.
+    pub active_tab: Line,
 }
 
 /// The appearance of a highlight of the [`PaneGrid`].
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Highlight {
     /// The [`Background`] of the pane region.
     pub background: Background,
@@ -1260,5 +1310,9 @@ pub fn default(theme: &Theme) -> Style {
             color: palette.primary.strong.color,
             width: 2.0,
         },
+        active_tab: Line {
+            color: palette.primary.strong.color,
+            width: 2.0,
+        },
     }
 }
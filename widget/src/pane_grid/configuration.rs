@@ -2,8 +2,25 @@ use crate::pane_grid::Axis;
 
 /// The arrangement of a [`PaneGrid`].
 ///
+/// A [`Configuration`] can be obtained from an existing [`State`] with
+/// [`State::configuration`], mapping each pane to a key of your choice.
+/// With the `serde` feature enabled, it can then be persisted and restored
+/// later on with [`State::try_from_configuration`].
+///
+/// A deserialized [`Configuration`] may not be valid; for instance, it may
+/// contain a split ratio outside of [0.0, 1.0] or an empty group of tabs.
+/// Always restore it with [`State::try_from_configuration`], which rejects
+/// it with an [`Error`]; and not with [`State::with_configuration`], which
+/// trusts its input.
+///
 /// [`PaneGrid`]: super::PaneGrid
-#[derive(Debug, Clone)]
+/// [`State`]: super::State
+/// [`Error`]: super::state::Error
+/// [`State::with_configuration`]: super::State::with_configuration
+/// [`State::configuration`]: super::State::configuration
+/// [`State::try_from_configuration`]: super::State::try_from_configuration
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Configuration<T> {
     /// A split of the available space.
     Split {
@@ -23,4 +40,97 @@ pub enum Configuration<T> {
     ///
     /// [`Pane`]: super::Pane
     Pane(T),
+    /// A group of panes stacked as tabs.
+    ///
+    /// An empty group is dropped, and its sibling takes the whole region
+    /// of their split.
+    Tabs {
+        /// The panes of the group, in tab order.
+        panes: Vec<T>,
+
+        /// The index of the active pane of the group.
+        active: usize,
+    },
+}
+
+impl<T> Configuration<T> {
+    /// Applies a transformation to the panes of the [`Configuration`].
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Configuration<U> {
+        fn map<T, U>(
+            configuration: Configuration<T>,
+            f: &mut dyn FnMut(T) -> U,
+        ) -> Configuration<U> {
+            match configuration {
+                Configuration::Split { axis, ratio, a, b } => Configuration::Split {
+                    axis,
+                    ratio,
+                    a: Box::new(map(*a, f)),
+                    b: Box::new(map(*b, f)),
+                },
+                Configuration::Pane(pane) => Configuration::Pane(f(pane)),
+                Configuration::Tabs { panes, active } => Configuration::Tabs {
+                    panes: panes.into_iter().map(f).collect(),
+                    active,
+                },
+            }
+        }
+
+        map(self, &mut f)
+    }
+
+    /// Keeps only the panes of the [`Configuration`] that satisfy the given
+    /// predicate.
+    ///
+    /// The sibling of a removed pane takes the whole region of their split.
+    /// Returns `None` if no pane is left.
+    pub fn retain(self, mut f: impl FnMut(&T) -> bool) -> Option<Self> {
+        fn retain<T>(
+            configuration: Configuration<T>,
+            f: &mut dyn FnMut(&T) -> bool,
+        ) -> Option<Configuration<T>> {
+            match configuration {
+                Configuration::Split { axis, ratio, a, b } => {
+                    match (retain(*a, f), retain(*b, f)) {
+                        (Some(a), Some(b)) => Some(Configuration::Split {
+                            axis,
+                            ratio,
+                            a: Box::new(a),
+                            b: Box::new(b),
+                        }),
+                        (Some(remaining), None) | (None, Some(remaining)) => Some(remaining),
+                        (None, None) => None,
+                    }
+                }
+                Configuration::Pane(pane) => f(&pane).then_some(Configuration::Pane(pane)),
+                Configuration::Tabs { panes, active } => {
+                    let mut new_active = 0;
+
+                    let mut panes: Vec<_> = panes
+                        .into_iter()
+                        .enumerate()
+                        .filter(|(_, pane)| f(pane))
+                        .enumerate()
+                        .map(|(retained, (index, pane))| {
+                            if index <= active {
+                                new_active = retained;
+                            }
+
+                            pane
+                        })
+                        .collect();
+
+                    match panes.len() {
+                        0 => None,
+                        1 => panes.pop().map(Configuration::Pane),
+                        _ => Some(Configuration::Tabs {
+                            panes,
+                            active: new_active,
+                        }),
+                    }
+                }
+            }
+        }
+
+        retain(self, &mut f)
+    }
 }
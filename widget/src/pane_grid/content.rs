@@ -5,7 +5,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::{self, Tree};
 use crate::core::{self, Clipboard, Element, Event, Layout, Point, Rectangle, Shell, Size, Vector};
-use crate::pane_grid::{Draggable, TitleBar};
+use crate::pane_grid::{Draggable, Pane, TitleBar};
 
 /// The content of a [`Pane`].
 ///
@@ -262,6 +262,22 @@ where
         }
     }
 
+    /// Returns the bounds of the [`TitleBar`] of the [`Content`], if any.
+    pub(super) fn title_bar_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.title_bar
+            .as_ref()
+            .and_then(|_| layout.children().next())
+            .map(|layout| layout.bounds())
+    }
+
+    /// Returns the bounds of the tab of the given [`Pane`] in the
+    /// [`TitleBar`] of the [`Content`], if any.
+    pub(super) fn tab_bounds(&self, layout: Layout<'_>, pane: Pane) -> Option<Rectangle> {
+        let title_bar = self.title_bar.as_ref()?;
+
+        title_bar.tab_bounds(layout.children().next()?, pane)
+    }
+
     pub(crate) fn grid_interaction(
         &self,
         layout: Layout<'_>,
@@ -387,6 +403,12 @@ where
             false
         }
     }
+
+    fn tab_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<Pane> {
+        let title_bar = self.title_bar.as_ref()?;
+
+        title_bar.tab_at(layout.children().next()?, cursor_position)
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> From<T> for Content<'a, Message, Theme, Renderer>
@@ -27,6 +27,17 @@ pub enum Node {
     },
     /// The region of this [`Node`] is taken by a [`Pane`].
     Pane(Pane),
+    /// The region of this [`Node`] is taken by a group of panes stacked as
+    /// tabs.
+    ///
+    /// Only the active [`Pane`] of the group is displayed.
+    Tabs {
+        /// The panes of the group, in tab order.
+        panes: Vec<Pane>,
+
+        /// The index of the active [`Pane`] of the group.
+        active: usize,
+    },
 }
 
 #[derive(Debug)]
@@ -99,7 +110,7 @@ impl Node {
                     b: Box::new(b),
                 }
             }
-            Node::Pane(_) => Count::Pane,
+            Node::Pane(_) | Node::Tabs { .. } => Count::Pane,
         }
     }
 
@@ -158,6 +169,26 @@ impl Node {
         splits
     }
 
+    /// Returns the panes stacked as tabs together with the given [`Pane`] and
+    /// the index of the active one, if the [`Pane`] is part of a tab group.
+    pub fn tabs(&self, pane: Pane) -> Option<(&[Pane], usize)> {
+        match self {
+            Node::Split { a, b, .. } => a.tabs(pane).or_else(|| b.tabs(pane)),
+            Node::Pane(_) => None,
+            Node::Tabs { panes, active } => panes.contains(&pane).then_some((panes, *active)),
+        }
+    }
+
+    /// Returns whether the given [`Pane`] is displayed; that is, it is part
+    /// of the [`Node`] and it is not hidden behind another tab.
+    pub fn is_visible(&self, pane: Pane) -> bool {
+        match self {
+            Node::Split { a, b, .. } => a.is_visible(pane) || b.is_visible(pane),
+            Node::Pane(p) => *p == pane,
+            Node::Tabs { panes, active } => panes.get(*active) == Some(&pane),
+        }
+    }
+
     pub(crate) fn find(&mut self, pane: Pane) -> Option<&mut Node> {
         match self {
             Node::Split { a, b, .. } => a.find(pane).or_else(move || b.find(pane)),
@@ -168,6 +199,37 @@ impl Node {
                     None
                 }
             }
+            Node::Tabs { panes, .. } => {
+                if panes.contains(&pane) {
+                    Some(self)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub(crate) fn stack(&mut self, pane: Pane) {
+        match self {
+            Node::Split { .. } => {}
+            Node::Pane(p) => {
+                *self = Node::Tabs {
+                    panes: vec![*p, pane],
+                    active: 1,
+                };
+            }
+            Node::Tabs { panes, active } => {
+                panes.push(pane);
+                *active = panes.len() - 1;
+            }
+        }
+    }
+
+    pub(crate) fn activate(&mut self, pane: Pane) {
+        if let Node::Tabs { panes, active } = self
+            && let Some(index) = panes.iter().position(|p| *p == pane)
+        {
+            *active = index;
         }
     }
 
@@ -215,7 +277,7 @@ impl Node {
                     b.resize(split, percentage)
                 }
             }
-            Node::Pane(_) => false,
+            Node::Pane(_) | Node::Tabs { .. } => false,
         }
     }
 
@@ -233,12 +295,28 @@ impl Node {
                 }
             }
             Node::Pane(_) => None,
+            Node::Tabs { panes, active } => {
+                let index = panes.iter().position(|p| *p == pane)?;
+                let _ = panes.remove(index);
+
+                if index < *active || *active == panes.len() {
+                    *active -= 1;
+                }
+
+                let sibling = panes[*active];
+
+                if panes.len() == 1 {
+                    *self = Node::Pane(sibling);
+                }
+
+                Some(sibling)
+            }
         }
     }
 
     fn pane(&self) -> Option<Pane> {
         match self {
-            Node::Split { .. } => None,
+            Node::Split { .. } | Node::Tabs { .. } => None,
             Node::Pane(pane) => Some(*pane),
         }
     }
@@ -247,6 +325,7 @@ impl Node {
         match self {
             Node::Split { a, .. } => a.first_pane(),
             Node::Pane(pane) => *pane,
+            Node::Tabs { panes, active } => panes[*active],
         }
     }
 
@@ -288,6 +367,11 @@ impl Node {
             (Node::Pane(pane), Count::Pane) => {
                 let _ = regions.insert(*pane, *current);
             }
+            (Node::Tabs { panes, active }, Count::Pane) => {
+                if let Some(pane) = panes.get(*active) {
+                    let _ = regions.insert(*pane, *current);
+                }
+            }
             _ => {
                 unreachable!("Node configuration and count do not match")
             }
@@ -335,7 +419,7 @@ impl Node {
                 a.compute_splits(spacing, min_size, &region_a, count_a, splits);
                 b.compute_splits(spacing, min_size, &region_b, count_b, splits);
             }
-            (Node::Pane(_), Count::Pane) => {}
+            (Node::Pane(_) | Node::Tabs { .. }, Count::Pane) => {}
             _ => {
                 unreachable!("Node configuration and split count do not match")
             }
@@ -362,6 +446,54 @@ impl std::hash::Hash for Node {
             Node::Pane(pane) => {
                 pane.hash(state);
             }
+            Node::Tabs { panes, active } => {
+                panes.hash(state);
+                active.hash(state);
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removing_the_last_active_tab_activates_the_previous_one() {
+        let [a, b, c] = [Pane(0), Pane(1), Pane(2)];
+
+        let mut node = Node::Tabs {
+            panes: vec![a, b, c],
+            active: 2,
+        };
+
+        assert_eq!(node.remove(c), Some(b));
+        assert!(matches!(
+            &node,
+            Node::Tabs { panes, active: 1 } if panes == &[a, b]
+        ));
+    }
+
+    #[test]
+    fn removing_a_tab_of_a_pair_collapses_the_group() {
+        let [a, b, c] = [Pane(0), Pane(1), Pane(2)];
+
+        let mut node = Node::Split {
+            id: Split(0),
+            axis: Axis::Horizontal,
+            ratio: 0.5,
+            a: Box::new(Node::Pane(a)),
+            b: Box::new(Node::Tabs {
+                panes: vec![b, c],
+                active: 0,
+            }),
+        };
+
+        assert_eq!(node.remove(b), Some(c));
+        assert!(matches!(
+            &node,
+            Node::Split { a: first, b: second, .. }
+                if first.pane() == Some(a) && second.pane() == Some(c)
+        ));
+    }
+}
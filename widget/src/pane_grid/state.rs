@@ -6,6 +6,7 @@ use crate::pane_grid::{Axis, Configuration, Direction, Edge, Node, Pane, Region,
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use thiserror::Error;
 
 /// The state of a [`PaneGrid`].
 ///
@@ -38,14 +39,33 @@ impl<T> State<T> {
     ///
     /// Alongside the [`State`], it returns the first [`Pane`] identifier.
     pub fn new(first_pane_state: T) -> (Self, Pane) {
+        let pane = Pane(0);
+
         (
-            Self::with_configuration(Configuration::Pane(first_pane_state)),
-            Pane(0),
+            State {
+                panes: BTreeMap::from([(pane, first_pane_state)]),
+                internal: Internal {
+                    layout: Node::Pane(pane),
+                    last_id: 1,
+                    maximized: None,
+                },
+            },
+            pane,
         )
     }
 
     /// Creates a new [`State`] with the given [`Configuration`].
-    pub fn with_configuration(config: impl Into<Configuration<T>>) -> Self {
+    ///
+    /// Empty groups of tabs are dropped, so their sibling takes the whole
+    /// region of their split. If no panes are left, the [`State`] starts
+    /// with a single pane with the default state.
+    ///
+    /// The [`Configuration`] is not validated. Use
+    /// [`State::try_from_configuration`] to restore a deserialized one.
+    pub fn with_configuration(config: impl Into<Configuration<T>>) -> Self
+    where
+        T: Default,
+    {
         let mut panes = BTreeMap::default();
 
         let internal = Internal::from_configuration(&mut panes, config.into(), 0);
@@ -53,6 +73,111 @@ impl<T> State<T> {
         State { panes, internal }
     }
 
+    /// Creates a new [`State`] from a [`Configuration`] of keys, restoring
+    /// the internal state of each pane from its key.
+    ///
+    /// This is useful to restore a layout previously obtained with
+    /// [`State::configuration`]. Use [`Configuration::retain`] beforehand
+    /// to drop the panes you no longer know about, instead of failing.
+    ///
+    /// Returns an [`Error`] if some key cannot be restored or some split has
+    /// an invalid ratio.
+    pub fn try_from_configuration<K>(
+        configuration: Configuration<K>,
+        mut restore: impl FnMut(&K) -> Option<T>,
+    ) -> Result<Self, Error<K>> {
+        fn restore_all<K, T>(
+            configuration: Configuration<K>,
+            restore: &mut dyn FnMut(&K) -> Option<T>,
+        ) -> Result<Configuration<T>, Error<K>> {
+            match configuration {
+                Configuration::Split { axis, ratio, a, b } => {
+                    if !(0.0..=1.0).contains(&ratio) {
+                        return Err(Error::InvalidRatio(ratio));
+                    }
+
+                    Ok(Configuration::Split {
+                        axis,
+                        ratio,
+                        a: Box::new(restore_all(*a, restore)?),
+                        b: Box::new(restore_all(*b, restore)?),
+                    })
+                }
+                Configuration::Pane(key) => restore(&key)
+                    .map(Configuration::Pane)
+                    .ok_or(Error::UnknownPane(key)),
+                Configuration::Tabs { panes, active } => {
+                    if panes.is_empty() {
+                        return Err(Error::EmptyTabs);
+                    }
+
+                    if active >= panes.len() {
+                        return Err(Error::InvalidTabs(active));
+                    }
+
+                    Ok(Configuration::Tabs {
+                        panes: panes
+                            .into_iter()
+                            .map(|key| restore(&key).ok_or(Error::UnknownPane(key)))
+                            .collect::<Result<_, _>>()?,
+                        active,
+                    })
+                }
+            }
+        }
+
+        let configuration = restore_all(configuration, &mut restore)?;
+
+        let mut panes = BTreeMap::default();
+        let (layout, last_id) =
+            Internal::node(&mut panes, configuration, 0).ok_or(Error::EmptyTabs)?;
+
+        Ok(State {
+            panes,
+            internal: Internal {
+                layout,
+                last_id,
+                maximized: None,
+            },
+        })
+    }
+
+    /// Returns the current [`Configuration`] of the [`State`], mapping each
+    /// [`Pane`] to a key with the given function.
+    ///
+    /// The keys should identify the contents of each [`Pane`] across
+    /// restarts, since [`Pane`] identifiers are not stable.
+    ///
+    /// Whether a [`Pane`] is maximized is not part of the [`Configuration`].
+    pub fn configuration<K>(&self, mut key: impl FnMut(Pane, &T) -> K) -> Configuration<K> {
+        fn configuration<T, K>(
+            node: &Node,
+            panes: &BTreeMap<Pane, T>,
+            key: &mut dyn FnMut(Pane, &T) -> K,
+        ) -> Configuration<K> {
+            match node {
+                Node::Split {
+                    axis, ratio, a, b, ..
+                } => Configuration::Split {
+                    axis: *axis,
+                    ratio: *ratio,
+                    a: Box::new(configuration(a, panes, key)),
+                    b: Box::new(configuration(b, panes, key)),
+                },
+                Node::Pane(pane) => Configuration::Pane(key(*pane, &panes[pane])),
+                Node::Tabs {
+                    panes: tabs,
+                    active,
+                } => Configuration::Tabs {
+                    panes: tabs.iter().map(|pane| key(*pane, &panes[pane])).collect(),
+                    active: *active,
+                },
+            }
+        }
+
+        configuration(&self.internal.layout, &self.panes, &mut key)
+    }
+
     /// Returns the total amount of panes in the [`State`].
     pub fn len(&self) -> usize {
         self.panes.len()
@@ -91,6 +216,60 @@ impl<T> State<T> {
         &self.internal.layout
     }
 
+    /// Returns the panes stacked as tabs together with the given [`Pane`],
+    /// including itself, if it is part of a tab group.
+    ///
+    /// Only the active tab of a group is displayed; so, when building the
+    /// [`TitleBar`] of a [`Pane`] in the view function of a [`PaneGrid`],
+    /// the active tab is the [`Pane`] itself.
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`TitleBar`]: super::TitleBar
+    pub fn tabs(&self, pane: Pane) -> Option<&[Pane]> {
+        self.internal.layout.tabs(pane).map(|(panes, _)| panes)
+    }
+
+    /// Returns whether the given [`Pane`] is displayed; that is, it is not
+    /// hidden behind another tab or another maximized [`Pane`].
+    pub fn is_visible(&self, pane: Pane) -> bool {
+        self.internal.is_visible(pane)
+    }
+
+    /// Stacks the given [`Pane`] as a tab on top of the target [`Pane`],
+    /// making it the active tab of the group.
+    ///
+    /// If the target [`Pane`] is already part of a tab group, the [`Pane`]
+    /// is added at the end of the group.
+    pub fn stack(&mut self, pane: Pane, target: Pane) {
+        if pane == target || !self.panes.contains_key(&pane) || !self.panes.contains_key(&target) {
+            return;
+        }
+
+        if self.internal.layout.remove(pane).is_none() {
+            return;
+        }
+
+        if let Some(node) = self.internal.layout.find(target) {
+            node.stack(pane);
+        }
+
+        let _ = self.internal.maximized.take();
+    }
+
+    /// Makes the given [`Pane`] the active tab of its group.
+    ///
+    /// If you want to switch tabs when they are clicked, you will need to
+    /// call this method when handling the [`on_click`] message of your
+    /// [`PaneGrid`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`on_click`]: super::PaneGrid::on_click
+    pub fn activate(&mut self, pane: Pane) {
+        if let Some(node) = self.internal.layout.find(pane) {
+            node.activate(pane);
+        }
+    }
+
     /// Returns the adjacent [`Pane`] of another [`Pane`] in the given
     /// direction, if there is one.
     pub fn adjacent(&self, pane: Pane, direction: Direction) -> Option<Pane> {
@@ -129,10 +308,12 @@ impl<T> State<T> {
 
     /// Split a target [`Pane`] with a given [`Pane`] on a given [`Region`].
     ///
-    /// Panes will be swapped by default for [`Region::Center`].
+    /// Panes will be swapped by default for [`Region::Center`], and stacked
+    /// as tabs for [`Region::Tab`].
     pub fn split_with(&mut self, target: Pane, pane: Pane, region: Region) {
         match region {
             Region::Center => self.swap(pane, target),
+            Region::Tab => self.stack(pane, target),
             Region::Edge(edge) => match edge {
                 Edge::Top => {
                     self.split_and_swap(Axis::Horizontal, target, pane, true);
@@ -257,15 +438,18 @@ impl<T> State<T> {
     /// [`PaneGrid`]: super::PaneGrid
     /// [`DragEvent`]: super::DragEvent
     pub fn swap(&mut self, a: Pane, b: Pane) {
+        let swap = |pane: &mut Pane| {
+            if *pane == a {
+                *pane = b;
+            } else if *pane == b {
+                *pane = a;
+            }
+        };
+
         self.internal.layout.update(&|node| match node {
             Node::Split { .. } => {}
-            Node::Pane(pane) => {
-                if *pane == a {
-                    *node = Node::Pane(b);
-                } else if *pane == b {
-                    *node = Node::Pane(a);
-                }
-            }
+            Node::Pane(pane) => swap(pane),
+            Node::Tabs { panes, .. } => panes.iter_mut().for_each(swap),
         });
     }
 
@@ -321,6 +505,29 @@ impl<T> State<T> {
     }
 }
 
+/// An error produced when restoring a [`State`] from a [`Configuration`]
+/// with [`State::try_from_configuration`].
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum Error<K> {
+    /// The [`Configuration`] contains a pane that could not be restored.
+    #[error("the configuration contains an unknown pane: {0:?}")]
+    UnknownPane(K),
+
+    /// The [`Configuration`] contains a split with a ratio outside of
+    /// [0.0, 1.0].
+    #[error("the configuration contains an invalid split ratio: {0}")]
+    InvalidRatio(f32),
+
+    /// The [`Configuration`] contains a group of tabs with an active index
+    /// out of bounds.
+    #[error("the configuration contains an invalid active tab: {0}")]
+    InvalidTabs(usize),
+
+    /// The [`Configuration`] contains a group of tabs without any panes.
+    #[error("the configuration contains an empty group of tabs")]
+    EmptyTabs,
+}
+
 /// The internal state of a [`PaneGrid`].
 ///
 /// [`PaneGrid`]: super::PaneGrid
@@ -335,49 +542,95 @@ impl Internal {
     /// Initializes the [`Internal`] state of a [`PaneGrid`] from a
     /// [`Configuration`].
     ///
+    /// If the [`Configuration`] has no panes at all, a single pane with the
+    /// default state is added.
+    ///
     /// [`PaneGrid`]: super::PaneGrid
     pub fn from_configuration<T>(
         panes: &mut BTreeMap<Pane, T>,
         content: Configuration<T>,
         next_id: usize,
-    ) -> Self {
-        let (layout, last_id) = match content {
+    ) -> Self
+    where
+        T: Default,
+    {
+        let (layout, last_id) = Self::node(panes, content, next_id).unwrap_or_else(|| {
+            let pane = Pane(next_id);
+            let _ = panes.insert(pane, T::default());
+
+            (Node::Pane(pane), next_id + 1)
+        });
+
+        Self {
+            layout,
+            last_id,
+            maximized: None,
+        }
+    }
+
+    /// Builds the [`Node`] of a [`Configuration`], dropping any empty group
+    /// of tabs; so its sibling takes the whole region of their split.
+    fn node<T>(
+        panes: &mut BTreeMap<Pane, T>,
+        content: Configuration<T>,
+        next_id: usize,
+    ) -> Option<(Node, usize)> {
+        match content {
             Configuration::Split { axis, ratio, a, b } => {
-                let Internal {
-                    layout: a,
-                    last_id: next_id,
-                    ..
-                } = Self::from_configuration(panes, *a, next_id);
-
-                let Internal {
-                    layout: b,
-                    last_id: next_id,
-                    ..
-                } = Self::from_configuration(panes, *b, next_id);
-
-                (
-                    Node::Split {
-                        id: Split(next_id),
-                        axis,
-                        ratio,
-                        a: Box::new(a),
-                        b: Box::new(b),
-                    },
-                    next_id + 1,
-                )
+                let a = Self::node(panes, *a, next_id);
+                let next_id = a.as_ref().map_or(next_id, |(_, last_id)| *last_id);
+
+                let b = Self::node(panes, *b, next_id);
+                let next_id = b.as_ref().map_or(next_id, |(_, last_id)| *last_id);
+
+                match (a, b) {
+                    (Some((a, _)), Some((b, _))) => Some((
+                        Node::Split {
+                            id: Split(next_id),
+                            axis,
+                            ratio,
+                            a: Box::new(a),
+                            b: Box::new(b),
+                        },
+                        next_id + 1,
+                    )),
+                    (Some(remaining), None) | (None, Some(remaining)) => Some(remaining),
+                    (None, None) => None,
+                }
             }
             Configuration::Pane(state) => {
                 let id = Pane(next_id);
                 let _ = panes.insert(id, state);
 
-                (Node::Pane(id), next_id + 1)
+                Some((Node::Pane(id), next_id + 1))
             }
-        };
+            Configuration::Tabs {
+                panes: tabs,
+                active,
+            } => {
+                let ids: Vec<_> = (next_id..).map(Pane).take(tabs.len()).collect();
+                let last_id = next_id + ids.len();
+
+                panes.extend(ids.iter().copied().zip(tabs));
+
+                let layout = match ids.as_slice() {
+                    [] => return None,
+                    [pane] => Node::Pane(*pane),
+                    _ => Node::Tabs {
+                        active: active.min(ids.len() - 1),
+                        panes: ids,
+                    },
+                };
 
-        Self {
-            layout,
-            last_id,
-            maximized: None,
+                Some((layout, last_id))
+            }
+        }
+    }
+
+    pub(super) fn is_visible(&self, pane: Pane) -> bool {
+        match self.maximized {
+            Some(maximized) => pane == maximized,
+            None => self.layout.is_visible(pane),
         }
     }
 
@@ -440,3 +693,190 @@ impl Action {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configurations_restore_panes_by_key() {
+        let (mut state, editor) = State::new("editor");
+        let (terminal, split) = state.split(Axis::Horizontal, editor, "terminal").unwrap();
+        let _ = state.split(Axis::Vertical, terminal, "logs").unwrap();
+        state.resize(split, 0.7);
+
+        let configuration = state.configuration(|_, name| name.to_string());
+
+        assert_eq!(
+            configuration,
+            Configuration::Split {
+                axis: Axis::Horizontal,
+                ratio: 0.7,
+                a: Box::new(Configuration::Pane("editor".to_owned())),
+                b: Box::new(Configuration::Split {
+                    axis: Axis::Vertical,
+                    ratio: 0.5,
+                    a: Box::new(Configuration::Pane("terminal".to_owned())),
+                    b: Box::new(Configuration::Pane("logs".to_owned())),
+                }),
+            }
+        );
+
+        let known = ["editor", "terminal"];
+        let restore = |key: &String| known.into_iter().find(|name| name == key);
+
+        assert_eq!(
+            State::try_from_configuration(configuration.clone(), restore).err(),
+            Some(Error::UnknownPane("logs".to_owned()))
+        );
+
+        let restored = State::try_from_configuration(
+            configuration
+                .retain(|key| known.contains(&key.as_str()))
+                .unwrap(),
+            restore,
+        )
+        .unwrap();
+
+        assert_eq!(
+            restored.configuration(|_, name| *name),
+            Configuration::Split {
+                axis: Axis::Horizontal,
+                ratio: 0.7,
+                a: Box::new(Configuration::Pane("editor")),
+                b: Box::new(Configuration::Pane("terminal")),
+            }
+        );
+    }
+
+    #[test]
+    fn stacked_panes_form_tab_groups() {
+        let (mut state, editor) = State::new("editor");
+        let (terminal, _) = state.split(Axis::Horizontal, editor, "terminal").unwrap();
+        let (logs, _) = state.split(Axis::Vertical, terminal, "logs").unwrap();
+
+        state.stack(terminal, editor);
+        state.stack(logs, editor);
+
+        assert_eq!(state.tabs(editor), Some(&[editor, terminal, logs][..]));
+        assert!(state.is_visible(logs));
+        assert!(!state.is_visible(editor));
+
+        state.activate(terminal);
+
+        assert_eq!(
+            state.configuration(|_, name| *name),
+            Configuration::Tabs {
+                panes: vec!["editor", "terminal", "logs"],
+                active: 1,
+            }
+        );
+
+        let _ = state.close(terminal).unwrap();
+        let _ = state.close(logs).unwrap();
+
+        assert_eq!(state.tabs(editor), None);
+        assert!(state.is_visible(editor));
+        assert_eq!(
+            state.configuration(|_, name| *name),
+            Configuration::Pane("editor")
+        );
+    }
+
+    #[test]
+    fn panes_dropped_on_tabs_are_stacked() {
+        let (mut state, editor) = State::new("editor");
+        let (terminal, _) = state.split(Axis::Horizontal, editor, "terminal").unwrap();
+        let (logs, _) = state.split(Axis::Vertical, terminal, "logs").unwrap();
+
+        state.drop(terminal, Target::Pane(editor, Region::Tab));
+
+        assert_eq!(state.tabs(editor), Some(&[editor, terminal][..]));
+        assert!(state.is_visible(terminal));
+
+        // A hidden tab can be dragged out of its group, too
+        state.drop(editor, Target::Pane(logs, Region::Tab));
+
+        assert_eq!(state.tabs(terminal), None);
+        assert_eq!(state.tabs(logs), Some(&[logs, editor][..]));
+        assert_eq!(
+            state.configuration(|_, name| *name),
+            Configuration::Split {
+                axis: Axis::Horizontal,
+                ratio: 0.5,
+                a: Box::new(Configuration::Pane("terminal")),
+                b: Box::new(Configuration::Tabs {
+                    panes: vec!["logs", "editor"],
+                    active: 1,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn closing_tabs_keeps_the_active_one() {
+        let mut state = State::with_configuration(Configuration::Tabs {
+            panes: vec!["a", "b", "c", "d"],
+            active: 2,
+        });
+        let [a, b, c, d] = [Pane(0), Pane(1), Pane(2), Pane(3)];
+
+        assert_eq!(state.close(b).map(|(_, sibling)| sibling), Some(c));
+        assert!(state.is_visible(c));
+
+        assert_eq!(state.close(c).map(|(_, sibling)| sibling), Some(d));
+        assert!(state.is_visible(d));
+
+        assert_eq!(state.close(d).map(|(_, sibling)| sibling), Some(a));
+        assert_eq!(
+            state.configuration(|_, name| *name),
+            Configuration::Pane("a")
+        );
+    }
+
+    #[test]
+    fn empty_tab_groups_are_invalid() {
+        let configuration = Configuration::Split {
+            axis: Axis::Vertical,
+            ratio: 0.5,
+            a: Box::new(Configuration::Tabs {
+                panes: Vec::new(),
+                active: 0,
+            }),
+            b: Box::new(Configuration::Pane("editor")),
+        };
+
+        assert_eq!(
+            State::try_from_configuration(configuration.clone(), |name| Some(*name)).err(),
+            Some(Error::EmptyTabs)
+        );
+
+        let state = State::with_configuration(configuration);
+
+        assert_eq!(
+            state.configuration(|_, name| *name),
+            Configuration::Pane("editor")
+        );
+    }
+
+    #[test]
+    fn configurations_without_panes_start_with_a_default_pane() {
+        let configuration = Configuration::Tabs {
+            panes: Vec::new(),
+            active: 0,
+        };
+
+        assert_eq!(
+            State::<&str>::try_from_configuration(configuration.clone(), |name| Some(*name)).err(),
+            Some(Error::EmptyTabs)
+        );
+
+        let state = State::<&str>::with_configuration(configuration);
+
+        assert_eq!(state.len(), 1);
+        assert_eq!(
+            state.configuration(|_, name| *name),
+            Configuration::Pane("")
+        );
+    }
+}
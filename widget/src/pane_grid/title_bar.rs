@@ -1,3 +1,4 @@
+use crate::Row;
 use crate::container;
 use crate::core::layout;
 use crate::core::mouse;
@@ -7,6 +8,7 @@ use crate::core::widget::{self, Tree};
 use crate::core::{
     self, Clipboard, Element, Event, Layout, Padding, Point, Rectangle, Shell, Size, Vector,
 };
+use crate::pane_grid::Pane;
 use crate::pane_grid::controls::Controls;
 
 /// The title bar of a [`Pane`].
@@ -19,6 +21,7 @@ where
 {
     content: Element<'a, Message, Theme, Renderer>,
     controls: Option<Controls<'a, Message, Theme, Renderer>>,
+    tabs: Vec<Pane>,
     padding: Padding,
     always_show_controls: bool,
     class: Theme::Class<'a>,
@@ -34,12 +37,43 @@ where
         Self {
             content: content.into(),
             controls: None,
+            tabs: Vec::new(),
             padding: Padding::ZERO,
             always_show_controls: false,
             class: Theme::default(),
         }
     }
 
+    /// Creates a new [`TitleBar`] with a strip of tabs; one for each of the
+    /// given panes, displaying the given label.
+    ///
+    /// Clicking a tab produces the [`on_click`] message of the [`PaneGrid`]
+    /// for its [`Pane`], and dragging a tab picks its [`Pane`]; so tabs can be
+    /// moved around like any other [`Pane`].
+    ///
+    /// Normally, the tabs are obtained with [`State::tabs`].
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`on_click`]: super::PaneGrid::on_click
+    /// [`State::tabs`]: super::State::tabs
+    pub fn tabs<Label>(tabs: impl IntoIterator<Item = (Pane, Label)>) -> Self
+    where
+        Label: Into<Element<'a, Message, Theme, Renderer>>,
+        Message: 'a,
+        Theme: 'a,
+        Renderer: 'a,
+    {
+        let (tabs, labels): (Vec<_>, Vec<_>) = tabs
+            .into_iter()
+            .map(|(pane, label)| (pane, label.into()))
+            .unzip();
+
+        Self {
+            tabs,
+            ..Self::new(Row::with_children(labels))
+        }
+    }
+
     /// Sets the controls of the [`TitleBar`].
     pub fn controls(mut self, controls: impl Into<Controls<'a, Message, Theme, Renderer>>) -> Self {
         self.controls = Some(controls.into());
@@ -217,6 +251,32 @@ where
         }
     }
 
+    /// Returns the [`Pane`] of the tab under the mouse cursor, if any.
+    pub(super) fn tab_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<Pane> {
+        self.tab_layouts(layout)
+            .find(|(_, layout)| layout.bounds().contains(cursor_position))
+            .map(|(pane, _)| pane)
+    }
+
+    /// Returns the bounds of the tab of the given [`Pane`], if any.
+    pub(super) fn tab_bounds(&self, layout: Layout<'_>, pane: Pane) -> Option<Rectangle> {
+        self.tab_layouts(layout)
+            .find(|(tab, _)| *tab == pane)
+            .map(|(_, layout)| layout.bounds())
+    }
+
+    fn tab_layouts<'b>(&'b self, layout: Layout<'b>) -> impl Iterator<Item = (Pane, Layout<'b>)> {
+        let title_layout = layout
+            .children()
+            .next()
+            .and_then(|padded| padded.children().next());
+
+        self.tabs
+            .iter()
+            .copied()
+            .zip(title_layout.into_iter().flat_map(Layout::children))
+    }
+
     /// Returns whether the mouse cursor is over the pick area of the
     /// [`TitleBar`] or not.
     ///
@@ -62,6 +62,7 @@
 //! ```
 use crate::core::alignment;
 use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
@@ -69,6 +70,9 @@ use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -150,6 +154,7 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Option<widget::Id>,
     on_select: Box<dyn Fn(T) -> Message + 'a>,
     on_open: Option<Message>,
     on_close: Option<Message>,
@@ -182,6 +187,7 @@ where
     /// selected value, and the message to produce when an option is selected.
     pub fn new(options: L, selected: Option<V>, on_select: impl Fn(T) -> Message + 'a) -> Self {
         Self {
+            id: None,
             on_select: Box::new(on_select),
             on_open: None,
             on_close: None,
@@ -202,6 +208,12 @@ where
         }
     }
 
+    /// Sets the [`widget::Id`] of the [`PickList`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the placeholder of the [`PickList`].
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = Some(placeholder.into());
@@ -408,6 +420,31 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let mut accessible = Accessible::new(Role::ComboBox)
+            .expanded(state.is_open)
+            .focused(state.is_focused);
+
+        if let Some(placeholder) = &self.placeholder {
+            accessible = accessible.label(placeholder.as_str());
+        }
+
+        if let Some(selected) = &self.selected {
+            accessible = accessible.value(selected.borrow().to_string());
+        }
+
+        operation.accessible(self.id.as_ref(), layout.bounds(), &accessible);
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
     fn update(
         &mut self,
         tree: &mut Tree,
@@ -424,6 +461,8 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
                 if state.is_open {
                     // Event wasn't processed by overlay, so cursor was clicked either outside its
                     // bounds or on the drop-down, either way we close the overlay.
@@ -493,6 +532,26 @@ where
                     shell.capture_event();
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(key::Named::Space | key::Named::Enter | key::Named::ArrowDown),
+                ..
+            }) if state.is_focused && !state.is_open => {
+                let selected = self.selected.as_ref().map(Borrow::borrow);
+
+                state.is_open = true;
+                state.hovered_option = self
+                    .options
+                    .borrow()
+                    .iter()
+                    .position(|option| Some(option) == selected);
+
+                if let Some(on_open) = &self.on_open {
+                    shell.publish(on_open.clone());
+                }
+
+                shell.capture_event();
+            }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 state.keyboard_modifiers = *modifiers;
             }
@@ -504,7 +563,7 @@ where
 
             if state.is_open {
                 Status::Opened { is_hovered }
-            } else if is_hovered {
+            } else if is_hovered || state.is_focused {
                 Status::Hovered
             } else {
                 Status::Active
@@ -570,67 +629,15 @@ where
             style.background,
         );
 
-        let handle = match &self.handle {
-            Handle::Arrow { size } => Some((
-                Renderer::ICON_FONT,
-                Renderer::ARROW_DOWN_ICON,
-                *size,
-                text::LineHeight::default(),
-                text::Shaping::Basic,
-            )),
-            Handle::Static(Icon {
-                font,
-                code_point,
-                size,
-                line_height,
-                shaping,
-            }) => Some((*font, *code_point, *size, *line_height, *shaping)),
-            Handle::Dynamic { open, closed } => {
-                if state.is_open {
-                    Some((
-                        open.font,
-                        open.code_point,
-                        open.size,
-                        open.line_height,
-                        open.shaping,
-                    ))
-                } else {
-                    Some((
-                        closed.font,
-                        closed.code_point,
-                        closed.size,
-                        closed.line_height,
-                        closed.shaping,
-                    ))
-                }
-            }
-            Handle::None => None,
-        };
-
-        if let Some((font, code_point, size, line_height, shaping)) = handle {
-            let size = size.unwrap_or_else(|| renderer.default_size());
-
-            renderer.fill_text(
-                Text {
-                    content: code_point.to_string(),
-                    size,
-                    line_height,
-                    font,
-                    bounds: Size::new(bounds.width, f32::from(line_height.to_absolute(size))),
-                    align_x: text::Alignment::Right,
-                    align_y: alignment::Vertical::Center,
-                    shaping,
-                    wrapping: text::Wrapping::default(),
-                    hint_factor: None,
-                },
-                Point::new(
-                    bounds.x + bounds.width - self.padding.right,
-                    bounds.center_y(),
-                ),
-                style.handle_color,
-                *viewport,
-            );
-        }
+        draw_handle(
+            renderer,
+            &self.handle,
+            state.is_open,
+            bounds,
+            self.padding,
+            style.handle_color,
+            viewport,
+        );
 
         let label = selected.map(ToString::to_string);
 
@@ -728,11 +735,84 @@ where
     }
 }
 
+/// Draws the [`Handle`] of a pick list field with the given `bounds`.
+pub(crate) fn draw_handle<Renderer>(
+    renderer: &mut Renderer,
+    handle: &Handle<Renderer::Font>,
+    is_open: bool,
+    bounds: Rectangle,
+    padding: Padding,
+    color: Color,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let handle = match handle {
+        Handle::Arrow { size } => Some((
+            Renderer::ICON_FONT,
+            Renderer::ARROW_DOWN_ICON,
+            *size,
+            text::LineHeight::default(),
+            text::Shaping::Basic,
+        )),
+        Handle::Static(Icon {
+            font,
+            code_point,
+            size,
+            line_height,
+            shaping,
+        }) => Some((*font, *code_point, *size, *line_height, *shaping)),
+        Handle::Dynamic { open, closed } => {
+            if is_open {
+                Some((
+                    open.font,
+                    open.code_point,
+                    open.size,
+                    open.line_height,
+                    open.shaping,
+                ))
+            } else {
+                Some((
+                    closed.font,
+                    closed.code_point,
+                    closed.size,
+                    closed.line_height,
+                    closed.shaping,
+                ))
+            }
+        }
+        Handle::None => None,
+    };
+
+    if let Some((font, code_point, size, line_height, shaping)) = handle {
+        let size = size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.fill_text(
+            Text {
+                content: code_point.to_string(),
+                size,
+                line_height,
+                font,
+                bounds: Size::new(bounds.width, f32::from(line_height.to_absolute(size))),
+                align_x: text::Alignment::Right,
+                align_y: alignment::Vertical::Center,
+                shaping,
+                wrapping: text::Wrapping::default(),
+                hint_factor: None,
+            },
+            Point::new(bounds.x + bounds.width - padding.right, bounds.center_y()),
+            color,
+            *viewport,
+        );
+    }
+}
+
 #[derive(Debug)]
 struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
@@ -745,6 +825,7 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: false,
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
@@ -758,6 +839,20 @@ impl<P: text::Paragraph> Default for State<P> {
     }
 }
 
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The handle to the right side of the [`PickList`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Handle<Font> {
@@ -817,7 +912,7 @@ pub enum Status {
 }
 
 /// The appearance of a pick list.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The text [`Color`] of the pick list.
     pub text_color: Color,
@@ -23,7 +23,8 @@ use crate::core::border::{self, Border};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::operation::accessibility::{Accessible, Role, Value};
+use crate::core::widget::{Operation, Tree};
 use crate::core::{
     self, Background, Color, Element, Layout, Length, Rectangle, Size, Theme, Widget,
 };
@@ -162,6 +163,25 @@ where
         layout::atomic(limits, self.width(), self.height())
     }
 
+    fn operate(
+        &mut self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            &Accessible::new(Role::ProgressBar).value(Value::Number {
+                value: f64::from(self.value),
+                min: f64::from(*self.range.start()),
+                max: f64::from(*self.range.end()),
+                step: None,
+            }),
+        );
+    }
+
     fn draw(
         &self,
         _tree: &Tree,
@@ -240,7 +260,7 @@ where
 }
 
 /// The appearance of a progress bar.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the progress bar.
     pub background: Background,
@@ -58,12 +58,16 @@
 //! ```
 use crate::core::alignment;
 use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::operation::{self, Focusable, Operation, Outcome};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -71,8 +75,14 @@ use crate::core::{
     Theme, Widget,
 };
 
+use std::any::Any;
+
 /// A circular button representing a choice.
 ///
+/// A [`Radio`] button can be focused with the keyboard and selected by
+/// pressing Space or Enter. The arrow keys move the focus between the
+/// consecutive [`Radio`] buttons of a group, selecting the newly focused one.
+///
 /// # Example
 /// ```no_run
 /// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
@@ -134,6 +144,7 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Option<widget::Id>,
     is_selected: bool,
     on_click: Message,
     label: String,
@@ -175,6 +186,7 @@ where
         F: FnOnce(V) -> Message,
     {
         Radio {
+            id: None,
             is_selected: Some(value) == selected,
             on_click: f(value),
             label: label.into(),
@@ -191,6 +203,12 @@ where
         }
     }
 
+    /// Sets the [`widget::Id`] of the [`Radio`] button.
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the size of the [`Radio`] button.
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.size = size.into().0;
@@ -258,6 +276,38 @@ where
     }
 }
 
+struct State<P: text::Paragraph> {
+    focus: Focus,
+    text: widget::text::State<P>,
+}
+
+/// The focus of a [`Radio`] button.
+///
+/// It is exposed to operations as custom state, so the buttons of a group
+/// can be told apart from other focusable widgets.
+#[derive(Debug, Clone, Copy, Default)]
+struct Focus {
+    is_focused: bool,
+    /// Whether the [`Radio`] button was focused with the arrow keys, and
+    /// must be selected on its next update.
+    is_selecting: bool,
+}
+
+impl Focusable for Focus {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_selecting = false;
+    }
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Radio<'_, Message, Theme, Renderer>
 where
@@ -266,11 +316,14 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph> {
+            focus: Focus::default(),
+            text: widget::text::State::default(),
+        })
     }
 
     fn size(&self) -> Size<Length> {
@@ -291,12 +344,10 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.text,
                     renderer,
                     limits,
                     &self.label,
@@ -316,9 +367,30 @@ where
         )
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.accessible(
+            self.id.as_ref(),
+            layout.bounds(),
+            &Accessible::new(Role::RadioButton)
+                .label(self.label.as_str())
+                .checked(self.is_selected)
+                .focused(state.focus.is_focused),
+        );
+        operation.custom(self.id.as_ref(), layout.bounds(), &mut state.focus);
+        operation.focusable(self.id.as_ref(), layout.bounds(), &mut state.focus);
+    }
+
     fn update(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -327,14 +399,46 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if std::mem::take(&mut state.focus.is_selecting) {
+            shell.publish(self.on_click.clone());
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.focus.is_focused = false;
+
                 if cursor.is_over(layout.bounds()) {
                     shell.publish(self.on_click.clone());
                     shell.capture_event();
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                repeat,
+                ..
+            }) if state.focus.is_focused => match named {
+                key::Named::Space | key::Named::Enter => {
+                    if !repeat {
+                        shell.publish(self.on_click.clone());
+                    }
+
+                    shell.capture_event();
+                }
+                key::Named::ArrowDown | key::Named::ArrowRight => {
+                    shell.request_operation(focus_next());
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+                key::Named::ArrowUp | key::Named::ArrowLeft => {
+                    shell.request_operation(focus_previous());
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+                _ => {}
+            },
             _ => {}
         }
 
@@ -342,7 +446,9 @@ where
             let is_mouse_over = cursor.is_over(layout.bounds());
             let is_selected = self.is_selected;
 
-            if is_mouse_over {
+            if state.focus.is_focused {
+                Status::Focused { is_selected }
+            } else if is_mouse_over {
                 Status::Hovered { is_selected }
             } else {
                 Status::Active { is_selected }
@@ -432,13 +538,13 @@ where
 
         {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> = tree.state.downcast_ref();
+            let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout.bounds(),
-                state.raw(),
+                state.text.raw(),
                 crate::text::Style {
                     color: style.text_color,
                 },
@@ -460,6 +566,131 @@ where
     }
 }
 
+/// The focused [`Radio`] button of a group and the one that should be
+/// focused next, as indices in focus order.
+#[derive(Debug, Clone, Copy)]
+struct Group {
+    focused: usize,
+    target: usize,
+}
+
+/// Produces an [`Operation`] that finds the group of consecutive [`Radio`]
+/// buttons containing the focused one, and picks the next or previous button
+/// of the group; wrapping around its ends.
+fn find_group(forward: bool) -> impl Operation<Group> {
+    struct FindGroup {
+        forward: bool,
+        is_radio: bool,
+        radios: Vec<bool>,
+        focused: Option<usize>,
+    }
+
+    impl Operation<Group> for FindGroup {
+        fn custom(&mut self, _id: Option<&widget::Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            self.is_radio = state.is::<Focus>();
+        }
+
+        fn focusable(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            if state.is_focused() {
+                self.focused = Some(self.radios.len());
+            }
+
+            self.radios.push(std::mem::take(&mut self.is_radio));
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Group>)) {
+            operate(self);
+        }
+
+        fn finish(&self) -> Outcome<Group> {
+            let Some(focused) = self.focused.filter(|focused| self.radios[*focused]) else {
+                return Outcome::None;
+            };
+
+            let first = self.radios[..focused]
+                .iter()
+                .rposition(|is_radio| !is_radio)
+                .map_or(0, |other| other + 1);
+
+            let last = self.radios[focused..]
+                .iter()
+                .position(|is_radio| !is_radio)
+                .map_or(self.radios.len(), |other| focused + other)
+                - 1;
+
+            let target = if self.forward {
+                if focused == last { first } else { focused + 1 }
+            } else if focused == first {
+                last
+            } else {
+                focused - 1
+            };
+
+            Outcome::Some(Group { focused, target })
+        }
+    }
+
+    FindGroup {
+        forward,
+        is_radio: false,
+        radios: Vec::new(),
+        focused: None,
+    }
+}
+
+/// Moves the focus to the target [`Radio`] button of a [`Group`], which
+/// gets selected on its next update.
+fn focus(group: Group) -> impl Operation {
+    struct MoveFocus {
+        group: Group,
+        current: usize,
+    }
+
+    impl Operation for MoveFocus {
+        fn custom(&mut self, _id: Option<&widget::Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if self.current == self.group.target
+                && let Some(focus) = state.downcast_mut::<Focus>()
+            {
+                focus.is_selecting = true;
+            }
+        }
+
+        fn focusable(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            if self.current == self.group.target {
+                state.focus();
+            } else if self.current == self.group.focused {
+                state.unfocus();
+            }
+
+            self.current += 1;
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+    }
+
+    MoveFocus { group, current: 0 }
+}
+
+fn focus_next() -> impl Operation {
+    operation::then(find_group(true), focus)
+}
+
+fn focus_previous() -> impl Operation {
+    operation::then(find_group(false), focus)
+}
+
 /// The possible status of a [`Radio`] button.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -473,10 +704,15 @@ pub enum Status {
         /// Indicates whether the [`Radio`] button is currently selected.
         is_selected: bool,
     },
+    /// The [`Radio`] button has keyboard focus.
+    Focused {
+        /// Indicates whether the [`Radio`] button is currently selected.
+        is_selected: bool,
+    },
 }
 
 /// The appearance of a radio button.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the radio button.
     pub background: Background,
@@ -536,5 +772,104 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             background: palette.primary.weak.color.into(),
             ..active
         },
+        Status::Focused { .. } => Style {
+            border_width: 2.0,
+            border_color: palette.background.base.text,
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Column;
+    use crate::core::clipboard;
+    use crate::core::time::Instant;
+    use crate::core::widget::operation::focusable;
+
+    fn key_pressed(named: key::Named) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modified_key: keyboard::Key::Named(named),
+            physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+            repeat: false,
+        })
+    }
+
+    #[test]
+    fn arrow_keys_select_the_focused_radio() {
+        let mut radios: Element<'_, char, Theme, ()> = Column::new()
+            .push(Radio::new("A", 'a', Some('a'), |choice| choice).id("a"))
+            .push(Radio::new("B", 'b', Some('a'), |choice| choice))
+            .push(Radio::new("C", 'c', Some('a'), |choice| choice))
+            .into();
+
+        let mut tree = Tree::new(&radios);
+        let bounds = Rectangle::with_size(Size::new(100.0, 100.0));
+
+        let node = radios.as_widget_mut().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, bounds.size()),
+        );
+
+        radios.as_widget_mut().operate(
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            &mut focusable::focus("a".into()),
+        );
+
+        let mut press = |named| {
+            let mut messages = Vec::new();
+
+            for event in [
+                key_pressed(named),
+                Event::Window(window::Event::RedrawRequested(Instant::now())),
+            ] {
+                let mut shell = Shell::new(&mut messages);
+
+                radios.as_widget_mut().update(
+                    &mut tree,
+                    &event,
+                    Layout::new(&node),
+                    mouse::Cursor::Unavailable,
+                    &(),
+                    &mut clipboard::Null,
+                    &mut shell,
+                    &bounds,
+                );
+
+                for mut operation in shell.take_operations() {
+                    loop {
+                        radios.as_widget_mut().operate(
+                            &mut tree,
+                            Layout::new(&node),
+                            &(),
+                            operation.as_mut(),
+                        );
+
+                        match operation.finish() {
+                            Outcome::Chain(next) => operation = next,
+                            _ => break,
+                        }
+                    }
+                }
+            }
+
+            messages
+        };
+
+        assert_eq!(press(key::Named::ArrowDown), ['b']);
+        assert_eq!(press(key::Named::ArrowRight), ['c']);
+
+        // The focus wraps around the ends of the group
+        assert_eq!(press(key::Named::ArrowDown), ['a']);
+        assert_eq!(press(key::Named::ArrowUp), ['c']);
     }
 }
@@ -31,6 +31,7 @@ use crate::core::text;
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -536,6 +537,7 @@ where
         let translation = state.translation(self.direction, bounds, content_bounds);
 
         operation.scrollable(self.id.as_ref(), bounds, content_bounds, translation, state);
+        operation.accessible(self.id.as_ref(), bounds, &Accessible::new(Role::ScrollView));
 
         operation.traverse(&mut |operation| {
             self.content.as_widget_mut().operate(
@@ -2063,7 +2065,7 @@ pub enum Status {
 }
 
 /// The appearance of a scrollable.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`container::Style`] of a scrollable.
     pub container: container::Style,
@@ -2078,7 +2080,7 @@ pub struct Style {
 }
 
 /// The appearance of the scrollbar of a scrollable.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Rail {
     /// The [`Background`] of a scrollbar.
     pub background: Option<Background>,
@@ -2089,7 +2091,7 @@ pub struct Rail {
 }
 
 /// The appearance of the scroller of a scrollable.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Scroller {
     /// The [`Background`] of the scroller.
     pub background: Background,
@@ -2098,7 +2100,7 @@ pub struct Scroller {
 }
 
 /// The appearance of the autoscroll overlay of a scrollable.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AutoScroll {
     /// The [`Background`] of the [`AutoScroll`] overlay.
     pub background: Background,
@@ -2155,18 +2157,14 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         border: border::rounded(u32::MAX)
             .width(1)
             .color(palette.background.base.text.scale_alpha(0.8)),
-        shadow: Shadow {
-            color: Color::BLACK.scale_alpha(0.7),
-            offset: Vector::ZERO,
-            blur_radius: 2.0,
-        },
+        shadow: Shadow::new(Color::BLACK.scale_alpha(0.7), Vector::ZERO, 2.0),
         icon: palette.background.base.text.scale_alpha(0.8),
     };
 
     match status {
         Status::Active { .. } => Style {
             container: container::Style::default(),
-            vertical_rail: scrollbar,
+            vertical_rail: scrollbar.clone(),
             horizontal_rail: scrollbar,
             gap: None,
             auto_scroll,
@@ -2179,17 +2177,17 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             let hovered_scrollbar = Rail {
                 scroller: Scroller {
                     background: palette.primary.strong.color.into(),
-                    ..scrollbar.scroller
+                    ..scrollbar.scroller.clone()
                 },
-                ..scrollbar
+                ..scrollbar.clone()
             };
 
             Style {
                 container: container::Style::default(),
                 vertical_rail: if is_vertical_scrollbar_hovered {
-                    hovered_scrollbar
+                    hovered_scrollbar.clone()
                 } else {
-                    scrollbar
+                    scrollbar.clone()
                 },
                 horizontal_rail: if is_horizontal_scrollbar_hovered {
                     hovered_scrollbar
@@ -2208,17 +2206,17 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             let dragged_scrollbar = Rail {
                 scroller: Scroller {
                     background: palette.primary.base.color.into(),
-                    ..scrollbar.scroller
+                    ..scrollbar.scroller.clone()
                 },
-                ..scrollbar
+                ..scrollbar.clone()
             };
 
             Style {
                 container: container::Style::default(),
                 vertical_rail: if is_vertical_scrollbar_dragged {
-                    dragged_scrollbar
+                    dragged_scrollbar.clone()
                 } else {
-                    scrollbar
+                    scrollbar.clone()
                 },
                 horizontal_rail: if is_horizontal_scrollbar_dragged {
                     dragged_scrollbar
@@ -35,6 +35,8 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::Operation;
+use crate::core::widget::operation::accessibility::{Accessible, Role, Value};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -240,6 +242,25 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &mut self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            &Accessible::new(Role::Slider).value(Value::Number {
+                value: self.value.into(),
+                min: (*self.range.start()).into(),
+                max: (*self.range.end()).into(),
+                step: Some(self.step.into()),
+            }),
+        );
+    }
+
     fn update(
         &mut self,
         tree: &mut Tree,
@@ -562,7 +583,7 @@ pub enum Status {
 }
 
 /// The appearance of a slider.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The colors of the rail of the slider.
     pub rail: Rail,
@@ -582,7 +603,7 @@ impl Style {
 }
 
 /// The appearance of a slider rail
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Rail {
     /// The backgrounds of the rail of the slider.
     pub backgrounds: (Background, Background),
@@ -593,7 +614,7 @@ pub struct Rail {
 }
 
 /// The appearance of the handle of a slider.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Handle {
     /// The shape of the handle.
     pub shape: HandleShape,
@@ -1,14 +1,24 @@
 //! Display tables.
 use crate::core;
 use crate::core::alignment;
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::text;
 use crate::core::widget;
 use crate::core::{
-    Alignment, Background, Element, Layout, Length, Pixels, Rectangle, Size, Widget,
+    Alignment, Background, Color, Element, Layout, Length, Pixels, Rectangle, Size, Vector, Widget,
 };
+use crate::date_picker::fill_label;
+use crate::{Row, Space};
+
+/// The size of the sort indicator drawn in the header of a sortable [`Column`].
+const INDICATOR_SIZE: f32 = 12.0;
+
+/// The distance from a column edge at which its resize handle can be grabbed.
+const RESIZE_HANDLE: f32 = 4.0;
 
 /// Creates a new [`Table`] with the given columns and rows.
 ///
@@ -20,8 +30,9 @@ pub fn table<'a, 'b, T, Message, Theme, Renderer>(
 ) -> Table<'a, Message, Theme, Renderer>
 where
     T: Clone,
-    Theme: Catalog,
-    Renderer: core::Renderer,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
 {
     Table::new(columns, rows)
 }
@@ -44,6 +55,9 @@ where
         width: Length::Shrink,
         align_x: alignment::Horizontal::Left,
         align_y: alignment::Vertical::Top,
+        sorted: None,
+        on_sort: None,
+        on_resize: None,
     }
 }
 
@@ -52,7 +66,7 @@ pub struct Table<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
     Theme: Catalog,
 {
-    columns: Vec<Column_>,
+    columns: Vec<Column_<'a, Message>>,
     cells: Vec<Element<'a, Message, Theme, Renderer>>,
     width: Length,
     height: Length,
@@ -60,19 +74,26 @@ where
     padding_y: f32,
     separator_x: f32,
     separator_y: f32,
+    on_row_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_row_shift_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    selected: Vec<usize>,
+    sticky_header: bool,
     class: Theme::Class<'a>,
 }
 
-struct Column_ {
+struct Column_<'a, Message> {
     width: Length,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    sorted: Option<Direction>,
+    on_sort: Option<Box<dyn Fn(Direction) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
 }
 
 impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
 where
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     /// Creates a new [`Table`] with the given columns and rows.
     ///
@@ -84,6 +105,9 @@ where
     ) -> Self
     where
         T: Clone,
+        Message: 'a,
+        Theme: 'a,
+        Renderer: 'a,
     {
         let columns = columns.into_iter();
         let rows = rows.into_iter();
@@ -97,13 +121,29 @@ where
             .map(|column| {
                 width = width.enclose(column.width);
 
-                cells.push(column.header);
+                // Sortable headers reserve some room for the sort indicator
+                if column.on_sort.is_some() || column.sorted.is_some() {
+                    cells.push(
+                        Row::with_children([
+                            column.header,
+                            Space::new().width(INDICATOR_SIZE).into(),
+                        ])
+                        .spacing(INDICATOR_SIZE / 3.0)
+                        .align_y(Alignment::Center)
+                        .into(),
+                    );
+                } else {
+                    cells.push(column.header);
+                }
 
                 (
                     Column_ {
                         width: column.width,
                         align_x: column.align_x,
                         align_y: column.align_y,
+                        sorted: column.sorted,
+                        on_sort: column.on_sort,
+                        on_resize: column.on_resize,
                     },
                     column.view,
                 )
@@ -136,6 +176,10 @@ where
             padding_y: 5.0,
             separator_x: 1.0,
             separator_y: 1.0,
+            on_row_click: None,
+            on_row_shift_click: None,
+            selected: Vec::new(),
+            sticky_header: true,
             class: Theme::default(),
         }
     }
@@ -183,18 +227,232 @@ where
         self.separator_y = separator.into().0;
         self
     }
+
+    /// Sets the message that should be produced when a row of the [`Table`]
+    /// is clicked.
+    ///
+    /// The index of the row does not account for the header; i.e. the first
+    /// row of data has index `0`.
+    pub fn on_row_click(mut self, on_row_click: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_row_click = Some(Box::new(on_row_click));
+        self
+    }
+
+    /// Sets the message that should be produced when a row of the [`Table`]
+    /// is clicked while holding the shift key.
+    ///
+    /// This is normally used to extend a selection. If unset, shift-clicks
+    /// will produce the message of [`on_row_click`](Self::on_row_click).
+    pub fn on_row_shift_click(
+        mut self,
+        on_row_shift_click: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_row_shift_click = Some(Box::new(on_row_shift_click));
+        self
+    }
+
+    /// Sets the rows of the [`Table`] that are currently selected.
+    pub fn selected(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = rows.into_iter().collect();
+        self.selected.sort_unstable();
+        self.selected.dedup();
+        self
+    }
+
+    /// Sets whether the header of the [`Table`] should stick to the top of
+    /// the viewport when scrolled out of view (e.g. inside a `scrollable`).
+    ///
+    /// By default, it is enabled.
+    pub fn sticky_header(mut self, sticky_header: bool) -> Self {
+        self.sticky_header = sticky_header;
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Table`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the height of the header row, including its padding.
+    fn header_height(&self, state: &State) -> f32 {
+        state
+            .rows
+            .first()
+            .map(|height| height + self.padding_y * 2.0)
+            .unwrap_or_default()
+    }
+
+    /// Returns how far the header must be moved down to stay visible in the
+    /// given viewport.
+    fn sticky_offset(&self, state: &State, bounds: Rectangle, viewport: &Rectangle) -> f32 {
+        if !self.sticky_header || state.rows.len() < 2 {
+            return 0.0;
+        }
+
+        let header = self.header_height(state) + self.separator_y;
+
+        (viewport.y - bounds.y).clamp(0.0, (bounds.height - header).max(0.0))
+    }
+
+    /// Finds the interactive part of the [`Table`] under the cursor.
+    fn target(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        offset: f32,
+    ) -> Option<Target> {
+        let position = cursor.position_over(bounds)?;
+        let x = position.x - bounds.x;
+        let y = position.y - bounds.y;
+
+        if (offset..offset + self.header_height(state)).contains(&y) {
+            let columns = bands(&state.columns, self.padding_x, self.separator_x);
+
+            for (i, (_, end)) in columns.clone().enumerate() {
+                if self.columns[i].on_resize.is_some()
+                    && (x - end - self.separator_x / 2.0).abs() <= RESIZE_HANDLE
+                {
+                    return Some(Target::Resize(i));
+                }
+            }
+
+            return columns
+                .enumerate()
+                .find(|(i, (start, end))| {
+                    self.columns[*i].on_sort.is_some() && (*start..*end).contains(&x)
+                })
+                .map(|(i, _)| Target::Sort(i));
+        }
+
+        bands(&state.rows, self.padding_y, self.separator_y)
+            .enumerate()
+            .skip(1)
+            .find(|(_, (start, end))| (*start..end + self.separator_y).contains(&y))
+            .map(|(i, _)| Target::Row(i - 1))
+    }
+
+    fn draw_separators_x(
+        &self,
+        renderer: &mut Renderer,
+        state: &State,
+        bounds: Rectangle,
+        background: &Background,
+    ) {
+        for (_, end) in bands(&state.columns, self.padding_x, self.separator_x)
+            .take(state.columns.len().saturating_sub(1))
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + end,
+                        width: self.separator_x,
+                        ..bounds
+                    },
+                    snap: true,
+                    ..renderer::Quad::default()
+                },
+                background.clone(),
+            );
+        }
+    }
+
+    fn draw_indicators(
+        &self,
+        renderer: &mut Renderer,
+        state: &State,
+        bounds: Rectangle,
+        style: &Style,
+    ) {
+        let Some(height) = state.rows.first() else {
+            return;
+        };
+
+        for (column, (_, end)) in
+            self.columns
+                .iter()
+                .zip(bands(&state.columns, self.padding_x, self.separator_x))
+        {
+            let Some(direction) = column.sorted else {
+                continue;
+            };
+
+            let icon = match direction {
+                Direction::Ascending => Renderer::SCROLL_UP_ICON,
+                Direction::Descending => Renderer::SCROLL_DOWN_ICON,
+            };
+
+            fill_label(
+                renderer,
+                &icon.to_string(),
+                Rectangle {
+                    x: bounds.x + end - self.padding_x - INDICATOR_SIZE,
+                    y: bounds.y + self.padding_y,
+                    width: INDICATOR_SIZE,
+                    height: *height,
+                },
+                Pixels(INDICATOR_SIZE),
+                Renderer::ICON_FONT,
+                style.sort_indicator,
+            );
+        }
+    }
 }
 
-struct Metrics {
+struct State {
     columns: Vec<f32>,
     rows: Vec<f32>,
+    hovered_row: Option<usize>,
+    resizing: Option<Resizing>,
+    modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Resize(usize),
+    Sort(usize),
+    Row(usize),
+}
+
+/// Returns the start and end of every cell band along an axis, padding included.
+fn bands(
+    sizes: &[f32],
+    padding: f32,
+    separator: f32,
+) -> impl Iterator<Item = (f32, f32)> + Clone + '_ {
+    sizes.iter().scan(0.0, move |start, size| {
+        let band = (*start, *start + size + padding * 2.0);
+        *start = band.1 + separator;
+
+        Some(band)
+    })
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Table<'a, Message, Theme, Renderer>
 where
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     fn size(&self) -> Size<Length> {
         Size {
@@ -204,13 +462,16 @@ where
     }
 
     fn tag(&self) -> widget::tree::Tag {
-        widget::tree::Tag::of::<Metrics>()
+        widget::tree::Tag::of::<State>()
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(Metrics {
+        widget::tree::State::new(State {
             columns: Vec::new(),
             rows: Vec::new(),
+            hovered_row: None,
+            resizing: None,
+            modifiers: keyboard::Modifiers::default(),
         })
     }
 
@@ -231,7 +492,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let metrics = tree.state.downcast_mut::<Metrics>();
+        let metrics = tree.state.downcast_mut::<State>();
         let columns = self.columns.len();
         let rows = self.cells.len() / columns;
 
@@ -456,15 +717,143 @@ where
         shell: &mut core::Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        for ((cell, tree), layout) in self
+        let widget::Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let offset = self.sticky_offset(state, bounds, viewport);
+        let header = self.columns.len();
+
+        let over_header = offset > 0.0
+            && cursor.is_over(Rectangle {
+                y: bounds.y + offset,
+                height: self.header_height(state),
+                ..bounds
+            });
+
+        let header_viewport = Rectangle {
+            y: viewport.y - offset,
+            ..*viewport
+        };
+
+        for (i, ((cell, tree), layout)) in self
             .cells
             .iter_mut()
-            .zip(&mut tree.children)
+            .zip(children.iter_mut())
             .zip(layout.children())
+            .enumerate()
         {
-            cell.as_widget_mut().update(
-                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
-            );
+            if i < header {
+                cell.as_widget_mut().update(
+                    tree,
+                    event,
+                    layout,
+                    cursor - Vector::new(0.0, offset),
+                    renderer,
+                    clipboard,
+                    shell,
+                    &header_viewport,
+                );
+            } else {
+                cell.as_widget_mut().update(
+                    tree,
+                    event,
+                    layout,
+                    if over_header {
+                        mouse::Cursor::Unavailable
+                    } else {
+                        cursor
+                    },
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+        }
+
+        match event {
+            core::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            core::Event::Mouse(mouse::Event::CursorMoved { .. }) if state.resizing.is_some() => {
+                if let Some(resizing) = state.resizing
+                    && let Some(position) = cursor.land().position()
+                    && let Some(on_resize) = &self.columns[resizing.column].on_resize
+                {
+                    shell.publish(on_resize(
+                        (resizing.width + position.x - resizing.origin).max(0.0),
+                    ));
+                    shell.capture_event();
+                }
+            }
+            core::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.resizing.is_some() =>
+            {
+                state.resizing = None;
+                shell.capture_event();
+            }
+            core::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if !shell.is_event_captured() =>
+            {
+                match self.target(state, bounds, cursor, offset) {
+                    Some(Target::Resize(column)) => {
+                        if let Some(position) = cursor.position() {
+                            state.resizing = Some(Resizing {
+                                column,
+                                origin: position.x,
+                                width: state.columns[column],
+                            });
+
+                            shell.capture_event();
+                        }
+                    }
+                    Some(Target::Sort(column)) => {
+                        let column = &self.columns[column];
+
+                        if let Some(on_sort) = &column.on_sort {
+                            shell.publish(on_sort(
+                                column
+                                    .sorted
+                                    .map_or(Direction::Ascending, Direction::reverse),
+                            ));
+                            shell.capture_event();
+                        }
+                    }
+                    Some(Target::Row(row)) => {
+                        let on_click = if state.modifiers.shift() {
+                            self.on_row_shift_click
+                                .as_ref()
+                                .or(self.on_row_click.as_ref())
+                        } else {
+                            self.on_row_click.as_ref()
+                        };
+
+                        if let Some(on_click) = on_click {
+                            shell.publish(on_click(row));
+                            shell.capture_event();
+                        }
+                    }
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+
+        let hovered_row = if self.on_row_click.is_some() || self.on_row_shift_click.is_some() {
+            match self.target(state, bounds, cursor, offset) {
+                Some(Target::Row(row)) => Some(row),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        if hovered_row != state.hovered_row {
+            state.hovered_row = hovered_row;
+            shell.request_redraw();
         }
     }
 
@@ -473,68 +862,177 @@ where
         tree: &widget::Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        style: &renderer::Style,
+        defaults: &renderer::Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        for ((cell, state), layout) in self.cells.iter().zip(&tree.children).zip(layout.children())
-        {
-            cell.as_widget()
-                .draw(state, renderer, theme, style, layout, cursor, viewport);
-        }
-
+        let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
-        let metrics = tree.state.downcast_ref::<Metrics>();
         let style = theme.style(&self.class);
+        let offset = self.sticky_offset(state, bounds, viewport);
+        let header = self.columns.len();
+        let header_height = self.header_height(state);
+
+        for (i, (start, end)) in bands(&state.rows, self.padding_y, self.separator_y).enumerate() {
+            let background = if i == 0 {
+                style.header_background.as_ref()
+            } else if self.selected.binary_search(&(i - 1)).is_ok() {
+                style.row_selected_background.as_ref()
+            } else if state.hovered_row == Some(i - 1) {
+                style.row_hovered_background.as_ref()
+            } else {
+                None
+            };
 
-        if self.separator_x > 0.0 {
-            let mut x = self.padding_x;
-
-            for width in &metrics.columns[..metrics.columns.len().saturating_sub(1)] {
-                x += width + self.padding_x;
-
+            if let Some(background) = background {
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds: Rectangle {
-                            x: bounds.x + x,
-                            y: bounds.y,
-                            width: self.separator_x,
-                            height: bounds.height,
+                            y: bounds.y + start,
+                            height: end - start,
+                            ..bounds
                         },
                         snap: true,
                         ..renderer::Quad::default()
                     },
-                    style.separator_x,
+                    background.clone(),
                 );
-
-                x += self.separator_x + self.padding_x;
             }
         }
 
-        if self.separator_y > 0.0 {
-            let mut y = self.padding_y;
+        let header_bounds = Rectangle {
+            y: bounds.y + offset,
+            height: header_height,
+            ..bounds
+        };
+
+        let body_cursor = if offset > 0.0 && cursor.is_over(header_bounds) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        for (i, ((cell, state), layout)) in self
+            .cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            if offset > 0.0 && i < header {
+                continue;
+            }
 
-            for height in &metrics.rows[..metrics.rows.len().saturating_sub(1)] {
-                y += height + self.padding_y;
+            cell.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                defaults,
+                layout,
+                body_cursor,
+                viewport,
+            );
+        }
 
+        if self.separator_x > 0.0 {
+            self.draw_separators_x(renderer, state, bounds, &style.separator_x);
+        }
+
+        if self.separator_y > 0.0 {
+            for (_, end) in bands(&state.rows, self.padding_y, self.separator_y)
+                .take(state.rows.len().saturating_sub(1))
+            {
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds: Rectangle {
-                            x: bounds.x,
-                            y: bounds.y + y,
-                            width: bounds.width,
+                            y: bounds.y + end,
                             height: self.separator_y,
+                            ..bounds
                         },
                         snap: true,
                         ..renderer::Quad::default()
                     },
-                    style.separator_y,
+                    style.separator_y.clone(),
                 );
-
-                y += self.separator_y + self.padding_y;
             }
         }
+
+        if offset == 0.0 {
+            self.draw_indicators(renderer, state, bounds, &style);
+            return;
+        }
+
+        // The header is floating over the body; draw it on top
+        let header_viewport = Rectangle {
+            y: viewport.y - offset,
+            ..*viewport
+        };
+
+        renderer.with_layer(
+            Rectangle {
+                height: header_height + self.separator_y,
+                ..header_bounds
+            },
+            |renderer| {
+                renderer.with_translation(Vector::new(0.0, offset), |renderer| {
+                    let header_bounds = Rectangle {
+                        height: header_height,
+                        ..bounds
+                    };
+
+                    if let Some(background) = &style.header_background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: header_bounds,
+                                snap: true,
+                                ..renderer::Quad::default()
+                            },
+                            background.clone(),
+                        );
+                    }
+
+                    for ((cell, state), layout) in self
+                        .cells
+                        .iter()
+                        .zip(&tree.children)
+                        .zip(layout.children())
+                        .take(header)
+                    {
+                        cell.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            defaults,
+                            layout,
+                            cursor - Vector::new(0.0, offset),
+                            &header_viewport,
+                        );
+                    }
+
+                    if self.separator_x > 0.0 {
+                        self.draw_separators_x(renderer, state, header_bounds, &style.separator_x);
+                    }
+
+                    if self.separator_y > 0.0 {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    y: bounds.y + header_height,
+                                    height: self.separator_y,
+                                    ..bounds
+                                },
+                                snap: true,
+                                ..renderer::Quad::default()
+                            },
+                            style.separator_y.clone(),
+                        );
+                    }
+
+                    self.draw_indicators(renderer, state, bounds, &style);
+                });
+            },
+        );
     }
 
     fn mouse_interaction(
@@ -545,16 +1043,58 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.cells
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let offset = self.sticky_offset(state, bounds, viewport);
+        let target = self.target(state, bounds, cursor, offset);
+
+        if state.resizing.is_some() || matches!(target, Some(Target::Resize(_))) {
+            return mouse::Interaction::ResizingColumn;
+        }
+
+        let header = self.columns.len();
+        let over_header = offset > 0.0
+            && cursor.is_over(Rectangle {
+                y: bounds.y + offset,
+                height: self.header_height(state),
+                ..bounds
+            });
+
+        let header_viewport = Rectangle {
+            y: viewport.y - offset,
+            ..*viewport
+        };
+
+        let interaction = self
+            .cells
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
-            .map(|((cell, tree), layout)| {
-                cell.as_widget()
-                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            .enumerate()
+            .map(|(i, ((cell, tree), layout))| {
+                if i < header {
+                    cell.as_widget().mouse_interaction(
+                        tree,
+                        layout,
+                        cursor - Vector::new(0.0, offset),
+                        &header_viewport,
+                        renderer,
+                    )
+                } else if over_header {
+                    mouse::Interaction::None
+                } else {
+                    cell.as_widget()
+                        .mouse_interaction(tree, layout, cursor, viewport, renderer)
+                }
             })
             .max()
-            .unwrap_or_default()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::None && matches!(target, Some(Target::Sort(_))) {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
     }
 
     fn operate(
@@ -599,7 +1139,7 @@ impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
         Element::new(table)
@@ -613,6 +1153,9 @@ pub struct Column<'a, 'b, T, Message, Theme = crate::Theme, Renderer = crate::Re
     width: Length,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    sorted: Option<Direction>,
+    on_sort: Option<Box<dyn Fn(Direction) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
 }
 
 impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Renderer> {
@@ -633,15 +1176,73 @@ impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Rend
         self.align_y = alignment.into();
         self
     }
+
+    /// Sets the current sort [`Direction`] of the [`Column`], if any.
+    ///
+    /// A sorted [`Column`] displays an indicator in its header.
+    pub fn sorted(mut self, direction: impl Into<Option<Direction>>) -> Self {
+        self.sorted = direction.into();
+        self
+    }
+
+    /// Sets the message that should be produced when the header of the
+    /// [`Column`] is clicked.
+    ///
+    /// The closure receives the [`Direction`] the [`Column`] should be sorted
+    /// in next; that is, [`Direction::Ascending`] unless it is already sorted
+    /// in ascending order.
+    pub fn on_sort(mut self, on_sort: impl Fn(Direction) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Column`] is resized
+    /// by dragging the right edge of its header.
+    ///
+    /// The closure receives the new width of the [`Column`], which should be
+    /// applied with [`width`](Self::width).
+    pub fn on_resize(mut self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+}
+
+/// The sort direction of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Smallest values first.
+    Ascending,
+    /// Largest values first.
+    Descending,
+}
+
+impl Direction {
+    /// Returns the opposite [`Direction`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
 }
 
 /// The appearance of a [`Table`].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Style {
     /// The background color of the horizontal line separator between cells.
     pub separator_x: Background,
     /// The background color of the vertical line separator between cells.
     pub separator_y: Background,
+    /// The background of the header row.
+    ///
+    /// It should be opaque if the header is sticky.
+    pub header_background: Option<Background>,
+    /// The background of a clickable row when hovered.
+    pub row_hovered_background: Option<Background>,
+    /// The background of a selected row.
+    pub row_selected_background: Option<Background>,
+    /// The color of the sort indicator of a sorted column.
+    pub sort_indicator: Color,
 }
 
 /// The theme catalog of a [`Table`].
@@ -661,7 +1262,7 @@ pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
 
 impl<Theme> From<Style> for StyleFn<'_, Theme> {
     fn from(style: Style) -> Self {
-        Box::new(move |_theme| style)
+        Box::new(move |_theme| style.clone())
     }
 }
 
@@ -680,10 +1281,14 @@ impl Catalog for crate::Theme {
 /// The default style of a [`Table`].
 pub fn default(theme: &crate::Theme) -> Style {
     let palette = theme.extended_palette();
-    let separator = palette.background.strong.color.into();
+    let separator: Background = palette.background.strong.color.into();
 
     Style {
-        separator_x: separator,
+        separator_x: separator.clone(),
         separator_y: separator,
+        header_background: Some(palette.background.base.color.into()),
+        row_hovered_background: Some(palette.background.weaker.color.into()),
+        row_selected_background: Some(palette.primary.weak.color.scale_alpha(0.5).into()),
+        sort_indicator: palette.background.base.text,
     }
 }
@@ -0,0 +1,1029 @@
+//! Tabs let users switch between different views that share the same space.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{tabs, text};
+//!
+//! struct State {
+//!    documents: Vec<(usize, String)>,
+//!    active: usize,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Select(usize),
+//!     Close(usize),
+//!     Reorder(usize, usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     tabs(state.active, text!("Document {}", state.active))
+//!         .extend(
+//!             state
+//!                 .documents
+//!                 .iter()
+//!                 .map(|(id, name)| (*id, text(name).into())),
+//!         )
+//!         .on_select(Message::Select)
+//!         .on_close(Message::Close)
+//!         .on_reorder(Message::Reorder)
+//!         .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::operation::Operation;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector, Widget, widget,
+};
+
+/// A tab bar on top of the content of the active tab.
+///
+/// Tabs can be selected by clicking them or by pressing `Ctrl+Tab` and
+/// `Ctrl+Shift+Tab`. If there are too many tabs to fit, the tab bar can
+/// be scrolled with the mouse wheel.
+///
+/// Tabs can be closed with their close button or with the middle mouse
+/// button when [`on_close`] is set, and they can be reordered by dragging
+/// them when [`on_reorder`] is set.
+///
+/// [`on_close`]: Self::on_close
+/// [`on_reorder`]: Self::on_reorder
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{tabs, text};
+///
+/// struct State {
+///    documents: Vec<(usize, String)>,
+///    active: usize,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+///     Close(usize),
+///     Reorder(usize, usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     tabs(state.active, text!("Document {}", state.active))
+///         .extend(
+///             state
+///                 .documents
+///                 .iter()
+///                 .map(|(id, name)| (*id, text(name).into())),
+///         )
+///         .on_select(Message::Select)
+///         .on_close(Message::Close)
+///         .on_reorder(Message::Reorder)
+///         .into()
+/// }
+/// ```
+pub struct Tabs<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    active: Key,
+    tabs: Vec<(Key, Element<'a, Message, Theme, Renderer>)>,
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    on_select: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+    hovered: Option<(usize, bool)>,
+}
+
+const CLOSE_SIZE: f32 = 16.0;
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
+impl<'a, Key, Message, Theme, Renderer> Tabs<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the tabs of a [`Tabs`] widget.
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 6.0,
+        bottom: 6.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`Tabs`] widget with the key of the active tab and
+    /// its content.
+    pub fn new(active: Key, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            id: None,
+            active,
+            tabs: Vec::new(),
+            content: content.into(),
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 2.0,
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            class: Theme::default(),
+            hovered: None,
+        }
+    }
+
+    /// Adds a tab with the given key and label to the [`Tabs`].
+    pub fn push(
+        mut self,
+        key: Key,
+        label: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.tabs.push((key, label.into()));
+        self
+    }
+
+    /// Extends the [`Tabs`] with the given keys and labels.
+    pub fn extend(
+        self,
+        tabs: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        tabs.into_iter()
+            .fold(self, |tabs, (key, label)| tabs.push(key, label))
+    }
+
+    /// Sets the [`widget::Id`] of the [`Tabs`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each tab of the [`Tabs`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ the tabs of the [`Tabs`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the message that should be produced when a tab is selected.
+    pub fn on_select(mut self, on_select: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message that should be produced when a tab is closed.
+    ///
+    /// If set, every tab will display a close button.
+    pub fn on_close(mut self, on_close: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message that should be produced when a tab is dragged
+    /// to a new position.
+    ///
+    /// The closure receives the current index of the tab and the index it
+    /// should be moved to; once the tab is removed from its current index.
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tabs`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn close_bounds(&self, tab: Rectangle) -> Option<Rectangle> {
+        self.on_close.is_some().then(|| Rectangle {
+            x: tab.x + tab.width - self.padding.right - CLOSE_SIZE,
+            y: tab.center_y() - CLOSE_SIZE / 2.0,
+            width: CLOSE_SIZE,
+            height: CLOSE_SIZE,
+        })
+    }
+
+    /// Returns the key of the tab after the active one—or before it, if
+    /// `backwards`—wrapping around the ends.
+    fn cycle(&self, backwards: bool) -> Option<Key> {
+        let count = self.tabs.len();
+        let active = self.tabs.iter().position(|(key, _)| *key == self.active)?;
+
+        let next = if backwards {
+            (active + count - 1) % count
+        } else {
+            (active + 1) % count
+        };
+
+        Some(self.tabs[next].0)
+    }
+
+    fn hovered(&self, bar: Layout<'_>, cursor: mouse::Cursor) -> Option<(usize, bool)> {
+        if !cursor.is_over(bar.bounds()) {
+            return None;
+        }
+
+        bar.children().enumerate().find_map(|(index, tab)| {
+            let bounds = tab.bounds();
+
+            cursor.is_over(bounds).then(|| {
+                let is_over_close = self
+                    .close_bounds(bounds)
+                    .is_some_and(|close| cursor.is_over(close));
+
+                (index, is_over_close)
+            })
+        })
+    }
+}
+
+/// Returns the index a dragged tab would be moved to if dropped at the
+/// given horizontal position.
+fn drop_index(bar: Layout<'_>, dragged: usize, x: f32) -> usize {
+    bar.children()
+        .enumerate()
+        .filter(|(index, tab)| *index != dragged && tab.bounds().center_x() < x)
+        .count()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    delta: f32,
+    is_dragging: bool,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    offset: f32,
+    active: Option<usize>,
+    drag: Option<Drag>,
+    closing: Option<usize>,
+}
+
+impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'_, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(self.tabs.iter().map(|(_, label)| label))
+            .map(|element| Tree::new(element.as_widget()))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(self.tabs.iter().map(|(_, label)| label))
+            .map(Element::as_widget)
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let close_width = if self.on_close.is_some() {
+            self.spacing.max(4.0) + CLOSE_SIZE
+        } else {
+            0.0
+        };
+
+        let labels: Vec<_> = self
+            .tabs
+            .iter_mut()
+            .zip(&mut tree.children[1..])
+            .map(|((_, label), tree)| {
+                label.as_widget_mut().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, Size::INFINITE),
+                )
+            })
+            .collect();
+
+        let bar_height = labels
+            .iter()
+            .map(|label| label.size().height)
+            .fold(CLOSE_SIZE, f32::max)
+            + self.padding.y();
+
+        let limits = limits.width(self.width).height(self.height);
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &limits.shrink(Size::new(0.0, bar_height)),
+            )
+            .move_to(Point::new(0.0, bar_height));
+
+        let tabs_width = labels
+            .iter()
+            .map(|label| label.size().width + self.padding.x() + close_width)
+            .sum::<f32>()
+            + self.spacing * labels.len().saturating_sub(1) as f32;
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(
+                tabs_width.max(content.size().width),
+                bar_height + content.size().height,
+            ),
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let active = self.tabs.iter().position(|(key, _)| *key == self.active);
+
+        // Scroll the active tab into view when it changes
+        if active != state.active {
+            state.active = active;
+
+            if let Some(active) = active {
+                let start = labels[..active]
+                    .iter()
+                    .map(|label| label.size().width + self.padding.x() + close_width + self.spacing)
+                    .sum::<f32>();
+
+                let end = start + labels[active].size().width + self.padding.x() + close_width;
+
+                if start < state.offset {
+                    state.offset = start;
+                } else if end > state.offset + size.width {
+                    state.offset = end - size.width;
+                }
+            }
+        }
+
+        state.offset = state.offset.clamp(0.0, (tabs_width - size.width).max(0.0));
+
+        let mut x = -state.offset;
+
+        let tabs = labels
+            .into_iter()
+            .map(|label| {
+                let label_size = label.size();
+                let width = label_size.width + self.padding.x() + close_width;
+
+                let label = label.move_to(Point::new(
+                    self.padding.left,
+                    (bar_height - label_size.height) / 2.0,
+                ));
+
+                let tab = layout::Node::with_children(Size::new(width, bar_height), vec![label])
+                    .move_to(Point::new(x, 0.0));
+
+                x += width + self.spacing;
+
+                tab
+            })
+            .collect();
+
+        let bar = layout::Node::with_children(Size::new(size.width, bar_height), tabs);
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        operation.container(self.id.as_ref(), layout.bounds());
+        operation.accessible(None, bar.bounds(), &Accessible::new(Role::TabList));
+
+        operation.traverse(&mut |operation| {
+            let (content_tree, labels) = tree.children.split_at_mut(1);
+
+            for (((key, label), tree), layout) in
+                self.tabs.iter_mut().zip(labels).zip(bar.children())
+            {
+                operation.accessible(
+                    None,
+                    layout.bounds(),
+                    &Accessible::new(Role::Tab).selected(*key == self.active),
+                );
+
+                label.as_widget_mut().operate(
+                    tree,
+                    layout.children().next().unwrap(),
+                    renderer,
+                    operation,
+                );
+            }
+
+            self.content.as_widget_mut().operate(
+                &mut content_tree[0],
+                content,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        {
+            let (content_tree, labels) = tree.children.split_at_mut(1);
+
+            if let Some(bar_viewport) = bar.bounds().intersection(viewport) {
+                for (((_, label), tree), layout) in
+                    self.tabs.iter_mut().zip(labels).zip(bar.children())
+                {
+                    label.as_widget_mut().update(
+                        tree,
+                        event,
+                        layout.children().next().unwrap(),
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        &bar_viewport,
+                    );
+                }
+            }
+
+            self.content.as_widget_mut().update(
+                &mut content_tree[0],
+                event,
+                content,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some((index, is_over_close)) = self.hovered(bar, cursor) else {
+                    return;
+                };
+
+                if is_over_close {
+                    state.closing = Some(index);
+                } else {
+                    let key = self.tabs[index].0;
+
+                    if let Some(on_select) = &self.on_select
+                        && key != self.active
+                    {
+                        shell.publish(on_select(key));
+                    }
+
+                    if self.on_reorder.is_some()
+                        && let Some(position) = cursor.position()
+                    {
+                        state.drag = Some(Drag {
+                            index,
+                            origin: position.x,
+                            delta: 0.0,
+                            is_dragging: false,
+                        });
+                    }
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                if let Some(on_close) = &self.on_close
+                    && let Some((index, _)) = self.hovered(bar, cursor)
+                {
+                    shell.publish(on_close(self.tabs[index].0));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    drag.delta = position.x - drag.origin;
+                    drag.is_dragging =
+                        drag.is_dragging || drag.delta.abs() > DRAG_DEADBAND_DISTANCE;
+
+                    if drag.is_dragging {
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(index) = state.closing.take() {
+                    if let Some(on_close) = &self.on_close
+                        && self.hovered(bar, cursor) == Some((index, true))
+                    {
+                        shell.publish(on_close(self.tabs[index].0));
+                    }
+
+                    shell.capture_event();
+                }
+
+                if let Some(drag) = state.drag.take()
+                    && drag.is_dragging
+                {
+                    let target = drop_index(bar, drag.index, drag.origin + drag.delta);
+
+                    if let Some(on_reorder) = &self.on_reorder
+                        && target != drag.index
+                    {
+                        shell.publish(on_reorder(drag.index, target));
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(bar.bounds()) {
+                    return;
+                }
+
+                let delta = match *delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (if x.abs() > y.abs() { x } else { y }) * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x.abs() > y.abs() {
+                            x
+                        } else {
+                            y
+                        }
+                    }
+                };
+
+                state.offset -= delta;
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Tab),
+                modifiers,
+                ..
+            }) if modifiers.control() => {
+                if let Some(on_select) = &self.on_select
+                    && let Some(next) = self.cycle(modifiers.shift())
+                {
+                    shell.publish(on_select(next));
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+
+        let hovered = self.hovered(bar, cursor);
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.hovered = hovered;
+        } else if self.hovered != hovered {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        if self.hovered(bar, cursor).is_some() {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let hovered = self.hovered(bar, cursor);
+        let drag = state.drag.filter(|drag| drag.is_dragging);
+
+        if let Some(background) = theme.style(&self.class, Status::Active).bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bar.bounds(),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let draw_tab = |renderer: &mut Renderer, index: usize, status: Status| {
+            let (_, label) = &self.tabs[index];
+            let layout = bar.children().nth(index).unwrap();
+            let bounds = layout.bounds();
+            let style = theme.style(&self.class, status);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+
+            label.as_widget().draw(
+                &tree.children[index + 1],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color,
+                },
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+
+            if let Some(close) = self.close_bounds(bounds) {
+                renderer.fill_text(
+                    Text {
+                        content: String::from("×"),
+                        bounds: close.size(),
+                        size: Pixels(CLOSE_SIZE),
+                        line_height: text::LineHeight::Absolute(Pixels(CLOSE_SIZE)),
+                        font: renderer.default_font(),
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                        hint_factor: None,
+                    },
+                    close.center(),
+                    style.close_color,
+                    bounds,
+                );
+            }
+        };
+
+        if let Some(bar_bounds) = bar.bounds().intersection(viewport) {
+            renderer.with_layer(bar_bounds, |renderer| {
+                for (index, (key, _)) in self.tabs.iter().enumerate() {
+                    if drag.is_some_and(|drag| drag.index == index) {
+                        continue;
+                    }
+
+                    let status = if *key == self.active {
+                        Status::Selected
+                    } else if drag.is_none() && hovered.is_some_and(|(hovered, _)| hovered == index)
+                    {
+                        Status::Hovered
+                    } else {
+                        Status::Active
+                    };
+
+                    draw_tab(renderer, index, status);
+                }
+
+                if let Some(drag) = drag {
+                    let style = theme.style(&self.class, Status::Dragged);
+                    let target = drop_index(bar, drag.index, drag.origin + drag.delta);
+
+                    let others: Vec<_> = bar
+                        .children()
+                        .enumerate()
+                        .filter(|(index, _)| *index != drag.index)
+                        .map(|(_, tab)| tab.bounds())
+                        .collect();
+
+                    let x = match others.get(target) {
+                        Some(tab) => tab.x - self.spacing / 2.0,
+                        None => others
+                            .last()
+                            .map_or(bar.bounds().x, |tab| tab.x + tab.width + self.spacing / 2.0),
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: x - 1.0,
+                                y: bar.bounds().y,
+                                width: 2.0,
+                                height: bar.bounds().height,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.border.color,
+                    );
+
+                    renderer.with_translation(Vector::new(drag.delta, 0.0), |renderer| {
+                        draw_tab(renderer, drag.index, Status::Dragged);
+                    });
+                }
+            });
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().nth(1).unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<Tabs<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(tabs)
+    }
+}
+
+/// The possible status of a tab of a [`Tabs`] widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The tab can be interacted with.
+    Active,
+    /// The tab is being hovered.
+    Hovered,
+    /// The tab is selected.
+    Selected,
+    /// The tab is being dragged.
+    Dragged,
+}
+
+/// The appearance of a tab of a [`Tabs`] widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the tab.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the tab.
+    pub text_color: Color,
+    /// The [`Border`] of the tab.
+    pub border: Border,
+    /// The [`Color`] of the close button of the tab.
+    pub close_color: Color,
+    /// The [`Background`] of the tab bar.
+    ///
+    /// Only the bar background of [`Status::Active`] is used.
+    pub bar_background: Option<Background>,
+}
+
+/// The theme catalog of a [`Tabs`] widget.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Tabs`] widget.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Tabs`] widget.
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: None,
+        text_color: palette.background.weak.text,
+        border: border::rounded(border::top(4)),
+        close_color: palette.background.weak.text.scale_alpha(0.7),
+        bar_background: Some(palette.background.weak.color.into()),
+    };
+
+    let selected = Style {
+        background: Some(palette.background.base.color.into()),
+        text_color: palette.background.base.text,
+        close_color: palette.background.base.text.scale_alpha(0.7),
+        ..active.clone()
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            background: Some(palette.background.strong.color.into()),
+            ..active
+        },
+        Status::Selected => selected,
+        Status::Dragged => Style {
+            border: selected.border.width(1).color(palette.primary.base.color),
+            ..selected
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Space;
+
+    #[test]
+    fn ctrl_tab_cycles_through_the_tabs() {
+        let tabs: Tabs<'_, char, (), crate::Theme, ()> = Tabs::new('c', Space::new())
+            .push('a', Space::new())
+            .push('b', Space::new())
+            .push('c', Space::new());
+
+        assert_eq!(tabs.cycle(false), Some('a'));
+        assert_eq!(tabs.cycle(true), Some('b'));
+    }
+
+    #[test]
+    fn dragged_tabs_drop_past_the_centers_they_cross() {
+        // Three tabs that are 70 pixels wide, with a spacing of 2
+        let bar = layout::Node::with_children(
+            Size::new(214.0, 10.0),
+            (0..3)
+                .map(|index| {
+                    layout::Node::new(Size::new(70.0, 10.0))
+                        .move_to(Point::new(index as f32 * 72.0, 0.0))
+                })
+                .collect(),
+        );
+
+        let bar = Layout::new(&bar);
+
+        // Past the center of the last tab
+        assert_eq!(drop_index(bar, 0, 185.0), 2);
+
+        // Before the center of the second tab
+        assert_eq!(drop_index(bar, 0, 100.0), 0);
+
+        // Back to the beginning
+        assert_eq!(drop_index(bar, 2, 10.0), 0);
+        assert_eq!(drop_index(bar, 2, 150.0), 2);
+    }
+}
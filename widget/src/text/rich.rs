@@ -262,7 +262,7 @@ where
                 let translation = layout.position() - Point::ORIGIN;
                 let regions = state.paragraph.span_bounds(index);
 
-                if let Some(highlight) = span.highlight {
+                if let Some(highlight) = &span.highlight {
                     for bounds in &regions {
                         let bounds = Rectangle::new(
                             bounds.position() - Vector::new(span.padding.left, span.padding.top),
@@ -275,7 +275,7 @@ where
                                 border: highlight.border,
                                 ..Default::default()
                             },
-                            highlight.background,
+                            highlight.background.clone(),
                         );
                     }
                 }
@@ -31,6 +31,10 @@
 //!     }
 //! }
 //! ```
+pub mod search;
+
+pub use search::Search;
+
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::input_method;
@@ -45,6 +49,7 @@ use crate::core::text::{self, LineHeight, Text, Wrapping};
 use crate::core::theme;
 use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
 use crate::core::widget::{self, Widget};
 use crate::core::window;
 use crate::core::{
@@ -54,6 +59,7 @@ use crate::core::{
 
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::fmt;
 use std::ops;
 use std::ops::DerefMut;
@@ -360,6 +366,45 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    history: History,
+    search: Option<Search>,
+    matches: Vec<search::Match>,
+}
+
+impl<R> Internal<R>
+where
+    R: text::Renderer,
+{
+    /// Updates the matches of the current [`Search`] after `removed` lines
+    /// starting at `line` were replaced by `inserted` ones.
+    ///
+    /// Only the replaced lines are searched again; the matches after them
+    /// are just shifted.
+    fn refresh_matches(&mut self, line: usize, removed: usize, inserted: usize) {
+        let Some(search) = &self.search else {
+            return;
+        };
+
+        let start = self
+            .matches
+            .partition_point(|found| found.start.line < line);
+        let end = self
+            .matches
+            .partition_point(|found| found.start.line < line + removed);
+
+        let found: Vec<_> = (line..line + inserted)
+            .filter_map(|i| Some((i, self.editor.line(i)?)))
+            .flat_map(|(i, line)| search.find_in(i, &line.text))
+            .collect();
+
+        let shifted = start + found.len();
+        let _ = self.matches.splice(start..end, found);
+
+        for found in &mut self.matches[shifted..] {
+            found.start.line = found.start.line + inserted - removed;
+            found.end.line = found.end.line + inserted - removed;
+        }
+    }
 }
 
 impl<R> Content<R>
@@ -375,28 +420,396 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            history: History::default(),
+            search: None,
+            matches: Vec::new(),
         }))
     }
 
     /// Performs an [`Action`] on the [`Content`].
+    ///
+    /// Edits are recorded in the history of the [`Content`], which can be
+    /// traversed with [`Action::Undo`] and [`Action::Redo`].
+    ///
+    /// [`Action::FindNext`], [`Action::FindPrevious`], [`Action::Replace`],
+    /// and [`Action::ReplaceAll`] operate on the current [`Search`] of the
+    /// [`Content`]. See [`Content::set_search`].
     pub fn perform(&mut self, action: Action) {
-        let internal = self.0.get_mut();
+        match action {
+            Action::Undo => {
+                let _ = self.undo();
+            }
+            Action::Redo => {
+                let _ = self.redo();
+            }
+            Action::Edit(edit) => {
+                let internal = self.0.get_mut();
+                let group = Group::of(&edit);
+                let lines = affected_lines(&internal.editor, &edit);
+                let before = internal.editor.cursor();
+
+                let change = Change::record(&mut internal.editor, lines, |editor| {
+                    editor.perform(Action::Edit(edit));
+                });
+
+                let after = internal.editor.cursor();
 
-        internal.editor.perform(action);
+                if let Some(change) = &change {
+                    internal.refresh_matches(change.line, change.before.count, change.after.count);
+                }
+
+                internal
+                    .history
+                    .record(change.into_iter().collect(), before, after, group);
+            }
+            Action::FindNext => {
+                let _ = self.find_next();
+            }
+            Action::FindPrevious => {
+                let _ = self.find_previous();
+            }
+            Action::Replace(replacement) => {
+                let _ = self.replace(&replacement);
+            }
+            Action::ReplaceAll(replacement) => {
+                let _ = self.replace_all(&replacement);
+            }
+            Action::Scroll { .. } => {
+                self.0.get_mut().editor.perform(action);
+            }
+            _ => {
+                let internal = self.0.get_mut();
+
+                internal.history.interrupt();
+                internal.editor.perform(action);
+            }
+        }
     }
 
     /// Moves the current cursor to reflect the given one.
     pub fn move_to(&mut self, cursor: Cursor) {
         let internal = self.0.get_mut();
 
+        internal.history.interrupt();
         internal.editor.move_to(cursor);
     }
 
+    /// Undoes the last edit of the [`Content`], restoring its text and
+    /// cursor.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let internal = self.0.get_mut();
+
+        let Some(entry) = internal.history.undo() else {
+            return false;
+        };
+
+        let spans: Vec<_> = entry
+            .changes
+            .iter()
+            .rev()
+            .map(|change| {
+                change.revert(&mut internal.editor);
+
+                (change.line, change.after.count, change.before.count)
+            })
+            .collect();
+
+        internal.editor.move_to(entry.before);
+
+        for (line, removed, inserted) in spans {
+            internal.refresh_matches(line, removed, inserted);
+        }
+
+        true
+    }
+
+    /// Redoes the last undone edit of the [`Content`].
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let internal = self.0.get_mut();
+
+        let Some(entry) = internal.history.redo() else {
+            return false;
+        };
+
+        let spans: Vec<_> = entry
+            .changes
+            .iter()
+            .map(|change| {
+                change.apply(&mut internal.editor);
+
+                (change.line, change.before.count, change.after.count)
+            })
+            .collect();
+
+        internal.editor.move_to(entry.after);
+
+        for (line, removed, inserted) in spans {
+            internal.refresh_matches(line, removed, inserted);
+        }
+
+        true
+    }
+
+    /// Returns whether the [`Content`] has any edits that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.0.borrow().history.undo.is_empty()
+    }
+
+    /// Returns whether the [`Content`] has any undone edits that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.0.borrow().history.redo.is_empty()
+    }
+
+    /// Forgets all the edit history of the [`Content`].
+    pub fn clear_history(&mut self) {
+        let history = &mut self.0.get_mut().history;
+
+        history.undo.clear();
+        history.redo.clear();
+        history.interrupt();
+    }
+
+    /// Sets the maximum amount of undo steps kept by the [`Content`].
+    ///
+    /// By default, the last 100 steps are kept.
+    pub fn set_history_limit(&mut self, limit: usize) {
+        let history = &mut self.0.get_mut().history;
+
+        history.limit = limit;
+        history.trim();
+    }
+
+    /// Returns all the [`Match`]es of the given [`Search`] in the [`Content`].
+    ///
+    /// [`Match`]: search::Match
+    pub fn find(&self, search: &Search) -> Vec<search::Match> {
+        let internal = self.0.borrow();
+
+        (0..internal.editor.line_count())
+            .filter_map(|i| Some((i, internal.editor.line(i)?)))
+            .flat_map(|(i, line)| search.find_in(i, &line.text))
+            .collect()
+    }
+
+    /// Sets the current [`Search`] of the [`Content`].
+    ///
+    /// The matches of the current [`Search`] are highlighted by the
+    /// [`TextEditor`] and kept up to date as the [`Content`] is edited.
+    pub fn set_search(&mut self, search: Option<Search>) {
+        let matches = search
+            .as_ref()
+            .map(|search| self.find(search))
+            .unwrap_or_default();
+
+        let internal = self.0.get_mut();
+
+        internal.search = search;
+        internal.matches = matches;
+    }
+
+    /// Returns the current [`Search`] of the [`Content`], if any.
+    pub fn search(&self) -> Option<Search> {
+        self.0.borrow().search.clone()
+    }
+
+    /// Returns the [`Match`]es of the current [`Search`] of the [`Content`].
+    ///
+    /// [`Match`]: search::Match
+    pub fn matches(&self) -> Vec<search::Match> {
+        self.0.borrow().matches.clone()
+    }
+
+    /// Selects the next match of the current [`Search`], wrapping around
+    /// the end of the [`Content`].
+    ///
+    /// Returns `false` if there are no matches.
+    pub fn find_next(&mut self) -> bool {
+        let internal = self.0.get_mut();
+        let cursor = internal.editor.cursor();
+
+        let from = order(cursor.position).max(order(cursor.selection.unwrap_or(cursor.position)));
+
+        let Some(next) = internal
+            .matches
+            .iter()
+            .find(|found| order(found.start) >= from)
+            .or(internal.matches.first())
+            .copied()
+        else {
+            return false;
+        };
+
+        self.select(next);
+
+        true
+    }
+
+    /// Selects the previous match of the current [`Search`], wrapping around
+    /// the start of the [`Content`].
+    ///
+    /// Returns `false` if there are no matches.
+    pub fn find_previous(&mut self) -> bool {
+        let internal = self.0.get_mut();
+        let cursor = internal.editor.cursor();
+
+        let to = order(cursor.position).min(order(cursor.selection.unwrap_or(cursor.position)));
+
+        let Some(previous) = internal
+            .matches
+            .iter()
+            .rev()
+            .find(|found| order(found.end) <= to)
+            .or(internal.matches.last())
+            .copied()
+        else {
+            return false;
+        };
+
+        self.select(previous);
+
+        true
+    }
+
+    /// Replaces the selected match of the current [`Search`] with the given
+    /// text and selects the next one.
+    ///
+    /// If no match is selected, the next match is selected instead.
+    ///
+    /// Returns `false` if there are no matches.
+    pub fn replace(&mut self, replacement: &str) -> bool {
+        let selected = {
+            let internal = self.0.get_mut();
+            let cursor = internal.editor.cursor();
+
+            internal.matches.iter().copied().find(|found| {
+                cursor.selection.is_some_and(|selection| {
+                    let (start, end) = if order(selection) <= order(cursor.position) {
+                        (selection, cursor.position)
+                    } else {
+                        (cursor.position, selection)
+                    };
+
+                    start == found.start && end == found.end
+                })
+            })
+        };
+
+        if let Some(selected) = selected {
+            let replacement = self.replacement(selected, replacement);
+
+            let internal = self.0.get_mut();
+            let before = internal.editor.cursor();
+
+            let change = Change::record(
+                &mut internal.editor,
+                selected.start.line..=selected.end.line,
+                |editor| {
+                    editor.perform(Action::Edit(Edit::Paste(Arc::new(replacement))));
+                },
+            );
+
+            let after = internal.editor.cursor();
+
+            if let Some(change) = &change {
+                internal.refresh_matches(change.line, change.before.count, change.after.count);
+            }
+
+            internal
+                .history
+                .record(change.into_iter().collect(), before, after, None);
+        }
+
+        self.find_next()
+    }
+
+    /// Replaces all the matches of the current [`Search`] with the given text.
+    ///
+    /// All the replacements are undone together.
+    ///
+    /// Returns the amount of replaced matches.
+    pub fn replace_all(&mut self, replacement: &str) -> usize {
+        let matches = self.0.get_mut().matches.clone();
+
+        if matches.is_empty() {
+            return 0;
+        }
+
+        let replacements: Vec<_> = matches
+            .iter()
+            .map(|found| self.replacement(*found, replacement))
+            .collect();
+
+        let internal = self.0.get_mut();
+        let before = internal.editor.cursor();
+
+        let changes: Vec<_> = matches
+            .iter()
+            .zip(replacements)
+            .rev()
+            .filter_map(|(found, replacement)| {
+                internal.editor.move_to(Cursor {
+                    position: found.end,
+                    selection: Some(found.start),
+                });
+
+                Change::record(
+                    &mut internal.editor,
+                    found.start.line..=found.end.line,
+                    |editor| {
+                        editor.perform(Action::Edit(Edit::Paste(Arc::new(replacement))));
+                    },
+                )
+            })
+            .collect();
+
+        let after = internal.editor.cursor();
+
+        for change in &changes {
+            internal.refresh_matches(change.line, change.before.count, change.after.count);
+        }
+
+        internal.history.record(changes, before, after, None);
+
+        matches.len()
+    }
+
+    fn select(&mut self, found: search::Match) {
+        self.move_to(Cursor {
+            position: found.end,
+            selection: Some(found.start),
+        });
+    }
+
+    fn replacement(&self, found: search::Match, replacement: &str) -> String {
+        let internal = self.0.borrow();
+
+        let (Some(search), Some(line)) = (
+            internal.search.as_ref(),
+            internal.editor.line(found.start.line),
+        ) else {
+            return replacement.to_owned();
+        };
+
+        search.replacement(&line.text, found.start.column, replacement)
+    }
+
     /// Returns the current cursor position of the [`Content`].
+    ///
+    /// If the [`Content`] has multiple cursors, this is the primary one.
     pub fn cursor(&self) -> Cursor {
         self.0.borrow().editor.cursor()
     }
 
+    /// Returns all the current cursors of the [`Content`], starting with the
+    /// primary one.
+    pub fn cursors(&self) -> Vec<Cursor> {
+        self.0.borrow().editor.cursors()
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -457,6 +870,272 @@ where
     }
 }
 
+#[derive(Debug)]
+struct History {
+    undo: VecDeque<Entry>,
+    redo: Vec<Entry>,
+    group: Option<Group>,
+    limit: usize,
+}
+
+impl History {
+    const DEFAULT_LIMIT: usize = 100;
+
+    /// Records the given changes as a new undo step.
+    ///
+    /// Consecutive changes of the same [`Group`] are coalesced into a
+    /// single step.
+    fn record(
+        &mut self,
+        changes: Vec<Change>,
+        before: Cursor,
+        after: Cursor,
+        group: Option<Group>,
+    ) {
+        // Edits that did not change anything (e.g. a backspace at the
+        // start of the text) are not recorded.
+        if changes.is_empty() {
+            return;
+        }
+
+        self.redo.clear();
+
+        if group.is_some()
+            && self.group == group
+            && let Some(entry) = self.undo.back_mut()
+        {
+            for change in changes {
+                entry.push(change);
+            }
+
+            entry.after = after;
+        } else {
+            self.undo.push_back(Entry {
+                changes,
+                before,
+                after,
+            });
+
+            self.trim();
+        }
+
+        self.group = group;
+    }
+
+    fn undo(&mut self) -> Option<&Entry> {
+        self.interrupt();
+
+        let entry = self.undo.pop_back()?;
+        self.redo.push(entry);
+
+        self.redo.last()
+    }
+
+    fn redo(&mut self) -> Option<&Entry> {
+        self.interrupt();
+
+        let entry = self.redo.pop()?;
+        self.undo.push_back(entry);
+
+        self.undo.back()
+    }
+
+    fn interrupt(&mut self) {
+        self.group = None;
+    }
+
+    fn trim(&mut self) {
+        while self.undo.len() > self.limit {
+            let _ = self.undo.pop_front();
+        }
+    }
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            group: None,
+            limit: Self::DEFAULT_LIMIT,
+        }
+    }
+}
+
+/// An undo step of the [`History`].
+#[derive(Debug)]
+struct Entry {
+    changes: Vec<Change>,
+    before: Cursor,
+    after: Cursor,
+}
+
+impl Entry {
+    fn push(&mut self, change: Change) {
+        // Typing on the same lines keeps replacing the same region; so we
+        // only need to remember its original and latest text.
+        if let Some(last) = self.changes.last_mut()
+            && last.line == change.line
+            && last.after == change.before
+        {
+            last.after = change.after;
+        } else {
+            self.changes.push(change);
+        }
+    }
+}
+
+/// A change of some consecutive lines of text.
+///
+/// Only the affected lines are stored; so the cost of a [`Change`] does
+/// not depend on the size of the whole text.
+#[derive(Debug)]
+struct Change {
+    line: usize,
+    before: Lines,
+    after: Lines,
+}
+
+impl Change {
+    /// Performs an edit on the given lines of the editor, recording its
+    /// [`Change`].
+    ///
+    /// The edit must not affect any lines outside of the given range.
+    /// Returns `None` if the text did not change.
+    fn record<E: text::Editor>(
+        editor: &mut E,
+        lines: ops::RangeInclusive<usize>,
+        edit: impl FnOnce(&mut E),
+    ) -> Option<Self> {
+        let line_count = editor.line_count();
+        let line = (*lines.start()).min(line_count.saturating_sub(1));
+        let count = lines
+            .end()
+            .saturating_sub(line)
+            .min(line_count.saturating_sub(line + 1))
+            + 1;
+
+        let before = Lines::of(editor, line, count);
+
+        edit(editor);
+
+        let count = (count + editor.line_count())
+            .saturating_sub(line_count)
+            .max(1);
+        let after = Lines::of(editor, line, count);
+
+        (before != after).then_some(Self {
+            line,
+            before,
+            after,
+        })
+    }
+
+    fn apply<E: text::Editor>(&self, editor: &mut E) {
+        self.before.replace(editor, self.line, &self.after.text);
+    }
+
+    fn revert<E: text::Editor>(&self, editor: &mut E) {
+        self.after.replace(editor, self.line, &self.before.text);
+    }
+}
+
+/// The text of some consecutive lines, without the ending of the last one.
+#[derive(Debug, PartialEq)]
+struct Lines {
+    text: String,
+    count: usize,
+}
+
+impl Lines {
+    fn of(editor: &impl text::Editor, first: usize, count: usize) -> Self {
+        let mut text = String::new();
+
+        for index in first..first + count {
+            let Some(line) = editor.line(index) else {
+                break;
+            };
+
+            if index > first {
+                text.push_str(line_ending(editor, index - 1).as_str());
+            }
+
+            text.push_str(&line.text);
+        }
+
+        Self { text, count }
+    }
+
+    /// Replaces these lines, starting at the given one, with some text.
+    fn replace(&self, editor: &mut impl text::Editor, first: usize, text: &str) {
+        let last = first + self.count - 1;
+        let column = editor.line(last).map_or(0, |line| line.text.len());
+
+        editor.move_to(Cursor {
+            position: Position { line: last, column },
+            selection: Some(Position {
+                line: first,
+                column: 0,
+            }),
+        });
+
+        editor.perform(Action::Edit(Edit::Paste(Arc::new(text.to_owned()))));
+    }
+}
+
+/// Returns the ending of the line at the given index.
+fn line_ending(editor: &impl text::Editor, index: usize) -> LineEnding {
+    match editor.line(index).map(|line| line.ending) {
+        Some(LineEnding::None) | None => LineEnding::default(),
+        Some(ending) => ending,
+    }
+}
+
+/// Returns the lines that an [`Edit`] may affect, given the cursors of an
+/// editor.
+fn affected_lines(editor: &impl text::Editor, edit: &Edit) -> ops::RangeInclusive<usize> {
+    let lines = editor
+        .cursors()
+        .into_iter()
+        .flat_map(|cursor| std::iter::once(cursor.position).chain(cursor.selection))
+        .map(|position| position.line);
+
+    let first = lines.clone().min().unwrap_or_default();
+    let last = lines.max().unwrap_or_default();
+
+    match edit {
+        // A backspace at the start of a line joins it with the previous one
+        Edit::Backspace => first.saturating_sub(1)..=last,
+        // A delete at the end of a line joins it with the next one
+        Edit::Delete => first..=last + 1,
+        _ => first..=last,
+    }
+}
+
+/// A kind of [`Edit`] whose consecutive occurrences are undone together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Group {
+    Insert,
+    Backspace,
+    Delete,
+}
+
+impl Group {
+    fn of(edit: &Edit) -> Option<Self> {
+        match edit {
+            Edit::Insert(_) => Some(Self::Insert),
+            Edit::Backspace => Some(Self::Backspace),
+            Edit::Delete => Some(Self::Delete),
+            Edit::Paste(_) | Edit::Enter | Edit::Indent | Edit::Unindent => None,
+        }
+    }
+}
+
+/// Returns the key of a [`Position`] in the reading order of some text.
+fn order(position: Position) -> (usize, usize) {
+    (position.line, position.column)
+}
+
 impl<Renderer> Clone for Content<Renderer>
 where
     Renderer: text::Renderer,
@@ -496,6 +1175,8 @@ pub struct State<Highlighter: text::Highlighter> {
     preedit: Option<input_method::Preedit>,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
+    rectangle_origin: Option<Point>,
+    keyboard_modifiers: keyboard::Modifiers,
     partial_scroll: f32,
     last_theme: RefCell<Option<String>>,
     highlighter: RefCell<Highlighter>,
@@ -568,6 +1249,8 @@ where
             preedit: None,
             last_click: None,
             drag_click: None,
+            rectangle_origin: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
             partial_scroll: 0.0,
             last_theme: RefCell::default(),
             highlighter: RefCell::new(Highlighter::new(&self.highlighter_settings)),
@@ -687,6 +1370,9 @@ where
                     );
                 }
             }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = *modifiers;
+            }
             _ => {}
         }
 
@@ -700,7 +1386,15 @@ where
         ) {
             match update {
                 Update::Click(click) => {
+                    let is_alt_click = state.keyboard_modifiers.alt()
+                        && click.kind() == mouse::click::Kind::Single;
+
+                    state.rectangle_origin = is_alt_click.then_some(click.position());
+
                     let action = match click.kind() {
+                        mouse::click::Kind::Single if is_alt_click => {
+                            Action::AddCursor(click.position())
+                        }
                         mouse::click::Kind::Single => Action::Click(click.position()),
                         mouse::click::Kind::Double => Action::SelectWord,
                         mouse::click::Kind::Triple => Action::SelectLine,
@@ -714,10 +1408,19 @@ where
                     shell.capture_event();
                 }
                 Update::Drag(position) => {
-                    shell.publish(on_edit(Action::Drag(position)));
+                    let action = match state.rectangle_origin {
+                        Some(origin) => Action::SelectRectangle {
+                            from: origin,
+                            to: position,
+                        },
+                        None => Action::Drag(position),
+                    };
+
+                    shell.publish(on_edit(action));
                 }
                 Update::Release => {
                     state.drag_click = None;
+                    state.rectangle_origin = None;
                 }
                 Update::Scroll(lines) => {
                     let bounds = self.content.0.borrow().editor.bounds();
@@ -786,6 +1489,18 @@ where
                                     publish(Action::Edit(Edit::Paste(Arc::new(contents))));
                                 }
                             }
+                            Binding::Undo => {
+                                publish(Action::Undo);
+                            }
+                            Binding::Redo => {
+                                publish(Action::Redo);
+                            }
+                            Binding::FindNext => {
+                                publish(Action::FindNext);
+                            }
+                            Binding::FindPrevious => {
+                                publish(Action::FindPrevious);
+                            }
                             Binding::Move(motion) => {
                                 publish(Action::Move(motion));
                             }
@@ -945,50 +1660,79 @@ where
 
         let translation = text_bounds.position() - Point::ORIGIN;
 
-        if let Some(focus) = state.focus.as_ref() {
-            match internal.editor.selection() {
-                Selection::Caret(position) if focus.is_cursor_visible() => {
-                    let cursor = Rectangle::new(
-                        position + translation,
-                        Size::new(
-                            if renderer::CRISP {
-                                (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
-                            } else {
-                                1.0
-                            },
-                            self.line_height
-                                .to_absolute(
-                                    self.text_size.unwrap_or_else(|| renderer.default_size()),
-                                )
-                                .into(),
-                        ),
-                    );
+        let match_color = style.selection.scale_alpha(0.5);
 
-                    if let Some(clipped_cursor) = text_bounds.intersection(&cursor) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: clipped_cursor,
-                                ..renderer::Quad::default()
-                            },
-                            style.value,
+        let visible = internal.editor.visible_lines();
+        let first = internal
+            .matches
+            .partition_point(|found| found.end.line < visible.start);
+
+        for found in internal.matches[first..]
+            .iter()
+            .take_while(|found| found.start.line < visible.end)
+        {
+            for range in internal
+                .editor
+                .range_bounds(found.start, found.end)
+                .into_iter()
+                .filter_map(|range| text_bounds.intersection(&(range + translation)))
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: range,
+                        ..renderer::Quad::default()
+                    },
+                    match_color,
+                );
+            }
+        }
+
+        if let Some(focus) = state.focus.as_ref() {
+            for selection in internal.editor.selections() {
+                match selection {
+                    Selection::Caret(position) if focus.is_cursor_visible() => {
+                        let cursor = Rectangle::new(
+                            position + translation,
+                            Size::new(
+                                if renderer::CRISP {
+                                    (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
+                                } else {
+                                    1.0
+                                },
+                                self.line_height
+                                    .to_absolute(
+                                        self.text_size.unwrap_or_else(|| renderer.default_size()),
+                                    )
+                                    .into(),
+                            ),
                         );
+
+                        if let Some(clipped_cursor) = text_bounds.intersection(&cursor) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: clipped_cursor,
+                                    ..renderer::Quad::default()
+                                },
+                                style.value,
+                            );
+                        }
                     }
-                }
-                Selection::Range(ranges) => {
-                    for range in ranges
-                        .into_iter()
-                        .filter_map(|range| text_bounds.intersection(&(range + translation)))
-                    {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: range,
-                                ..renderer::Quad::default()
-                            },
-                            style.selection,
-                        );
+                    Selection::Range(ranges) => {
+                        for range in ranges
+                            .into_iter()
+                            .filter_map(|range| text_bounds.intersection(&(range + translation)))
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: range,
+                                    ..renderer::Quad::default()
+                                },
+                                style.selection,
+                            );
+                        }
                     }
+                    Selection::Caret(_) => {}
                 }
-                Selection::Caret(_) => {}
             }
         }
     }
@@ -1023,6 +1767,16 @@ where
     ) {
         let state = tree.state.downcast_mut::<State<Highlighter>>();
 
+        let mut accessible = Accessible::new(Role::MultilineTextInput)
+            .value(self.content.text())
+            .focused(state.is_focused())
+            .disabled(self.on_edit.is_none());
+
+        if let Some(placeholder) = self.placeholder.as_deref() {
+            accessible = accessible.label(placeholder);
+        }
+
+        operation.accessible(self.id.as_ref(), layout.bounds(), &accessible);
         operation.focusable(self.id.as_ref(), layout.bounds(), state);
     }
 }
@@ -1052,6 +1806,14 @@ pub enum Binding<Message> {
     Cut,
     /// Paste the clipboard contents in the [`TextEditor`].
     Paste,
+    /// Undo the last edit of the [`TextEditor`].
+    Undo,
+    /// Redo the last undone edit of the [`TextEditor`].
+    Redo,
+    /// Select the next match of the current [`Search`].
+    FindNext,
+    /// Select the previous match of the current [`Search`].
+    FindPrevious,
     /// Apply a [`Motion`].
     Move(Motion),
     /// Select text with a given [`Motion`].
@@ -1116,6 +1878,11 @@ impl<Message> Binding<Message> {
         }
 
         let combination = match key.to_latin(physical_key) {
+            Some('z') if modifiers.command() && modifiers.shift() => Some(Self::Redo),
+            Some('z') if modifiers.command() => Some(Self::Undo),
+            Some('y') if modifiers.command() => Some(Self::Redo),
+            Some('g') if modifiers.command() && modifiers.shift() => Some(Self::FindPrevious),
+            Some('g') if modifiers.command() => Some(Self::FindNext),
             Some('c') if modifiers.command() => Some(Self::Copy),
             Some('x') if modifiers.command() => Some(Self::Cut),
             Some('v') if modifiers.command() && !modifiers.alt() => Some(Self::Paste),
@@ -1139,6 +1906,8 @@ impl<Message> Binding<Message> {
                 Some(Self::Delete)
             }
             keyboard::Key::Named(key::Named::Escape) => Some(Self::Unfocus),
+            keyboard::Key::Named(key::Named::F3) if modifiers.shift() => Some(Self::FindPrevious),
+            keyboard::Key::Named(key::Named::F3) => Some(Self::FindNext),
             _ => {
                 if let Some(text) = text {
                     let c = text.chars().find(|c| !c.is_control())?;
@@ -1332,7 +2101,7 @@ pub enum Status {
 }
 
 /// The appearance of a text input.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the text input.
     pub background: Background,
@@ -1435,3 +2204,226 @@ pub(crate) fn convert_macos_shortcut(
 
     Some(keyboard::Key::Named(key))
 }
+
+// Editing needs the text engine of an actual renderer
+#[cfg(all(test, feature = "wgpu"))]
+mod tests {
+    use super::*;
+
+    type Content = super::Content<crate::Renderer>;
+
+    fn type_text(content: &mut Content, text: &str) {
+        for c in text.chars() {
+            content.perform(Action::Edit(if c == '\n' {
+                Edit::Enter
+            } else {
+                Edit::Insert(c)
+            }));
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_restore_text_and_cursor() {
+        let mut content = Content::with_text("one\ntwo\nthree");
+
+        content.move_to(Cursor {
+            position: Position { line: 1, column: 3 },
+            selection: None,
+        });
+
+        type_text(&mut content, "!!");
+        content.perform(Action::Edit(Edit::Enter));
+        type_text(&mut content, "2½");
+
+        assert_eq!(content.text(), "one\ntwo!!\n2½\nthree");
+
+        assert!(content.undo());
+        assert_eq!(content.text(), "one\ntwo!!\n\nthree");
+
+        assert!(content.undo());
+        assert!(content.undo());
+        assert_eq!(content.text(), "one\ntwo\nthree");
+        assert_eq!(content.cursor().position, Position { line: 1, column: 3 });
+        assert!(!content.undo());
+
+        assert!(content.redo());
+        assert!(content.redo());
+        assert!(content.redo());
+        assert_eq!(content.text(), "one\ntwo!!\n2½\nthree");
+        assert!(!content.redo());
+    }
+
+    #[test]
+    fn backspace_and_delete_join_lines() {
+        let mut content = Content::with_text("a\nb\nc");
+
+        content.move_to(Cursor {
+            position: Position { line: 1, column: 0 },
+            selection: None,
+        });
+        content.perform(Action::Edit(Edit::Backspace));
+
+        content.move_to(Cursor {
+            position: Position { line: 0, column: 2 },
+            selection: None,
+        });
+        content.perform(Action::Edit(Edit::Delete));
+
+        assert_eq!(content.text(), "abc");
+
+        assert!(content.undo());
+        assert_eq!(content.text(), "ab\nc");
+
+        assert!(content.undo());
+        assert_eq!(content.text(), "a\nb\nc");
+    }
+
+    #[test]
+    fn new_edits_invalidate_redo() {
+        let mut content = Content::new();
+
+        type_text(&mut content, "hello");
+        assert!(content.undo());
+        assert!(content.can_redo());
+
+        type_text(&mut content, "bye");
+        assert!(!content.can_redo());
+        assert!(!content.redo());
+        assert_eq!(content.text(), "bye");
+    }
+
+    #[test]
+    fn replacements_are_undone_together() {
+        let mut content = Content::with_text("cat\ndog cat\ncat");
+
+        content.set_search(Some(Search::new("cat")));
+
+        assert_eq!(content.replace_all("bird"), 3);
+        assert_eq!(content.text(), "bird\ndog bird\nbird");
+
+        assert!(content.undo());
+        assert_eq!(content.text(), "cat\ndog cat\ncat");
+
+        assert!(content.redo());
+        assert_eq!(content.text(), "bird\ndog bird\nbird");
+    }
+
+    #[test]
+    fn matches_follow_edits() {
+        let mut content = Content::with_text("cat\ndog\ncat cat");
+        let search = Search::new("cat");
+
+        content.set_search(Some(search.clone()));
+        assert_eq!(content.matches().len(), 3);
+
+        let edits: [fn(&mut Content); 4] = [
+            |content| type_text(content, "cat\n"),
+            |content| {
+                content.move_to(Cursor {
+                    position: Position { line: 2, column: 3 },
+                    selection: Some(Position { line: 0, column: 1 }),
+                });
+                content.perform(Action::Edit(Edit::Delete));
+            },
+            |content| {
+                let _ = content.undo();
+            },
+            |content| {
+                let _ = content.redo();
+            },
+        ];
+
+        for edit in edits {
+            edit(&mut content);
+
+            assert_eq!(content.matches(), content.find(&search));
+        }
+
+        assert_eq!(content.text(), "c\ncat cat");
+        assert_eq!(content.matches().len(), 2);
+    }
+
+    /// Creates a [`Content`] laid out with lines that are 10 units tall.
+    fn laid_out(text: &str) -> Content {
+        let mut content = Content::with_text(text);
+
+        content.0.get_mut().editor.update(
+            Size::new(1000.0, 1000.0),
+            crate::core::Font::default(),
+            Pixels(10.0),
+            LineHeight::Absolute(Pixels(10.0)),
+            Wrapping::None,
+            None,
+            &mut highlighter::PlainText,
+        );
+
+        content
+    }
+
+    #[test]
+    fn multiple_cursors_are_edited_together() {
+        let mut content = laid_out("one\ntwo\nthree");
+
+        content.perform(Action::Click(Point::new(0.0, 5.0)));
+        content.perform(Action::AddCursor(Point::new(0.0, 15.0)));
+        content.perform(Action::AddCursor(Point::new(0.0, 25.0)));
+
+        assert_eq!(content.0.borrow().editor.cursors().len(), 3);
+
+        type_text(&mut content, "> ");
+        assert_eq!(content.text(), "> one\n> two\n> three");
+
+        content.perform(Action::Edit(Edit::Backspace));
+        assert_eq!(content.text(), ">one\n>two\n>three");
+
+        assert!(content.undo());
+        assert!(content.undo());
+        assert_eq!(content.text(), "one\ntwo\nthree");
+
+        content.perform(Action::Click(Point::new(0.0, 5.0)));
+        assert_eq!(content.0.borrow().editor.cursors().len(), 1);
+    }
+
+    #[test]
+    fn rectangular_selections_select_every_line() {
+        let mut content = laid_out("abc\ndefgh\nij\nklm");
+
+        content.perform(Action::SelectRectangle {
+            from: Point::new(0.0, 15.0),
+            to: Point::new(1000.0, 25.0),
+        });
+
+        let internal = content.0.borrow();
+
+        assert_eq!(internal.editor.selections().len(), 2);
+        assert_eq!(internal.editor.copy().as_deref(), Some("defgh\nij"));
+        assert_eq!(
+            internal.editor.cursor().position,
+            Position { line: 2, column: 2 }
+        );
+
+        drop(internal);
+
+        content.perform(Action::Edit(Edit::Insert('x')));
+        assert_eq!(content.text(), "abc\nx\nx\nklm");
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut content = Content::new();
+        content.set_history_limit(3);
+
+        for _ in 0..5 {
+            content.perform(Action::Edit(Edit::Enter));
+        }
+
+        let mut undone = 0;
+
+        while content.undo() {
+            undone += 1;
+        }
+
+        assert_eq!(undone, 3);
+        assert_eq!(content.line_count(), 3);
+    }
+}
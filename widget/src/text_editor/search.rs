@@ -0,0 +1,323 @@
+//! Search the contents of a text editor.
+use crate::core::text::editor::Position;
+
+#[cfg(feature = "regex")]
+use regex::{Regex, RegexBuilder};
+
+#[cfg(feature = "regex")]
+use std::borrow::Cow;
+
+/// A search of some text in the [`Content`] of a [`TextEditor`].
+///
+/// Matches never span multiple lines.
+///
+/// [`Content`]: super::Content
+/// [`TextEditor`]: super::TextEditor
+#[derive(Debug, Clone)]
+pub struct Search {
+    pattern: String,
+    is_case_sensitive: bool,
+    is_whole_word: bool,
+    matcher: Matcher,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    Text,
+    #[cfg(feature = "regex")]
+    Regex(Regex),
+}
+
+impl Search {
+    /// Creates a new [`Search`] of the given plain text.
+    ///
+    /// By default, the [`Search`] is case insensitive.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            pattern: text.into(),
+            is_case_sensitive: false,
+            is_whole_word: false,
+            matcher: Matcher::Text,
+        }
+    }
+
+    /// Creates a new [`Search`] of the given regular expression.
+    ///
+    /// Replacements of a regular expression [`Search`] may refer to its
+    /// capture groups (e.g. `$1` or `${name}`).
+    ///
+    /// By default, the [`Search`] is case insensitive.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: impl Into<String>) -> Result<Self, Error> {
+        let pattern = pattern.into();
+        let regex = build(&pattern, false, false)?;
+
+        Ok(Self {
+            pattern,
+            is_case_sensitive: false,
+            is_whole_word: false,
+            matcher: Matcher::Regex(regex),
+        })
+    }
+
+    /// Sets whether the [`Search`] must match the case of its pattern.
+    ///
+    /// If the regular expression of the [`Search`] cannot be built with
+    /// the new option, the [`Search`] is returned unchanged.
+    pub fn case_sensitive(self, is_case_sensitive: bool) -> Self {
+        Self {
+            is_case_sensitive,
+            ..self.clone()
+        }
+        .rebuild()
+        .unwrap_or(self)
+    }
+
+    /// Sets whether the [`Search`] must only match whole words.
+    ///
+    /// If the regular expression of the [`Search`] cannot be built with
+    /// the new option, the [`Search`] is returned unchanged.
+    pub fn whole_word(self, is_whole_word: bool) -> Self {
+        Self {
+            is_whole_word,
+            ..self.clone()
+        }
+        .rebuild()
+        .unwrap_or(self)
+    }
+
+    /// Returns the pattern of the [`Search`].
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns whether the pattern of the [`Search`] is a regular expression.
+    pub fn is_regex(&self) -> bool {
+        match self.matcher {
+            Matcher::Text => false,
+            #[cfg(feature = "regex")]
+            Matcher::Regex(_) => true,
+        }
+    }
+
+    /// Returns whether the [`Search`] is case sensitive.
+    pub fn is_case_sensitive(&self) -> bool {
+        self.is_case_sensitive
+    }
+
+    /// Returns whether the [`Search`] only matches whole words.
+    pub fn is_whole_word(&self) -> bool {
+        self.is_whole_word
+    }
+
+    /// Returns the [`Match`]es of the [`Search`] in the given line of text.
+    pub(super) fn find_in(&self, line: usize, text: &str) -> Vec<Match> {
+        let at = |start: usize, end: usize| Match {
+            start: Position {
+                line,
+                column: start,
+            },
+            end: Position { line, column: end },
+        };
+
+        match &self.matcher {
+            Matcher::Text => {
+                let mut matches = Vec::new();
+                let mut start = 0;
+
+                while start < text.len() {
+                    match self.text_match_at(text, start) {
+                        Some(end) => {
+                            matches.push(at(start, end));
+                            start = end;
+                        }
+                        None => {
+                            start += text[start..].chars().next().map_or(1, char::len_utf8);
+                        }
+                    }
+                }
+
+                matches
+            }
+            #[cfg(feature = "regex")]
+            Matcher::Regex(regex) => regex
+                .find_iter(text)
+                .filter(|found| !found.is_empty())
+                .map(|found| at(found.start(), found.end()))
+                .collect(),
+        }
+    }
+
+    /// Produces the replacement of the match at the given column of the
+    /// line of text.
+    #[cfg_attr(not(feature = "regex"), allow(unused_variables))]
+    pub(super) fn replacement(&self, text: &str, column: usize, replacement: &str) -> String {
+        match &self.matcher {
+            Matcher::Text => replacement.to_owned(),
+            #[cfg(feature = "regex")]
+            Matcher::Regex(regex) => {
+                let Some(captures) = regex.captures_at(text, column) else {
+                    return replacement.to_owned();
+                };
+
+                let mut result = String::new();
+                captures.expand(replacement, &mut result);
+
+                result
+            }
+        }
+    }
+
+    /// Returns the end of the plain text match starting at the given
+    /// index, if any.
+    fn text_match_at(&self, text: &str, start: usize) -> Option<usize> {
+        if self.pattern.is_empty() || (self.is_whole_word && !is_word_boundary(text, start)) {
+            return None;
+        }
+
+        let mut chars = text[start..].char_indices();
+
+        for expected in self.pattern.chars() {
+            let (_, c) = chars.next()?;
+
+            let is_equal = if self.is_case_sensitive {
+                c == expected
+            } else {
+                c == expected || c.to_lowercase().eq(expected.to_lowercase())
+            };
+
+            if !is_equal {
+                return None;
+            }
+        }
+
+        let end = chars.next().map_or(text.len(), |(i, _)| start + i);
+
+        (!self.is_whole_word || is_word_boundary(text, end)).then_some(end)
+    }
+
+    fn rebuild(self) -> Option<Self> {
+        match self.matcher {
+            Matcher::Text => Some(self),
+            #[cfg(feature = "regex")]
+            Matcher::Regex(_) => {
+                let regex = build(&self.pattern, self.is_case_sensitive, self.is_whole_word)
+                    .inspect_err(|error| log::warn!("Failed to rebuild search: {error}"))
+                    .ok()?;
+
+                Some(Self {
+                    matcher: Matcher::Regex(regex),
+                    ..self
+                })
+            }
+        }
+    }
+}
+
+impl PartialEq for Search {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+            && self.is_regex() == other.is_regex()
+            && self.is_case_sensitive == other.is_case_sensitive
+            && self.is_whole_word == other.is_whole_word
+    }
+}
+
+/// A match of a [`Search`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Match {
+    /// The [`Position`] where the [`Match`] starts.
+    pub start: Position,
+    /// The [`Position`] where the [`Match`] ends.
+    pub end: Position,
+}
+
+/// An error produced when creating a [`Search`].
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The regular expression is invalid.
+    #[error("invalid regular expression: {0}")]
+    InvalidRegex(String),
+}
+
+#[cfg(feature = "regex")]
+impl From<regex::Error> for Error {
+    fn from(error: regex::Error) -> Self {
+        Self::InvalidRegex(error.to_string())
+    }
+}
+
+/// Returns whether the given index of the text sits between a word
+/// character and a non-word character; like `\b` in a regular expression.
+fn is_word_boundary(text: &str, index: usize) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let before = text[..index].chars().next_back().is_some_and(is_word);
+    let after = text[index..].chars().next().is_some_and(is_word);
+
+    before != after
+}
+
+#[cfg(feature = "regex")]
+fn build(
+    pattern: &str,
+    is_case_sensitive: bool,
+    is_whole_word: bool,
+) -> Result<Regex, regex::Error> {
+    let pattern = if is_whole_word {
+        Cow::Owned(format!(r"\b(?:{pattern})\b"))
+    } else {
+        Cow::Borrowed(pattern)
+    };
+
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!is_case_sensitive)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns(search: &Search, text: &str) -> Vec<(usize, usize)> {
+        search
+            .find_in(0, text)
+            .into_iter()
+            .map(|found| (found.start.column, found.end.column))
+            .collect()
+    }
+
+    #[test]
+    fn text_searches_honor_their_options() {
+        let text = "Cat cat concat CAT";
+
+        assert_eq!(
+            columns(&Search::new("cat"), text),
+            vec![(0, 3), (4, 7), (11, 14), (15, 18)]
+        );
+
+        assert_eq!(
+            columns(&Search::new("cat").case_sensitive(true), text),
+            vec![(4, 7), (11, 14)]
+        );
+
+        assert_eq!(
+            columns(&Search::new("cat").whole_word(true), text),
+            vec![(0, 3), (4, 7), (15, 18)]
+        );
+
+        assert_eq!(columns(&Search::new("ñ"), "aÑñ"), vec![(1, 3), (3, 5)]);
+        assert!(columns(&Search::new(""), text).is_empty());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_searches_expand_their_captures() {
+        let search = Search::regex(r"(\w+)@(\w+)").expect("valid regex");
+
+        assert_eq!(columns(&search, "a@b, cd@ef"), vec![(0, 3), (5, 10)]);
+        assert_eq!(search.replacement("a@b, cd@ef", 5, "$2@$1"), "ef@cd");
+        assert!(Search::regex("(").is_err());
+    }
+}
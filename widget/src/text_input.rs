@@ -31,14 +31,19 @@
 //! }
 //! ```
 mod editor;
+mod format;
+mod history;
 mod value;
 
 pub mod cursor;
 
 pub use cursor::Cursor;
+pub use format::{Mask, Numeric};
 pub use value::Value;
 
 use editor::Editor;
+use format::Format;
+use history::{Group, History, Snapshot};
 
 use crate::core::alignment;
 use crate::core::clipboard::{self, Clipboard};
@@ -53,6 +58,7 @@ use crate::core::text::{self, Text};
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -102,6 +108,7 @@ where
     placeholder: String,
     value: Value,
     is_secure: bool,
+    format: Option<Format>,
     font: Option<Renderer::Font>,
     width: Length,
     padding: Padding,
@@ -133,6 +140,7 @@ where
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
+            format: None,
             font: None,
             width: Length::Fill,
             padding: DEFAULT_PADDING,
@@ -160,6 +168,28 @@ where
         self
     }
 
+    /// Constrains the contents of the [`TextInput`] to the given [`Mask`].
+    ///
+    /// The literal characters of the [`Mask`] are inserted while typing, and
+    /// any keystrokes that do not fit it are rejected.
+    pub fn mask(mut self, mask: impl Into<Mask>) -> Self {
+        self.format = Some(Format::Mask(mask.into()));
+        self
+    }
+
+    /// Turns the [`TextInput`] into a numeric field with the given [`Numeric`]
+    /// options.
+    ///
+    /// Numbers out of bounds can only be typed while more digits could still
+    /// bring them within bounds; they are clamped once the [`TextInput`] is
+    /// submitted, or left by clicking elsewhere or pressing Escape.
+    ///
+    /// The arrow keys step the value up and down.
+    pub fn numeric(mut self, numeric: Numeric) -> Self {
+        self.format = Some(Format::Numeric(numeric));
+        self
+    }
+
     /// Sets the message that should be produced when some text is typed into
     /// the [`TextInput`].
     ///
@@ -267,6 +297,27 @@ where
         self
     }
 
+    /// Replaces the [`Value`] of the [`TextInput`].
+    ///
+    /// This is useful for widgets that keep the text of an inner
+    /// [`TextInput`] in their own state.
+    pub(crate) fn set_value(&mut self, value: &str) {
+        self.value = Value::new(value);
+    }
+
+    /// Clamps the number of a numeric [`TextInput`] within bounds, producing
+    /// an input message if it changes.
+    fn clamp(&mut self, cursor: &mut Cursor, shell: &mut Shell<'_, Message>) {
+        if let (Some(on_input), Some(Format::Numeric(numeric))) = (&self.on_input, &self.format)
+            && let Some(text) = numeric.clamp(&self.value.to_string())
+        {
+            self.value = Value::new(&text);
+            cursor.move_to(self.value.len());
+
+            shell.publish(on_input(text));
+        }
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -634,6 +685,21 @@ where
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        operation.accessible(
+            self.id.as_ref(),
+            layout.bounds(),
+            &Accessible::new(Role::TextInput)
+                .label(self.placeholder.as_str())
+                .value(value.to_string())
+                .focused(state.is_focused())
+                .disabled(self.on_input.is_none()),
+        );
         operation.text_input(self.id.as_ref(), layout.bounds(), state);
         operation.focusable(self.id.as_ref(), layout.bounds(), state);
     }
@@ -669,6 +735,10 @@ where
 
                 let click_position = cursor.position_over(layout.bounds());
 
+                if state.is_focused.is_some() && click_position.is_none() {
+                    self.clamp(&mut state.cursor, shell);
+                }
+
                 state.is_focused = if click_position.is_some() {
                     let now = Instant::now();
 
@@ -865,13 +935,32 @@ where
                                 );
                             }
 
+                            let before = Snapshot::new(&self.value, state.cursor);
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
 
-                            let message = (on_input)(editor.contents());
+                            if !conform(
+                                self.format.as_ref(),
+                                &mut self.value,
+                                &mut state.cursor,
+                                &before,
+                                true,
+                            ) {
+                                shell.capture_event();
+                                return;
+                            }
+
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
                             shell.capture_event();
 
+                            state.history.record(
+                                before,
+                                Snapshot::new(&self.value, state.cursor),
+                                None,
+                            );
+
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
                             return;
@@ -898,17 +987,36 @@ where
                                 }
                             };
 
+                            let before = Snapshot::new(&self.value, state.cursor);
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
                             editor.paste(content.clone());
 
+                            if !conform(
+                                self.format.as_ref(),
+                                &mut self.value,
+                                &mut state.cursor,
+                                &before,
+                                false,
+                            ) {
+                                shell.capture_event();
+                                return;
+                            }
+
                             let message = if let Some(paste) = &self.on_paste {
-                                (paste)(editor.contents())
+                                (paste)(self.value.to_string())
                             } else {
-                                (on_input)(editor.contents())
+                                (on_input)(self.value.to_string())
                             };
                             shell.publish(message);
                             shell.capture_event();
 
+                            state.history.record(
+                                before,
+                                Snapshot::new(&self.value, state.cursor),
+                                None,
+                            );
+
                             state.is_pasting = Some(content);
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
@@ -928,6 +1036,33 @@ where
                             shell.capture_event();
                             return;
                         }
+                        Some(c @ ('z' | 'y')) if state.keyboard_modifiers.command() => {
+                            let Some(on_input) = &self.on_input else {
+                                return;
+                            };
+
+                            let current = Snapshot::new(&self.value, state.cursor);
+
+                            let snapshot = if c == 'z' && !state.keyboard_modifiers.shift() {
+                                state.history.undo(current)
+                            } else {
+                                state.history.redo(current)
+                            };
+
+                            if let Some(snapshot) = snapshot {
+                                self.value = Value::new(&snapshot.value);
+                                state.cursor = snapshot.cursor;
+                                state.is_pasting = None;
+
+                                shell.publish((on_input)(snapshot.value));
+
+                                focus.updated_at = Instant::now();
+                                update_cache(state, &self.value);
+                            }
+
+                            shell.capture_event();
+                            return;
+                        }
                         _ => {}
                     }
 
@@ -939,14 +1074,33 @@ where
                         state.is_pasting = None;
 
                         if let Some(c) = text.chars().next().filter(|c| !c.is_control()) {
+                            let before = Snapshot::new(&self.value, state.cursor);
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
 
                             editor.insert(c);
 
-                            let message = (on_input)(editor.contents());
+                            if !conform(
+                                self.format.as_ref(),
+                                &mut self.value,
+                                &mut state.cursor,
+                                &before,
+                                false,
+                            ) {
+                                shell.capture_event();
+                                return;
+                            }
+
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
                             shell.capture_event();
 
+                            state.history.record(
+                                before,
+                                Snapshot::new(&self.value, state.cursor),
+                                Some(Group::Insert),
+                            );
+
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
                             return;
@@ -961,6 +1115,8 @@ where
 
                     match modified_key.as_ref() {
                         keyboard::Key::Named(key::Named::Enter) => {
+                            self.clamp(&mut state.cursor, shell);
+
                             if let Some(on_submit) = self.on_submit.clone() {
                                 shell.publish(on_submit);
                                 shell.capture_event();
@@ -982,13 +1138,32 @@ where
                                 }
                             }
 
+                            let before = Snapshot::new(&self.value, state.cursor);
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
                             editor.backspace();
 
-                            let message = (on_input)(editor.contents());
+                            if !conform(
+                                self.format.as_ref(),
+                                &mut self.value,
+                                &mut state.cursor,
+                                &before,
+                                true,
+                            ) {
+                                shell.capture_event();
+                                return;
+                            }
+
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
                             shell.capture_event();
 
+                            state.history.record(
+                                before,
+                                Snapshot::new(&self.value, state.cursor),
+                                Some(Group::Backspace),
+                            );
+
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
                         }
@@ -1009,13 +1184,32 @@ where
                                 }
                             }
 
+                            let before = Snapshot::new(&self.value, state.cursor);
+
                             let mut editor = Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
 
-                            let message = (on_input)(editor.contents());
+                            if !conform(
+                                self.format.as_ref(),
+                                &mut self.value,
+                                &mut state.cursor,
+                                &before,
+                                true,
+                            ) {
+                                shell.capture_event();
+                                return;
+                            }
+
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
                             shell.capture_event();
 
+                            state.history.record(
+                                before,
+                                Snapshot::new(&self.value, state.cursor),
+                                Some(Group::Delete),
+                            );
+
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
                         }
@@ -1121,7 +1315,48 @@ where
 
                             shell.capture_event();
                         }
+                        keyboard::Key::Named(
+                            named @ (key::Named::ArrowUp | key::Named::ArrowDown),
+                        ) if matches!(self.format, Some(Format::Numeric(_))) => {
+                            let (Some(on_input), Some(Format::Numeric(numeric))) =
+                                (&self.on_input, &self.format)
+                            else {
+                                return;
+                            };
+
+                            let direction = if named == key::Named::ArrowUp {
+                                1.0
+                            } else {
+                                -1.0
+                            };
+
+                            let steps = if modifiers.shift() {
+                                direction * 10.0
+                            } else {
+                                direction
+                            };
+
+                            let before = Snapshot::new(&self.value, state.cursor);
+
+                            self.value =
+                                Value::new(&numeric.increment(&self.value.to_string(), steps));
+                            state.cursor.move_to(self.value.len());
+
+                            shell.publish((on_input)(self.value.to_string()));
+                            shell.capture_event();
+
+                            state.history.record(
+                                before,
+                                Snapshot::new(&self.value, state.cursor),
+                                None,
+                            );
+
+                            focus.updated_at = Instant::now();
+                            update_cache(state, &self.value);
+                        }
                         keyboard::Key::Named(key::Named::Escape) => {
+                            self.clamp(&mut state.cursor, shell);
+
                             state.is_focused = None;
                             state.is_dragging = None;
                             state.is_pasting = None;
@@ -1182,16 +1417,35 @@ where
                             return;
                         };
 
+                        let before = Snapshot::new(&self.value, state.cursor);
+
                         let mut editor = Editor::new(&mut self.value, &mut state.cursor);
                         editor.paste(Value::new(text));
 
                         focus.updated_at = Instant::now();
                         state.is_pasting = None;
 
-                        let message = (on_input)(editor.contents());
+                        if !conform(
+                            self.format.as_ref(),
+                            &mut self.value,
+                            &mut state.cursor,
+                            &before,
+                            false,
+                        ) {
+                            shell.capture_event();
+                            return;
+                        }
+
+                        let message = (on_input)(self.value.to_string());
                         shell.publish(message);
                         shell.capture_event();
 
+                        state.history.record(
+                            before,
+                            Snapshot::new(&self.value, state.cursor),
+                            None,
+                        );
+
                         update_cache(state, &self.value);
                     }
                 }
@@ -1345,9 +1599,48 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    history: History,
     // TODO: Add stateful horizontal scrolling offset
 }
 
+/// Conforms the edited [`Value`] of a [`TextInput`] to its [`Format`], if any.
+///
+/// Returns `false` if the edit must be rejected, restoring the value and
+/// the cursor from before the edit.
+fn conform(
+    format: Option<&Format>,
+    value: &mut Value,
+    cursor: &mut Cursor,
+    before: &Snapshot,
+    is_deletion: bool,
+) -> bool {
+    let Some(format) = format else {
+        return true;
+    };
+
+    let text = value.to_string();
+    let position = value.until(cursor.end(value)).to_string().chars().count();
+
+    let conformed = match format {
+        Format::Mask(mask) => Some(mask.conform(&text, position, is_deletion)),
+        Format::Numeric(numeric) => numeric.conform(&text, position),
+    };
+
+    if let Some((text, position)) = conformed {
+        let prefix: String = text.chars().take(position).collect();
+
+        *value = Value::new(&text);
+        cursor.move_to(Value::new(&prefix).len());
+
+        true
+    } else {
+        *value = Value::new(&before.value);
+        *cursor = before.cursor;
+
+        false
+    }
+}
+
 fn state<Renderer: text::Renderer>(tree: &mut Tree) -> &mut State<Renderer::Paragraph> {
     tree.state.downcast_mut::<State<Renderer::Paragraph>>()
 }
@@ -1423,6 +1716,11 @@ impl<P: text::Paragraph> State<P> {
     pub fn select_range(&mut self, start: usize, end: usize) {
         self.cursor.select_range(start, end);
     }
+
+    /// Clears the undo and redo history of the [`TextInput`].
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
 }
 
 impl<P: text::Paragraph> operation::Focusable for State<P> {
@@ -1467,6 +1765,10 @@ impl<P: text::Paragraph> operation::TextInput for State<P> {
     fn select_range(&mut self, start: usize, end: usize) {
         State::select_range(self, start, end);
     }
+
+    fn clear_history(&mut self) {
+        State::clear_history(self);
+    }
 }
 
 fn offset<P: text::Paragraph>(text_bounds: Rectangle, value: &Value, state: &State<P>) -> f32 {
@@ -1577,7 +1879,7 @@ pub enum Status {
 }
 
 /// The appearance of a text input.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The [`Background`] of the text input.
     pub background: Background,
@@ -0,0 +1,476 @@
+//! Constrain the contents of a [`TextInput`] while it is being edited.
+//!
+//! [`TextInput`]: super::TextInput
+
+/// A declarative input mask for a [`TextInput`].
+///
+/// A mask is built from a pattern, where the following characters match
+/// user input:
+///
+/// - `9` matches a digit.
+/// - `#` optionally matches a digit.
+/// - `a` matches a letter.
+/// - `A` matches a letter, which is turned uppercase.
+/// - `*` matches a letter or a digit.
+///
+/// Any other character is a literal that is inserted automatically while
+/// typing. A `\` turns the next character into a literal.
+///
+/// Keystrokes that do not fit the mask are rejected.
+///
+/// # Example
+/// ```
+/// use iced_widget::text_input::Mask;
+///
+/// let phone = Mask::new("(999) 999-9999");
+/// let iban = Mask::new("AA99 **** **** **** **** **");
+/// let date = Mask::new("9999-99-99");
+/// let ip = Mask::new("9##.9##.9##.9##");
+///
+/// assert_eq!(phone.apply("5551234567"), "(555) 123-4567");
+/// assert_eq!(ip.apply("192.168.1.1"), "192.168.1.1");
+/// assert!(!date.is_complete("2025-01"));
+/// ```
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Literal(char),
+    Slot { kind: Kind, is_optional: bool },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Digit,
+    Letter,
+    Uppercase,
+    Alphanumeric,
+}
+
+impl Kind {
+    fn accept(self, c: char) -> Option<char> {
+        match self {
+            Kind::Digit => c.is_ascii_digit().then_some(c),
+            Kind::Letter => c.is_alphabetic().then_some(c),
+            Kind::Uppercase => c
+                .is_alphabetic()
+                .then(|| c.to_uppercase().next().unwrap_or(c)),
+            Kind::Alphanumeric => c.is_alphanumeric().then_some(c),
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            let slot = |kind, is_optional| Token::Slot { kind, is_optional };
+
+            tokens.push(match c {
+                '9' => slot(Kind::Digit, false),
+                '#' => slot(Kind::Digit, true),
+                'a' => slot(Kind::Letter, false),
+                'A' => slot(Kind::Uppercase, false),
+                '*' => slot(Kind::Alphanumeric, false),
+                '\\' => Token::Literal(chars.next().unwrap_or('\\')),
+                c => Token::Literal(c),
+            });
+        }
+
+        Self { tokens }
+    }
+
+    /// Formats the given text with the [`Mask`], dropping any characters
+    /// that do not fit.
+    pub fn apply(&self, text: &str) -> String {
+        self.conform(text, 0, false).0
+    }
+
+    /// Returns whether the given text fills every required slot of the
+    /// [`Mask`].
+    pub fn is_complete(&self, text: &str) -> bool {
+        let mut position = 0;
+
+        for c in text.chars() {
+            match self.accept(position, c) {
+                Some((_, next)) => position = next,
+                None => return false,
+            }
+        }
+
+        self.tokens[position..].iter().all(|token| {
+            matches!(
+                token,
+                Token::Slot {
+                    is_optional: true,
+                    ..
+                }
+            )
+        })
+    }
+
+    /// Tries to fit a character at the given token position.
+    ///
+    /// Returns the characters to insert—including any literals that
+    /// precede it—and the position of the next token.
+    fn accept(&self, mut position: usize, c: char) -> Option<(String, usize)> {
+        let mut inserted = String::new();
+
+        while let Some(token) = self.tokens.get(position) {
+            position += 1;
+
+            match *token {
+                Token::Literal(literal) => {
+                    inserted.push(literal);
+
+                    if literal == c {
+                        return Some((inserted, position));
+                    }
+                }
+                Token::Slot { kind, is_optional } => {
+                    if let Some(c) = kind.accept(c) {
+                        inserted.push(c);
+
+                        return Some((inserted, position));
+                    }
+
+                    if !is_optional {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Conforms an edited text to the [`Mask`], mapping the given cursor
+    /// position (in characters) into the result.
+    ///
+    /// If `trim` is set, trailing literals are dropped; so that deleting
+    /// characters does not get stuck on them.
+    pub(crate) fn conform(&self, text: &str, cursor: usize, trim: bool) -> (String, usize) {
+        let mut result = String::new();
+        let mut literals = Vec::new();
+        let mut position = 0;
+        let mut mapped = None;
+
+        for (i, c) in text.chars().enumerate() {
+            if i == cursor {
+                mapped = Some(literals.len());
+            }
+
+            if let Some((inserted, next)) = self.accept(position, c) {
+                let count = inserted.chars().count();
+
+                // Every inserted character but the last one is a literal
+                literals.extend(std::iter::repeat_n(true, count - 1));
+                literals.push(matches!(self.tokens[next - 1], Token::Literal(_)));
+
+                result.push_str(&inserted);
+                position = next;
+            }
+        }
+
+        if trim {
+            while literals.last() == Some(&true) {
+                let _ = literals.pop();
+                let _ = result.pop();
+            }
+        }
+
+        let length = literals.len();
+
+        (result, mapped.unwrap_or(length).min(length))
+    }
+}
+
+impl From<&str> for Mask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+/// A numeric mode for a [`TextInput`].
+///
+/// Only numbers within the given bounds can be typed, the decimal separator
+/// follows the configured locale, and the arrow keys step the value up and
+/// down—ten times faster while holding shift.
+///
+/// # Example
+/// ```
+/// use iced_widget::text_input::Numeric;
+///
+/// let price = Numeric::new()
+///     .min(0.0)
+///     .max(1000.0)
+///     .step(0.05)
+///     .decimal_separator(',');
+///
+/// assert_eq!(price.parse("12,35"), Some(12.35));
+/// assert_eq!(price.format(3.5), "3,50");
+/// ```
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Numeric {
+    min: Option<f64>,
+    max: Option<f64>,
+    step: f64,
+    decimals: Option<usize>,
+    separator: char,
+}
+
+impl Numeric {
+    /// Creates a new [`Numeric`] mode for integers with no bounds.
+    pub fn new() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: 1.0,
+            decimals: None,
+            separator: '.',
+        }
+    }
+
+    /// Sets the minimum value of the [`Numeric`] mode.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`Numeric`] mode.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount the arrow keys increase or decrease the value by.
+    ///
+    /// Unless set explicitly with [`decimals`](Self::decimals), the amount
+    /// of decimals allowed is inferred from the step.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Sets the maximum amount of decimals of the [`Numeric`] mode.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Sets the decimal separator of the [`Numeric`] mode.
+    ///
+    /// Both `.` and `,` typed by the user are turned into this separator.
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Parses the contents of a numeric [`TextInput`].
+    ///
+    /// [`TextInput`]: super::TextInput
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        text.replace(self.separator, ".").parse().ok()
+    }
+
+    /// Formats a number for a numeric [`TextInput`].
+    ///
+    /// [`TextInput`]: super::TextInput
+    pub fn format(&self, number: f64) -> String {
+        format!("{number:.*}", self.decimal_places()).replace('.', &self.separator.to_string())
+    }
+
+    fn decimal_places(&self) -> usize {
+        self.decimals.unwrap_or_else(|| {
+            let step = self.step.to_string();
+
+            step.split_once('.')
+                .map(|(_, decimals)| decimals.len().min(10))
+                .unwrap_or(0)
+        })
+    }
+
+    /// Conforms an edited text to the [`Numeric`] mode, mapping the given
+    /// cursor position (in characters) into the result.
+    ///
+    /// Returns `None` if the edit produces a value out of bounds that no
+    /// further typing can bring back; the rest are clamped by [`clamp`].
+    ///
+    /// [`clamp`]: Self::clamp
+    pub(crate) fn conform(&self, text: &str, cursor: usize) -> Option<(String, usize)> {
+        let decimals = self.decimal_places();
+        let allows_negative = self.min.is_none_or(|min| min < 0.0);
+
+        let mut result = String::new();
+        let mut mapped = None;
+        let mut fraction = None;
+
+        for (i, c) in text.chars().enumerate() {
+            if i == cursor {
+                mapped = Some(result.chars().count());
+            }
+
+            match c {
+                '-' if allows_negative && result.is_empty() => {}
+                '0'..='9' if fraction.is_none_or(|digits| digits < decimals) => {
+                    fraction = fraction.map(|digits| digits + 1);
+                }
+                '.' | ',' if decimals > 0 && fraction.is_none() => {
+                    fraction = Some(0);
+                    result.push(self.separator);
+                    continue;
+                }
+                c if c == self.separator && decimals > 0 && fraction.is_none() => {
+                    fraction = Some(0);
+                }
+                _ => continue,
+            }
+
+            result.push(c);
+        }
+
+        // Typing more digits only moves a number away from zero
+        if let Some(number) = self.parse(&result) {
+            let is_too_big = self.max.is_some_and(|max| number > max && number > 0.0);
+            let is_too_small = self.min.is_some_and(|min| number < min && number < 0.0);
+
+            if is_too_big || is_too_small {
+                return None;
+            }
+        }
+
+        let length = result.chars().count();
+
+        Some((result, mapped.unwrap_or(length).min(length)))
+    }
+
+    /// Clamps the number in the given text within bounds, once the user is
+    /// done editing it.
+    ///
+    /// Returns `None` if there is no number or it is already within bounds.
+    pub(crate) fn clamp(&self, text: &str) -> Option<String> {
+        let number = self.parse(text)?;
+
+        let clamped = number
+            .max(self.min.unwrap_or(f64::NEG_INFINITY))
+            .min(self.max.unwrap_or(f64::INFINITY));
+
+        (clamped != number).then(|| self.format(clamped))
+    }
+
+    /// Steps the number in the given text by the given amount of steps,
+    /// clamping it within bounds.
+    pub(crate) fn increment(&self, text: &str, steps: f64) -> String {
+        let current = self
+            .parse(text)
+            .unwrap_or_else(|| self.min.unwrap_or(0.0).max(0.0) - self.step * steps);
+
+        let mut number = current + self.step * steps;
+
+        if let Some(min) = self.min {
+            number = number.max(min);
+        }
+
+        if let Some(max) = self.max {
+            number = number.min(max);
+        }
+
+        self.format(number)
+    }
+}
+
+impl Default for Numeric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The way the contents of a [`TextInput`] are constrained.
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Format {
+    Mask(Mask),
+    Numeric(Numeric),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_insert_literals_and_reject_keystrokes() {
+        let phone = Mask::new("(999) 999-9999");
+
+        assert_eq!(phone.conform("(555", 4, false), ("(555".to_owned(), 4));
+        assert_eq!(phone.conform("(5551", 5, false), ("(555) 1".to_owned(), 7));
+        assert_eq!(phone.conform("(555x", 5, false), ("(555".to_owned(), 4));
+        assert_eq!(phone.conform("(555) ", 6, true), ("(555".to_owned(), 4));
+        assert_eq!(phone.apply("555-123-45678"), "(555) 123-4567");
+
+        let iban = Mask::new("AA99 **** ****");
+
+        assert_eq!(iban.apply("de89 3704 0044"), "DE89 3704 0044");
+        assert!(iban.is_complete("DE89 3704 0044"));
+        assert!(!iban.is_complete("DE89"));
+
+        let ip = Mask::new("9##.9##.9##.9##");
+
+        assert_eq!(ip.apply("10.0.0.1"), "10.0.0.1");
+        assert_eq!(ip.apply("2552552551"), "255.255.255.1");
+        assert!(ip.is_complete("1.2.3.4"));
+        assert!(!ip.is_complete("1.2.3"));
+
+        assert_eq!(Mask::new("\\9-99").apply("12"), "9-12");
+    }
+
+    #[test]
+    fn numeric_mode_filters_and_steps() {
+        let percent = Numeric::new().min(0.0).max(100.0);
+
+        assert_eq!(percent.conform("4a2", 3), Some(("42".to_owned(), 2)));
+        assert_eq!(percent.conform("-4", 2), Some(("4".to_owned(), 1)));
+        assert_eq!(percent.conform("420", 3), None);
+        assert_eq!(percent.conform("4.5", 3), Some(("45".to_owned(), 2)));
+        assert_eq!(percent.increment("99", 10.0), "100");
+        assert_eq!(percent.increment("", 1.0), "0");
+
+        let price = Numeric::new().step(0.05).decimal_separator(',');
+
+        assert_eq!(price.conform("3.141", 5), Some(("3,14".to_owned(), 4)));
+        assert_eq!(price.conform("-1,5", 4), Some(("-1,5".to_owned(), 4)));
+        assert_eq!(price.increment("0,1", 1.0), "0,15");
+        assert_eq!(price.increment("0,1", -1.0), "0,05");
+    }
+
+    #[test]
+    fn numbers_that_may_still_fit_are_clamped_later() {
+        let adults = Numeric::new().min(18.0).max(120.0);
+
+        // More digits could bring these within bounds
+        assert_eq!(adults.conform("5", 1), Some(("5".to_owned(), 1)));
+        assert_eq!(adults.conform("1", 1), Some(("1".to_owned(), 1)));
+        assert_eq!(adults.conform("500", 3), None);
+        assert_eq!(adults.clamp("5"), Some("18".to_owned()));
+        assert_eq!(adults.clamp("42"), None);
+
+        let debt = Numeric::new().min(-1000.0).max(-10.0);
+
+        assert_eq!(debt.conform("-1", 2), Some(("-1".to_owned(), 2)));
+        assert_eq!(debt.conform("5", 1), None);
+        assert_eq!(debt.conform("-5000", 5), None);
+        assert_eq!(debt.clamp("-1"), Some("-10".to_owned()));
+        assert_eq!(debt.clamp("-100"), None);
+        assert_eq!(debt.clamp(""), None);
+    }
+}
@@ -0,0 +1,213 @@
+use crate::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The undo and redo stacks of a [`TextInput`].
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    group: Option<Group>,
+    last: Option<Snapshot>,
+}
+
+impl History {
+    const LIMIT: usize = 100;
+
+    /// Records an edit that turned `before` into `after`.
+    ///
+    /// Consecutive edits of the same [`Group`] are merged into a single
+    /// entry, as long as nothing else touched the value or the cursor
+    /// in between.
+    pub fn record(&mut self, before: Snapshot, after: Snapshot, group: Option<Group>) {
+        if before == after {
+            return;
+        }
+
+        let continues =
+            group.is_some() && self.group == group && self.last.as_ref() == Some(&before);
+
+        if !continues {
+            self.undo.push_back(before);
+            self.redo.clear();
+
+            while self.undo.len() > Self::LIMIT {
+                let _ = self.undo.pop_front();
+            }
+        }
+
+        self.group = group;
+        self.last = Some(after);
+    }
+
+    pub fn undo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        self.interrupt();
+
+        // The value may have been changed by the application in between,
+        // so we skip any entry that would not change anything
+        while let Some(snapshot) = self.undo.pop_back() {
+            if snapshot != current {
+                self.redo.push(current);
+
+                return Some(snapshot);
+            }
+        }
+
+        None
+    }
+
+    pub fn redo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        self.interrupt();
+
+        let snapshot = self.redo.pop()?;
+        self.undo.push_back(current);
+
+        Some(snapshot)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn interrupt(&mut self) {
+        self.group = None;
+        self.last = None;
+    }
+}
+
+/// The value and cursor of a [`TextInput`] at some point in time.
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub value: String,
+    pub cursor: Cursor,
+}
+
+impl Snapshot {
+    pub fn new(value: &Value, cursor: Cursor) -> Self {
+        Self {
+            value: value.to_string(),
+            cursor,
+        }
+    }
+}
+
+/// A kind of edit whose consecutive occurrences are undone together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Group {
+    Insert,
+    Backspace,
+    Delete,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(value: &str) -> Snapshot {
+        let mut cursor = Cursor::default();
+        cursor.move_to(value.len());
+
+        Snapshot::new(&Value::new(value), cursor)
+    }
+
+    fn type_text(history: &mut History, from: &str, text: &str) -> String {
+        let mut value = from.to_owned();
+
+        for c in text.chars() {
+            let before = snapshot(&value);
+            value.push(c);
+
+            history.record(before, snapshot(&value), Some(Group::Insert));
+        }
+
+        value
+    }
+
+    #[test]
+    fn typing_runs_are_undone_together() {
+        let mut history = History::default();
+
+        let value = type_text(&mut history, "", "hello");
+
+        let undone = history.undo(snapshot(&value)).expect("undo");
+        assert_eq!(undone, snapshot(""));
+        assert!(history.undo(undone.clone()).is_none());
+
+        let redone = history.redo(undone).expect("redo");
+        assert_eq!(redone, snapshot("hello"));
+        assert!(history.redo(redone).is_none());
+    }
+
+    #[test]
+    fn different_groups_are_undone_separately() {
+        let mut history = History::default();
+
+        let value = type_text(&mut history, "", "ab");
+        history.record(snapshot(&value), snapshot("a"), Some(Group::Backspace));
+
+        assert_eq!(history.undo(snapshot("a")), Some(snapshot("ab")));
+        assert_eq!(history.undo(snapshot("ab")), Some(snapshot("")));
+    }
+
+    #[test]
+    fn new_edits_invalidate_redo() {
+        let mut history = History::default();
+
+        let value = type_text(&mut history, "", "abc");
+        let undone = history.undo(snapshot(&value)).expect("undo");
+
+        history.record(undone, snapshot("x"), None);
+
+        assert!(history.redo(snapshot("x")).is_none());
+    }
+
+    #[test]
+    fn undo_skips_entries_matching_the_current_value() {
+        let mut history = History::default();
+
+        history.record(snapshot(""), snapshot("a"), None);
+        history.record(snapshot("a"), snapshot("ab"), None);
+
+        // The application reset the value to "a" behind our back
+        assert_eq!(history.undo(snapshot("a")), Some(snapshot("")));
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut history = History::default();
+
+        for i in 0..History::LIMIT + 10 {
+            history.record(
+                snapshot(&i.to_string()),
+                snapshot(&(i + 1).to_string()),
+                None,
+            );
+        }
+
+        let mut current = snapshot(&(History::LIMIT + 10).to_string());
+        let mut undone = 0;
+
+        while let Some(snapshot) = history.undo(current.clone()) {
+            current = snapshot;
+            undone += 1;
+        }
+
+        assert_eq!(undone, History::LIMIT);
+        assert_eq!(current, snapshot("10"));
+    }
+
+    #[test]
+    fn clear_forgets_everything() {
+        let mut history = History::default();
+
+        let value = type_text(&mut history, "", "abc");
+        history.clear();
+
+        assert!(history.undo(snapshot(&value)).is_none());
+        assert!(history.redo(snapshot(&value)).is_none());
+    }
+}
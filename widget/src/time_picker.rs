@@ -0,0 +1,959 @@
+//! Time pickers let users choose a time of the day.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::time_picker;
+//! use iced::widget::time_picker::Time;
+//!
+//! struct State {
+//!    alarm: Option<Time>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     AlarmSelected(Time),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     time_picker("Alarm", state.alarm, Message::AlarmSelected)
+//!         .step(15)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::AlarmSelected(alarm) => {
+//!             state.alarm = Some(alarm);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::operation::Operation;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget, border, widget,
+};
+use crate::date_picker::{self, Catalog, Style, StyleFn};
+use crate::text_input::{self, TextInput};
+
+use std::fmt;
+
+/// The padding around the dropdown of a [`TimePicker`].
+const PADDING: f32 = 8.0;
+
+/// The amount of columns of the dropdown of a [`TimePicker`].
+const COLUMNS: usize = 6;
+
+/// The space between the hours and the minutes of the dropdown.
+const SPACING: f32 = 8.0;
+
+/// The default step between the minutes of a [`TimePicker`].
+pub const DEFAULT_STEP: u32 = 5;
+
+/// A text input with a dropdown to pick a time of the day.
+///
+/// The time can either be typed in `HH:MM` format or picked from the
+/// dropdown, which opens while the input is focused and lists the hours of
+/// the day followed by the minutes of an hour. Picking an hour keeps the
+/// dropdown open, while picking the minutes closes it.
+///
+/// The up and down arrows move the highlighted time by a [`step`], and
+/// Enter picks it.
+///
+/// [`step`]: Self::step
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::time_picker;
+/// use iced::widget::time_picker::Time;
+///
+/// struct State {
+///    meeting: Option<Time>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     MeetingSelected(Time),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     time_picker("Meeting", state.meeting, Message::MeetingSelected)
+///         .min(Time::from_hm(9, 0).unwrap())
+///         .max(Time::from_hm(17, 30).unwrap())
+///         .step(30)
+///         .into()
+/// }
+/// ```
+pub struct TimePicker<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    text_input: TextInput<'a, TextInputEvent, Theme, Renderer>,
+    value: Option<Time>,
+    on_select: Box<dyn Fn(Time) -> Message + 'a>,
+    min: Option<Time>,
+    max: Option<Time>,
+    step: u32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`TimePicker`] with the given placeholder, the current
+    /// time, and the message to produce when a time is picked.
+    pub fn new(
+        placeholder: &str,
+        value: Option<Time>,
+        on_select: impl Fn(Time) -> Message + 'a,
+    ) -> Self {
+        let text = value.as_ref().map(Time::to_string).unwrap_or_default();
+
+        let text_input = TextInput::new(placeholder, &text)
+            .on_input(TextInputEvent::TextChanged)
+            .class(Theme::default_input());
+
+        Self {
+            text_input,
+            value,
+            on_select: Box::new(on_select),
+            min: None,
+            max: None,
+            step: DEFAULT_STEP,
+            text_size: None,
+            font: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`TimePicker`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the earliest [`Time`] that can be picked.
+    pub fn min(mut self, min: Time) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Time`] that can be picked.
+    pub fn max(mut self, max: Time) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the step between the minutes listed in the dropdown of the
+    /// [`TimePicker`].
+    ///
+    /// By default, it is [`DEFAULT_STEP`] minutes.
+    pub fn step(mut self, minutes: u32) -> Self {
+        self.step = minutes.clamp(1, 60);
+        self
+    }
+
+    /// Sets the width of the [`TimePicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TimePicker`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_input = self.text_input.size(size);
+        self.text_size = Some(size);
+
+        self
+    }
+
+    /// Sets the [`Renderer::Font`] of the [`TimePicker`].
+    ///
+    /// [`Renderer::Font`]: text::Renderer
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the [`text_input::Icon`] of the [`TimePicker`].
+    pub fn icon(mut self, icon: text_input::Icon<Renderer::Font>) -> Self {
+        self.text_input = self.text_input.icon(icon);
+        self
+    }
+
+    /// Sets the style of the input of the [`TimePicker`].
+    #[must_use]
+    pub fn input_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        <Theme as text_input::Catalog>::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style of the dropdown of the [`TimePicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the input of the [`TimePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn input_class(
+        mut self,
+        class: impl Into<<Theme as text_input::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.text_input = self.text_input.class(class);
+        self
+    }
+
+    /// Sets the style class of the dropdown of the [`TimePicker`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_enabled(&self, time: Time) -> bool {
+        self.min.is_none_or(|min| time >= min) && self.max.is_none_or(|max| time <= max)
+    }
+
+    fn minutes(&self) -> std::iter::StepBy<std::ops::Range<u32>> {
+        (0..60).step_by(self.step as usize)
+    }
+
+    fn cell_size(&self, renderer: &Renderer) -> Size {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size()).0;
+
+        Size::new(text_size * 2.5, text_size * 2.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TextInputEvent {
+    TextChanged(String),
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_open: bool,
+    text: Option<String>,
+    cursor: Option<Time>,
+    hovered: Option<Target>,
+}
+
+impl State {
+    fn sync(&mut self, is_focused: bool, value: Option<Time>) {
+        if is_focused && !self.is_open {
+            self.is_open = true;
+            self.cursor = value;
+        } else if !is_focused && self.is_open {
+            *self = Self::default();
+        }
+    }
+}
+
+fn is_focused<Renderer: text::Renderer>(tree: &Tree) -> bool {
+    tree.state
+        .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+        .is_focused()
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TimePicker<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        Widget::<TextInputEvent, Theme, Renderer>::diff(&self.text_input, &mut tree.children[0]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<TextInputEvent, Theme, Renderer>::size(&self.text_input)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(text) = &state.text {
+            self.text_input.set_value(text);
+        }
+
+        self.text_input
+            .layout(&mut tree.children[0], renderer, limits, None)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let is_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        operation.container(None, layout.bounds());
+        operation.accessible(
+            None,
+            layout.bounds(),
+            &Accessible::new(Role::ComboBox)
+                .value(self.value.as_ref().map(Time::to_string).unwrap_or_default())
+                .expanded(is_focused),
+        );
+        operation.traverse(&mut |operation| {
+            Widget::<TextInputEvent, Theme, Renderer>::operate(
+                &mut self.text_input,
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let started_focused = is_focused::<Renderer>(&tree.children[0]);
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(text) = &state.text {
+            self.text_input.set_value(text);
+        }
+
+        let mut local_messages = Vec::new();
+        let mut local_shell = Shell::new(&mut local_messages);
+
+        self.text_input.update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+
+        for operation in local_shell.take_operations() {
+            shell.request_operation(operation);
+        }
+
+        for message in local_messages {
+            let TextInputEvent::TextChanged(text) = message;
+
+            let time = Time::parse(&text).filter(|time| self.is_enabled(*time));
+            state.text = Some(text);
+
+            if let Some(time) = time {
+                state.cursor = Some(time);
+
+                if self.value != Some(time) {
+                    shell.publish((self.on_select)(time));
+                }
+            }
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        let is_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        if started_focused != is_focused {
+            state.sync(is_focused, self.value);
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.text_input
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let text = state.text.as_deref().map(text_input::Value::new);
+
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor,
+            text.as_ref(),
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let is_focused = is_focused::<Renderer>(&tree.children[0]);
+
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+        state.sync(is_focused, self.value);
+
+        if !is_focused {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(Box::new(Dropdown {
+            picker: self,
+            state,
+            input: children[0]
+                .state
+                .downcast_mut::<text_input::State<Renderer::Paragraph>>(),
+            position: layout.position() + translation,
+            target_height: bounds.height,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TimePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(time_picker: TimePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(time_picker)
+    }
+}
+
+/// A cell of the dropdown of a [`TimePicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Hour(u32),
+    Minute(u32),
+}
+
+struct Dropdown<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    picker: &'a TimePicker<'b, Message, Theme, Renderer>,
+    state: &'a mut State,
+    input: &'a mut text_input::State<Renderer::Paragraph>,
+    position: Point,
+    target_height: f32,
+}
+
+impl<Message, Theme, Renderer> Dropdown<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the time that is currently highlighted.
+    fn current(&self) -> Time {
+        self.state
+            .cursor
+            .or(self.picker.value)
+            .unwrap_or(Time::MIDNIGHT)
+    }
+
+    /// Returns the targets of the dropdown with their bounds.
+    fn targets(&self, bounds: Rectangle, cell: Size) -> Vec<(Target, Rectangle)> {
+        let origin = Point::new(bounds.x + PADDING, bounds.y + PADDING);
+
+        let cell_bounds = |index: usize, offset: f32| Rectangle {
+            x: origin.x + (index % COLUMNS) as f32 * cell.width,
+            y: origin.y + offset + (index / COLUMNS) as f32 * cell.height,
+            width: cell.width,
+            height: cell.height,
+        };
+
+        let hours = (0..24).map(|hour| (Target::Hour(hour), cell_bounds(hour as usize, 0.0)));
+
+        let offset = (24 / COLUMNS) as f32 * cell.height + SPACING;
+
+        let minutes = self
+            .picker
+            .minutes()
+            .enumerate()
+            .map(|(index, minute)| (Target::Minute(minute), cell_bounds(index, offset)));
+
+        hours.chain(minutes).collect()
+    }
+
+    fn target(&self, bounds: Rectangle, cell: Size, cursor: mouse::Cursor) -> Option<Target> {
+        self.targets(bounds, cell)
+            .into_iter()
+            .find(|(_, bounds)| cursor.is_over(*bounds))
+            .map(|(target, _)| target)
+    }
+
+    fn time(&self, target: Target) -> Time {
+        let current = self.current();
+
+        match target {
+            Target::Hour(hour) => Time {
+                hour,
+                minute: current.minute,
+            },
+            Target::Minute(minute) => Time {
+                hour: current.hour,
+                minute,
+            },
+        }
+    }
+
+    fn is_enabled(&self, target: Target) -> bool {
+        match target {
+            Target::Hour(hour) => self
+                .picker
+                .minutes()
+                .any(|minute| self.picker.is_enabled(Time { hour, minute })),
+            Target::Minute(_) => self.picker.is_enabled(self.time(target)),
+        }
+    }
+
+    /// Returns the time picked by clicking the given target, if enabled.
+    ///
+    /// Picking an hour keeps the current minute, or moves to the first
+    /// minute of that hour within the range.
+    fn time_at(&self, target: Target) -> Option<Time> {
+        if !self.is_enabled(target) {
+            return None;
+        }
+
+        let mut time = self.time(target);
+
+        if !self.picker.is_enabled(time)
+            && let Some(minute) = self
+                .picker
+                .minutes()
+                .find(|&minute| self.picker.is_enabled(Time { minute, ..time }))
+        {
+            time.minute = minute;
+        }
+
+        Some(time)
+    }
+
+    fn pick(&mut self, time: Time, close: bool, shell: &mut Shell<'_, Message>) {
+        shell.publish((self.picker.on_select)(time));
+
+        if close {
+            self.input.unfocus();
+            *self.state = State::default();
+        } else {
+            self.state.text = None;
+            self.state.cursor = Some(time);
+        }
+
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Dropdown<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let cell = self.picker.cell_size(renderer);
+        let minute_rows = self.picker.minutes().count().div_ceil(COLUMNS);
+
+        let size = Size::new(
+            cell.width * COLUMNS as f32 + PADDING * 2.0,
+            cell.height * (24 / COLUMNS + minute_rows) as f32 + SPACING + PADDING * 2.0,
+        );
+
+        layout::Node::new(size).move_to(date_picker::dropdown_position(
+            self.position,
+            self.target_height,
+            size,
+            bounds,
+        ))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+        let cell = self.picker.cell_size(renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let hovered = self.target(bounds, cell, cursor);
+
+                if self.state.hovered != hovered {
+                    self.state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(bounds) {
+                    return;
+                }
+
+                if let Some(target) = self.target(bounds, cell, cursor)
+                    && let Some(time) = self.time_at(target)
+                {
+                    self.pick(time, matches!(target, Target::Minute(_)), shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) => {
+                let current = self.current();
+                let step = self.picker.step as i32;
+
+                match named {
+                    key::Named::ArrowUp => self.state.cursor = Some(current.add_minutes(-step)),
+                    key::Named::ArrowDown => self.state.cursor = Some(current.add_minutes(step)),
+                    key::Named::Enter => match self.state.cursor {
+                        Some(time) if self.picker.is_enabled(time) => {
+                            self.pick(time, true, shell);
+                        }
+                        Some(_) => {}
+                        None => {
+                            self.input.unfocus();
+                            *self.state = State::default();
+
+                            shell.invalidate_layout();
+                        }
+                    },
+                    _ => return,
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if !cursor.is_over(bounds) {
+            return mouse::Interaction::None;
+        }
+
+        match self.target(bounds, self.picker.cell_size(renderer), cursor) {
+            Some(target) if self.is_enabled(target) => mouse::Interaction::Pointer,
+            Some(_) => mouse::Interaction::NotAllowed,
+            None => mouse::Interaction::Idle,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = <Theme as Catalog>::style(theme, &self.picker.class);
+        let bounds = layout.bounds();
+
+        let text_size = self
+            .picker
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self.picker.font.unwrap_or_else(|| renderer.default_font());
+        let cell = self.picker.cell_size(renderer);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            style.background.clone(),
+        );
+
+        let current = self.current();
+
+        for (target, bounds) in self.targets(bounds, cell) {
+            let (is_selected, label) = match target {
+                Target::Hour(hour) => (hour == current.hour, format!("{hour:02}")),
+                Target::Minute(minute) => (minute == current.minute, format!(":{minute:02}")),
+            };
+
+            let is_enabled = self.is_enabled(target);
+
+            let background: Option<Background> = if is_selected {
+                Some(style.selected_background.clone())
+            } else if is_enabled && self.state.hovered == Some(target) {
+                Some(style.hovered_background.clone())
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bounds.shrink(1.0),
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            let text_color = if is_selected {
+                style.selected_text_color
+            } else if !is_enabled {
+                style.disabled_text_color
+            } else {
+                style.text_color
+            };
+
+            date_picker::fill_label(renderer, &label, bounds, text_size, font, text_color);
+        }
+    }
+}
+
+/// A time of the day, with a precision of minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+}
+
+impl Time {
+    /// The start of the day.
+    pub const MIDNIGHT: Self = Self { hour: 0, minute: 0 };
+
+    /// Creates a new [`Time`] from its hour and minute, if valid.
+    pub fn from_hm(hour: u32, minute: u32) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Parses a [`Time`] in `HH:MM` format (e.g. `09:30`).
+    pub fn parse(text: &str) -> Option<Self> {
+        let (hour, minute) = text.trim().split_once(':')?;
+
+        Self::from_hm(hour.trim().parse().ok()?, minute.trim().parse().ok()?)
+    }
+
+    /// Returns the hour of the [`Time`].
+    pub fn hour(self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`].
+    pub fn minute(self) -> u32 {
+        self.minute
+    }
+
+    /// Adds the given amount of minutes to the [`Time`], wrapping around
+    /// midnight.
+    ///
+    /// The amount can be negative.
+    pub fn add_minutes(self, minutes: i32) -> Self {
+        let total = (self.hour * 60 + self.minute) as i32 + minutes;
+        let total = total.rem_euclid(24 * 60) as u32;
+
+        Self {
+            hour: total / 60,
+            minute: total % 60,
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> Time {
+        Time::from_hm(hour, minute).unwrap()
+    }
+
+    fn picker<'a>() -> TimePicker<'a, Time, crate::Theme, ()> {
+        TimePicker::new("", None, |time| time)
+            .min(time(9, 15))
+            .max(time(17, 30))
+            .step(15)
+    }
+
+    #[test]
+    fn times_are_limited_to_the_range() {
+        let picker = picker();
+        let mut state = State {
+            cursor: Some(time(9, 30)),
+            ..State::default()
+        };
+        let mut input = text_input::State::new();
+
+        let dropdown = Dropdown {
+            picker: &picker,
+            state: &mut state,
+            input: &mut input,
+            position: Point::ORIGIN,
+            target_height: 0.0,
+        };
+
+        assert!(!dropdown.is_enabled(Target::Hour(8)));
+        assert!(dropdown.is_enabled(Target::Hour(9)));
+        assert!(dropdown.is_enabled(Target::Hour(17)));
+        assert!(!dropdown.is_enabled(Target::Hour(18)));
+
+        assert!(!dropdown.is_enabled(Target::Minute(0)));
+        assert!(dropdown.is_enabled(Target::Minute(15)));
+
+        dropdown.state.cursor = Some(time(17, 0));
+
+        assert!(dropdown.is_enabled(Target::Minute(30)));
+        assert!(!dropdown.is_enabled(Target::Minute(45)));
+    }
+
+    #[test]
+    fn picked_hours_are_clamped_to_the_range() {
+        let picker = picker();
+        let mut state = State::default();
+        let mut input = text_input::State::new();
+
+        let dropdown = Dropdown {
+            picker: &picker,
+            state: &mut state,
+            input: &mut input,
+            position: Point::ORIGIN,
+            target_height: 0.0,
+        };
+
+        let mut time_at = |current, target| {
+            dropdown.state.cursor = Some(current);
+            dropdown.time_at(target)
+        };
+
+        assert_eq!(time_at(time(12, 0), Target::Hour(9)), Some(time(9, 15)));
+        assert_eq!(time_at(time(12, 45), Target::Hour(17)), Some(time(17, 0)));
+        assert_eq!(time_at(time(12, 30), Target::Hour(10)), Some(time(10, 30)));
+        assert_eq!(time_at(time(12, 30), Target::Hour(8)), None);
+
+        assert_eq!(time_at(time(9, 30), Target::Minute(0)), None);
+        assert_eq!(time_at(time(9, 30), Target::Minute(45)), Some(time(9, 45)));
+    }
+}
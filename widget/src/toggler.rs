@@ -32,12 +32,16 @@
 //! ```
 use crate::core::alignment;
 use crate::core::border;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -82,6 +86,7 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Option<widget::Id>,
     is_toggled: bool,
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     label: Option<text::Fragment<'a>>,
@@ -116,6 +121,7 @@ where
     ///     `Message`.
     pub fn new(is_toggled: bool) -> Self {
         Toggler {
+            id: None,
             is_toggled,
             on_toggle: None,
             label: None,
@@ -133,6 +139,12 @@ where
         }
     }
 
+    /// Sets the [`widget::Id`] of the [`Toggler`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the label of the [`Toggler`].
     pub fn label(mut self, label: impl text::IntoFragment<'a>) -> Self {
         self.label = Some(label.into_fragment());
@@ -232,6 +244,27 @@ where
     }
 }
 
+struct State<P: text::Paragraph> {
+    is_focused: bool,
+    is_pressed: bool,
+    text: widget::text::State<P>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_pressed = false;
+    }
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Toggler<'_, Message, Theme, Renderer>
 where
@@ -239,11 +272,15 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph> {
+            is_focused: false,
+            is_pressed: false,
+            text: widget::text::State::default(),
+        })
     }
 
     fn size(&self) -> Size<Length> {
@@ -281,12 +318,10 @@ where
             },
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                        .state
-                        .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                     widget::text::layout(
-                        state,
+                        &mut state.text,
                         renderer,
                         limits,
                         label,
@@ -309,9 +344,36 @@ where
         )
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let mut accessible = Accessible::new(Role::Switch)
+            .checked(self.is_toggled)
+            .disabled(self.on_toggle.is_none())
+            .focused(state.is_focused);
+
+        if let Some(label) = self.label.as_deref() {
+            accessible = accessible.label(label);
+        }
+
+        operation.accessible(self.id.as_ref(), layout.bounds(), &accessible);
+
+        if self.on_toggle.is_some() {
+            operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        } else {
+            state.is_focused = false;
+        }
+    }
+
     fn update(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -324,16 +386,38 @@ where
             return;
         };
 
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let mouse_over = cursor.is_over(layout.bounds());
 
+                state.is_focused = false;
+
                 if mouse_over {
                     shell.publish(on_toggle(!self.is_toggled));
                     shell.capture_event();
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                repeat: false,
+                ..
+            }) if state.is_focused => {
+                state.is_pressed = true;
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                ..
+            }) if state.is_focused && state.is_pressed => {
+                state.is_pressed = false;
+
+                shell.publish(on_toggle(!self.is_toggled));
+                shell.capture_event();
+            }
             _ => {}
         }
 
@@ -341,6 +425,10 @@ where
             Status::Disabled {
                 is_toggled: self.is_toggled,
             }
+        } else if state.is_focused {
+            Status::Focused {
+                is_toggled: self.is_toggled,
+            }
         } else if cursor.is_over(layout.bounds()) {
             Status::Hovered {
                 is_toggled: self.is_toggled,
@@ -402,13 +490,13 @@ where
 
         if self.label.is_some() {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> = tree.state.downcast_ref();
+            let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout.bounds(),
-                state.raw(),
+                state.text.raw(),
                 crate::text::Style {
                     color: style.text_color,
                 },
@@ -501,6 +589,11 @@ pub enum Status {
         /// Indicates whether the [`Toggler`] is toggled.
         is_toggled: bool,
     },
+    /// The [`Toggler`] has keyboard focus.
+    Focused {
+        /// Indicates whether the [`Toggler`] is toggled.
+        is_toggled: bool,
+    },
     /// The [`Toggler`] is disabled.
     Disabled {
         /// Indicates whether the [`Toggler`] is toggled.
@@ -509,7 +602,7 @@ pub enum Status {
 }
 
 /// The appearance of a toggler.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The background [`Color`] of the toggler.
     pub background: Background,
@@ -567,7 +660,9 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
 
     let background = match status {
-        Status::Active { is_toggled } | Status::Hovered { is_toggled } => {
+        Status::Active { is_toggled }
+        | Status::Hovered { is_toggled }
+        | Status::Focused { is_toggled } => {
             if is_toggled {
                 palette.primary.base.color
             } else {
@@ -591,7 +686,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
                 palette.background.base.color
             }
         }
-        Status::Hovered { is_toggled } => {
+        Status::Hovered { is_toggled } | Status::Focused { is_toggled } => {
             if is_toggled {
                 Color {
                     a: 0.5,
@@ -604,15 +699,90 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         Status::Disabled { .. } => palette.background.weakest.color,
     };
 
+    let (background_border_width, background_border_color) = match status {
+        Status::Focused { .. } => (2.0, palette.background.base.text),
+        _ => (0.0, Color::TRANSPARENT),
+    };
+
     Style {
         background: background.into(),
         foreground: foreground.into(),
         foreground_border_width: 0.0,
         foreground_border_color: Color::TRANSPARENT,
-        background_border_width: 0.0,
-        background_border_color: Color::TRANSPARENT,
+        background_border_width,
+        background_border_color,
         text_color: None,
         border_radius: None,
         padding_ratio: 0.1,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::clipboard;
+
+    #[test]
+    fn focused_togglers_toggle_when_space_is_released() {
+        let mut toggler: Element<'_, bool, Theme, ()> =
+            Toggler::new(false).on_toggle(|is_on| is_on).into();
+
+        let mut tree = Tree::new(&toggler);
+        let bounds = Rectangle::with_size(Size::new(100.0, 100.0));
+
+        let node = toggler.as_widget_mut().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, bounds.size()),
+        );
+
+        let space = keyboard::Key::Named(key::Named::Space);
+        let physical_key = key::Physical::Unidentified(key::NativeCode::Unidentified);
+
+        let pressed = Event::Keyboard(keyboard::Event::KeyPressed {
+            key: space.clone(),
+            modified_key: space.clone(),
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: Some(" ".into()),
+            repeat: false,
+        });
+
+        let released = Event::Keyboard(keyboard::Event::KeyReleased {
+            key: space.clone(),
+            modified_key: space,
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+        });
+
+        let mut send = |tree: &mut Tree, event: &Event| {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            toggler.as_widget_mut().update(
+                tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &bounds,
+            );
+
+            messages
+        };
+
+        assert_eq!(send(&mut tree, &pressed), []);
+        assert_eq!(send(&mut tree, &released), []);
+
+        tree.state.downcast_mut::<State<()>>().is_focused = true;
+
+        // The toggler toggles on release, so holding the key does nothing
+        assert_eq!(send(&mut tree, &pressed), []);
+        assert_eq!(send(&mut tree, &released), [true]);
+    }
+}
@@ -0,0 +1,1146 @@
+//! Tree views display hierarchies of items that can be expanded and selected.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{text, tree_view};
+//! use iced::widget::tree_view::Node;
+//!
+//! struct State {
+//!    is_expanded: bool,
+//!    selection: Vec<u32>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Toggle(u32, bool),
+//!     Select(Vec<u32>),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let is_selected = |key| state.selection.contains(&key);
+//!
+//!     tree_view([
+//!         Node::new(0, text("src"))
+//!             .expanded(state.is_expanded)
+//!             .selected(is_selected(0))
+//!             .push(Node::new(1, text("main.rs")).selected(is_selected(1))),
+//!         Node::new(2, text("Cargo.toml")).selected(is_selected(2)),
+//!     ])
+//!     .on_toggle(Message::Toggle)
+//!     .on_select(Message::Select)
+//!     .into()
+//! }
+//! ```
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::theme::palette;
+use crate::core::touch;
+use crate::core::widget::operation::accessibility::{Accessible, Role};
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector, Widget, widget,
+};
+
+/// A widget that displays a hierarchy of items that can be expanded,
+/// collapsed and selected.
+///
+/// The expansion and the selection of the items are owned by your
+/// application. A [`TreeView`] produces messages to change them with
+/// [`on_toggle`] and [`on_select`].
+///
+/// When focused, a [`TreeView`] can be navigated with the arrow keys. The
+/// left and right arrows collapse and expand the items.
+///
+/// [`on_toggle`]: Self::on_toggle
+/// [`on_select`]: Self::on_select
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{text, tree_view};
+/// use iced::widget::tree_view::Node;
+///
+/// struct State {
+///    is_expanded: bool,
+///    selection: Vec<u32>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle(u32, bool),
+///     Select(Vec<u32>),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     let is_selected = |key| state.selection.contains(&key);
+///
+///     tree_view([
+///         Node::new(0, text("src"))
+///             .expanded(state.is_expanded)
+///             .selected(is_selected(0))
+///             .push(Node::new(1, text("main.rs")).selected(is_selected(1))),
+///         Node::new(2, text("Cargo.toml")).selected(is_selected(2)),
+///     ])
+///     .on_toggle(Message::Toggle)
+///     .on_select(Message::Select)
+///     .into()
+/// }
+/// ```
+pub struct TreeView<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    id: Option<widget::Id>,
+    rows: Vec<Row<'a, Key, Message, Theme, Renderer>>,
+    keys: Vec<Key>,
+    width: Length,
+    indent: f32,
+    padding: Padding,
+    is_multiple: bool,
+    has_guides: bool,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Vec<Key>) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+    hovered: Option<usize>,
+}
+
+/// An item of a [`TreeView`].
+pub struct Node<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    key: Key,
+    content: Element<'a, Message, Theme, Renderer>,
+    children: Vec<Node<'a, Key, Message, Theme, Renderer>>,
+    is_expandable: bool,
+    is_expanded: bool,
+    is_selected: bool,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Node<'a, Key, Message, Theme, Renderer> {
+    /// Creates a new [`Node`] with the given key and content.
+    pub fn new(key: Key, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            key,
+            content: content.into(),
+            children: Vec::new(),
+            is_expandable: false,
+            is_expanded: false,
+            is_selected: false,
+        }
+    }
+
+    /// Adds a child to the [`Node`].
+    pub fn push(mut self, child: Node<'a, Key, Message, Theme, Renderer>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Extends the [`Node`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Node<'a, Key, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+
+    /// Sets whether the [`Node`] is expanded.
+    ///
+    /// The children of a collapsed [`Node`] are not displayed.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Node`] can be expanded, even if it has no children.
+    ///
+    /// This is useful to load the children of a [`Node`] lazily, once
+    /// the [`TreeView`] asks to expand it with [`TreeView::on_toggle`].
+    pub fn expandable(mut self, is_expandable: bool) -> Self {
+        self.is_expandable = is_expandable;
+        self
+    }
+
+    /// Sets whether the [`Node`] is selected.
+    pub fn selected(mut self, is_selected: bool) -> Self {
+        self.is_selected = is_selected;
+        self
+    }
+}
+
+struct Row<'a, Key, Message, Theme, Renderer> {
+    key: Key,
+    content: Element<'a, Message, Theme, Renderer>,
+    depth: usize,
+    parent: Option<usize>,
+    end: usize,
+    is_expandable: bool,
+    is_expanded: bool,
+    is_selected: bool,
+}
+
+const TOGGLE_SIZE: f32 = 9.0;
+
+impl<'a, Key, Message, Theme, Renderer> TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    /// The default indentation of each level of a [`TreeView`].
+    pub const DEFAULT_INDENT: f32 = 20.0;
+
+    /// The default padding of the items of a [`TreeView`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 2.0,
+        bottom: 2.0,
+        right: 4.0,
+        left: 4.0,
+    };
+
+    /// Creates a new [`TreeView`] with the given root nodes.
+    pub fn new(roots: impl IntoIterator<Item = Node<'a, Key, Message, Theme, Renderer>>) -> Self {
+        let mut rows = Vec::new();
+
+        for root in roots {
+            flatten(&mut rows, root, 0, None);
+        }
+
+        let keys = rows.iter().map(|row| row.key).collect();
+
+        Self {
+            id: None,
+            rows,
+            keys,
+            width: Length::Fill,
+            indent: Self::DEFAULT_INDENT,
+            padding: Self::DEFAULT_PADDING,
+            is_multiple: false,
+            has_guides: true,
+            on_toggle: None,
+            on_select: None,
+            class: Theme::default(),
+            hovered: None,
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`TreeView`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the indentation of each level of the [`TreeView`].
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the [`TreeView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets whether multiple items of the [`TreeView`] can be selected.
+    ///
+    /// When enabled, holding the command modifier toggles the selection
+    /// of an item and holding shift selects a range of items.
+    pub fn multiple(mut self, is_multiple: bool) -> Self {
+        self.is_multiple = is_multiple;
+        self
+    }
+
+    /// Sets whether the indentation guides of the [`TreeView`] are drawn.
+    pub fn guides(mut self, has_guides: bool) -> Self {
+        self.has_guides = has_guides;
+        self
+    }
+
+    /// Sets the message that should be produced when an item of the
+    /// [`TreeView`] is expanded or collapsed.
+    ///
+    /// The closure receives the key of the item and whether it should
+    /// be expanded.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(Key, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the message that should be produced when the selection of the
+    /// [`TreeView`] changes.
+    ///
+    /// The closure receives the keys of the new selection.
+    pub fn on_select(mut self, on_select: impl Fn(Vec<Key>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TreeView`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn toggle_bounds(&self, index: usize, bounds: Rectangle) -> Rectangle {
+        let row = &self.rows[index];
+
+        Rectangle {
+            x: bounds.x + row.depth as f32 * self.indent,
+            width: self.indent,
+            ..bounds
+        }
+    }
+
+    fn toggle(&self, index: usize, is_expanded: bool, shell: &mut Shell<'_, Message>) -> bool {
+        let row = &self.rows[index];
+
+        if let Some(on_toggle) = &self.on_toggle
+            && row.is_expandable
+            && row.is_expanded != is_expanded
+        {
+            shell.publish(on_toggle(row.key, is_expanded));
+            return true;
+        }
+
+        false
+    }
+
+    fn select(
+        &self,
+        state: &mut State<Key>,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let key = self.rows[index].key;
+
+        state.cursor = Some(key);
+
+        let Some(on_select) = &self.on_select else {
+            return;
+        };
+
+        let anchor = state
+            .anchor
+            .and_then(|anchor| self.keys.iter().position(|key| *key == anchor));
+
+        let selection = match anchor {
+            Some(anchor) if self.is_multiple && modifiers.shift() => {
+                let range = anchor.min(index)..=anchor.max(index);
+
+                self.keys[range].to_vec()
+            }
+            _ if self.is_multiple && modifiers.command() => {
+                state.anchor = Some(key);
+
+                self.rows
+                    .iter()
+                    .filter(|row| row.is_selected != (row.key == key))
+                    .map(|row| row.key)
+                    .collect()
+            }
+            _ => {
+                state.anchor = Some(key);
+
+                vec![key]
+            }
+        };
+
+        shell.publish(on_select(selection));
+    }
+}
+
+fn flatten<'a, Key, Message, Theme, Renderer>(
+    rows: &mut Vec<Row<'a, Key, Message, Theme, Renderer>>,
+    node: Node<'a, Key, Message, Theme, Renderer>,
+    depth: usize,
+    parent: Option<usize>,
+) {
+    let index = rows.len();
+    let is_expandable = node.is_expandable || !node.children.is_empty();
+
+    rows.push(Row {
+        key: node.key,
+        content: node.content,
+        depth,
+        parent,
+        end: index + 1,
+        is_expandable,
+        is_expanded: node.is_expanded && is_expandable,
+        is_selected: node.is_selected,
+    });
+
+    if node.is_expanded {
+        for child in node.children {
+            flatten(rows, child, depth + 1, Some(index));
+        }
+    }
+
+    rows[index].end = rows.len();
+}
+
+struct State<Key> {
+    keys: Vec<Key>,
+    cursor: Option<Key>,
+    anchor: Option<Key>,
+    modifiers: keyboard::Modifiers,
+    is_focused: bool,
+}
+
+impl<Key> operation::Focusable for State<Key> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TreeView<'_, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            keys: self.keys.clone(),
+            cursor: None,
+            anchor: None,
+            modifiers: keyboard::Modifiers::default(),
+            is_focused: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.rows
+            .iter()
+            .map(|row| Tree::new(row.content.as_widget()))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State<Key>>();
+
+        tree::diff_children_custom_with_search(
+            children,
+            &self.rows,
+            |tree, row| row.content.as_widget().diff(tree),
+            |index| {
+                self.keys.get(index).or_else(|| self.keys.last()).copied()
+                    != Some(state.keys[index])
+            },
+            |row| Tree::new(row.content.as_widget()),
+        );
+
+        if state.keys != self.keys {
+            state.keys.clone_from(&self.keys);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+
+        let mut intrinsic_width: f32 = 0.0;
+        let mut height = 0.0;
+
+        let contents: Vec<_> = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(row, tree)| {
+                let x = (row.depth + 1) as f32 * self.indent + self.padding.left;
+
+                let node = row.content.as_widget_mut().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new((max_width - x - self.padding.right).max(0.0), f32::INFINITY),
+                    ),
+                );
+
+                let size = node.size();
+
+                intrinsic_width = intrinsic_width.max(x + size.width + self.padding.right);
+                height += size.height.max(TOGGLE_SIZE) + self.padding.y();
+
+                (x, node)
+            })
+            .collect();
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(intrinsic_width, height),
+        );
+
+        let mut y = 0.0;
+
+        let rows = contents
+            .into_iter()
+            .map(|(x, content)| {
+                let content_height = content.size().height;
+                let height = content_height.max(TOGGLE_SIZE) + self.padding.y();
+
+                let content = content.move_to(Point::new(
+                    x,
+                    self.padding.top + (height - self.padding.y() - content_height) / 2.0,
+                ));
+
+                let row = layout::Node::with_children(Size::new(size.width, height), vec![content])
+                    .move_to(Point::new(0.0, y));
+
+                y += height;
+
+                row
+            })
+            .collect();
+
+        layout::Node::with_children(size, rows)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let is_enabled = self.on_toggle.is_some() || self.on_select.is_some();
+
+        operation.container(self.id.as_ref(), layout.bounds());
+        operation.accessible(
+            self.id.as_ref(),
+            layout.bounds(),
+            &Accessible::new(Role::Tree)
+                .disabled(!is_enabled)
+                .focused(state.is_focused),
+        );
+
+        if is_enabled {
+            operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        } else {
+            state.is_focused = false;
+        }
+
+        operation.traverse(&mut |operation| {
+            for ((row, tree), layout) in self
+                .rows
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                let mut accessible = Accessible::new(Role::TreeItem).selected(row.is_selected);
+
+                if row.is_expandable {
+                    accessible = accessible.expanded(row.is_expanded);
+                }
+
+                operation.accessible(None, layout.bounds(), &accessible);
+
+                row.content.as_widget_mut().operate(
+                    tree,
+                    layout.children().next().unwrap(),
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((row, tree), layout) in self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            row.content.as_widget_mut().update(
+                tree,
+                event,
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
+                let Some(index) = layout
+                    .children()
+                    .position(|layout| cursor.is_over(layout.bounds()))
+                else {
+                    return;
+                };
+
+                if self.on_toggle.is_none() && self.on_select.is_none() {
+                    return;
+                }
+
+                state.is_focused = true;
+
+                let bounds = layout.children().nth(index).unwrap().bounds();
+                let is_expanded = self.rows[index].is_expanded;
+                let modifiers = state.modifiers;
+
+                if !(cursor.is_over(self.toggle_bounds(index, bounds))
+                    && self.toggle(index, !is_expanded, shell))
+                {
+                    self.select(state, index, modifiers, shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modifiers,
+                ..
+            }) if state.is_focused && !self.rows.is_empty() => {
+                let current = state
+                    .cursor
+                    .and_then(|cursor| self.keys.iter().position(|key| *key == cursor));
+
+                let target = match (named, current) {
+                    (key::Named::ArrowDown, Some(index)) => {
+                        Some((index + 1).min(self.rows.len() - 1))
+                    }
+                    (key::Named::ArrowUp, Some(index)) => Some(index.saturating_sub(1)),
+                    (key::Named::ArrowDown | key::Named::ArrowUp | key::Named::Home, _) => Some(0),
+                    (key::Named::End, _) => Some(self.rows.len() - 1),
+                    (key::Named::ArrowRight, Some(index)) => {
+                        let row = &self.rows[index];
+
+                        if row.is_expanded {
+                            (row.end > index + 1).then_some(index + 1)
+                        } else {
+                            let _ = self.toggle(index, true, shell);
+                            None
+                        }
+                    }
+                    (key::Named::ArrowLeft, Some(index)) => {
+                        let row = &self.rows[index];
+
+                        if row.is_expanded {
+                            let _ = self.toggle(index, false, shell);
+                            None
+                        } else {
+                            row.parent
+                        }
+                    }
+                    (key::Named::Space | key::Named::Enter, Some(index)) => Some(index),
+                    _ => return,
+                };
+
+                let is_moving = !matches!(named, key::Named::Space | key::Named::Enter);
+
+                if let Some(target) = target {
+                    if is_moving && self.is_multiple && modifiers.command() {
+                        state.cursor = Some(self.rows[target].key);
+                    } else {
+                        self.select(state, target, *modifiers, shell);
+                    }
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            _ => {}
+        }
+
+        let hovered = layout
+            .children()
+            .position(|layout| cursor.is_over(layout.bounds()));
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.hovered = hovered;
+        } else if self.hovered != hovered {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        for (index, ((row, tree), layout)) in self
+            .rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let interaction = row.content.as_widget().mouse_interaction(
+                tree,
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+                renderer,
+            );
+
+            if interaction != mouse::Interaction::None {
+                return interaction;
+            }
+
+            if row.is_expandable
+                && self.on_toggle.is_some()
+                && cursor.is_over(self.toggle_bounds(index, layout.bounds()))
+            {
+                return mouse::Interaction::Pointer;
+            }
+        }
+
+        mouse::Interaction::None
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let rows: Vec<_> = layout.children().collect();
+
+        if self.has_guides {
+            let color = theme.style(&self.class, Status::Active).guide_color;
+
+            for (index, row) in self.rows.iter().enumerate() {
+                if !row.is_expanded || row.end <= index + 1 {
+                    continue;
+                }
+
+                let first = rows[index + 1].bounds();
+                let last = rows[row.end - 1].bounds();
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (first.x + row.depth as f32 * self.indent + self.indent / 2.0)
+                                .floor(),
+                            y: first.y,
+                            width: 1.0,
+                            height: last.y + last.height - first.y,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        }
+
+        for (index, ((row, tree), layout)) in
+            self.rows.iter().zip(&tree.children).zip(&rows).enumerate()
+        {
+            let bounds = layout.bounds();
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let status = if state.is_focused && state.cursor == Some(row.key) {
+                Status::Focused {
+                    is_selected: row.is_selected,
+                }
+            } else if row.is_selected {
+                Status::Selected
+            } else if cursor.is_over(bounds) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = theme.style(&self.class, status);
+
+            if style.background.is_some() || style.border.width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    style
+                        .background
+                        .clone()
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+
+            if row.is_expandable {
+                let toggle = self.toggle_bounds(index, bounds);
+
+                let icon = Rectangle {
+                    x: (toggle.center_x() - TOGGLE_SIZE / 2.0).round(),
+                    y: (toggle.center_y() - TOGGLE_SIZE / 2.0).round(),
+                    width: TOGGLE_SIZE,
+                    height: TOGGLE_SIZE,
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: icon,
+                        border: border::rounded(2).width(1).color(style.toggle_color),
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+
+                let center = (TOGGLE_SIZE / 2.0).floor();
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: icon.x + 2.0,
+                            y: icon.y + center,
+                            width: TOGGLE_SIZE - 4.0,
+                            height: 1.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.toggle_color,
+                );
+
+                if !row.is_expanded {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: icon.x + center,
+                                y: icon.y + 2.0,
+                                width: 1.0,
+                                height: TOGGLE_SIZE - 4.0,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.toggle_color,
+                    );
+                }
+            }
+
+            row.content.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color,
+                },
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((row, tree), layout)| {
+                row.content.as_widget_mut().overlay(
+                    tree,
+                    layout.children().next().unwrap(),
+                    renderer,
+                    viewport,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<TreeView<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(tree_view: TreeView<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(tree_view)
+    }
+}
+
+/// The possible status of an item of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The item can be interacted with.
+    Active,
+    /// The item is being hovered.
+    Hovered,
+    /// The item is selected.
+    Selected,
+    /// The item has the keyboard focus of the [`TreeView`].
+    Focused {
+        /// Indicates whether the item is selected.
+        is_selected: bool,
+    },
+}
+
+/// The appearance of an item of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the item.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the item.
+    pub text_color: Color,
+    /// The [`Border`] of the item.
+    pub border: Border,
+    /// The [`Color`] of the expand and collapse toggle of the item.
+    pub toggle_color: Color,
+    /// The [`Color`] of the indentation guides.
+    ///
+    /// Only the guide color of [`Status::Active`] is used.
+    pub guide_color: Color,
+}
+
+/// The theme catalog of a [`TreeView`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`TreeView`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`TreeView`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: None,
+        text_color: palette.background.base.text,
+        border: border::rounded(2),
+        toggle_color: palette.background.strongest.color,
+        guide_color: palette.background.strong.color,
+    };
+
+    let selected = Style {
+        background: Some(palette.primary.weak.color.into()),
+        text_color: palette.primary.weak.text,
+        ..active
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            background: Some(palette.background.weak.color.into()),
+            ..active
+        },
+        Status::Selected => selected,
+        Status::Focused { is_selected } => {
+            focused(if is_selected { selected } else { active }, palette)
+        }
+    }
+}
+
+fn focused(style: Style, palette: &palette::Extended) -> Style {
+    Style {
+        border: Border {
+            color: palette.background.base.text,
+            width: 2.0,
+            ..style.border
+        },
+        ..style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Space;
+    use crate::core::clipboard;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggled(u32, bool),
+        Selected(Vec<u32>),
+    }
+
+    fn key_pressed(named: key::Named) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modified_key: keyboard::Key::Named(named),
+            physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+            repeat: false,
+        })
+    }
+
+    #[test]
+    fn keyboard_navigation_follows_the_tree() {
+        let mut tree_view: Element<'_, Message, crate::Theme, ()> = TreeView::new([
+            Node::new(1, Space::new())
+                .expanded(true)
+                .push(Node::new(2, Space::new()))
+                .push(Node::new(3, Space::new()).push(Node::new(4, Space::new()))),
+            Node::new(5, Space::new()),
+        ])
+        .on_toggle(Message::Toggled)
+        .on_select(Message::Selected)
+        .into();
+
+        let bounds = Rectangle::with_size(Size::new(100.0, 100.0));
+        let mut tree = Tree::new(tree_view.as_widget());
+        let node = tree_view.as_widget_mut().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, bounds.size()),
+        );
+
+        tree.state.downcast_mut::<State<u32>>().is_focused = true;
+
+        let mut press = |named| {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            tree_view.as_widget_mut().update(
+                &mut tree,
+                &key_pressed(named),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &bounds,
+            );
+
+            messages
+        };
+
+        assert_eq!(press(key::Named::ArrowDown), [Message::Selected(vec![1])]);
+        assert_eq!(press(key::Named::ArrowDown), [Message::Selected(vec![2])]);
+        assert_eq!(press(key::Named::ArrowDown), [Message::Selected(vec![3])]);
+
+        // Collapsed nodes expand before their children can be reached
+        assert_eq!(press(key::Named::ArrowRight), [Message::Toggled(3, true)]);
+
+        // Collapsed nodes move to their parent; expanded ones collapse
+        assert_eq!(press(key::Named::ArrowLeft), [Message::Selected(vec![1])]);
+        assert_eq!(press(key::Named::ArrowLeft), [Message::Toggled(1, false)]);
+        assert_eq!(press(key::Named::ArrowRight), [Message::Selected(vec![2])]);
+
+        assert_eq!(press(key::Named::End), [Message::Selected(vec![5])]);
+        assert_eq!(press(key::Named::ArrowDown), [Message::Selected(vec![5])]);
+        assert_eq!(press(key::Named::ArrowLeft), []);
+        assert_eq!(press(key::Named::Home), [Message::Selected(vec![1])]);
+    }
+}
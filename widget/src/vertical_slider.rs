@@ -39,6 +39,8 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::Operation;
+use crate::core::widget::operation::accessibility::{Accessible, Role, Value};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -242,6 +244,25 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &mut self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            &Accessible::new(Role::Slider).value(Value::Number {
+                value: self.value.into(),
+                min: (*self.range.start()).into(),
+                max: (*self.range.end()).into(),
+                step: Some(self.step.into()),
+            }),
+        );
+    }
+
     fn update(
         &mut self,
         tree: &mut Tree,
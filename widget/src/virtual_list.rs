@@ -0,0 +1,720 @@
+//! Virtual lists display very large collections of items by only building
+//! the items that are visible.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{scrollable, text, virtual_list};
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     scrollable(
+//!         virtual_list(200_000, |i| text!("Row {i}").into())
+//!             .height(virtual_list::Height::Fixed(20.0)),
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    self, Clipboard, Element, Event, Length, Pixels, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use rustc_hash::{FxHashMap, FxHasher};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// A vertical list that only builds the items that are visible.
+///
+/// A [`VirtualList`] is meant to be placed inside a [`Scrollable`]. It lays
+/// itself out with the height of all of its items, so any [`Viewport`],
+/// anchoring or scrolling operation of the [`Scrollable`] keeps working
+/// as usual.
+///
+/// Only the visible items—plus some [`overscan`]—are built, laid out and
+/// operated on. The internal state of each built item is kept by its key;
+/// which is its index by default, but it can be changed with [`key`].
+///
+/// [`Scrollable`]: crate::Scrollable
+/// [`Viewport`]: crate::scrollable::Viewport
+/// [`overscan`]: Self::overscan
+/// [`key`]: Self::key
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{scrollable, text, virtual_list};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     scrollable(
+///         virtual_list(200_000, |i| text!("Row {i}").into())
+///             .height(virtual_list::Height::Fixed(20.0)),
+///     )
+///     .into()
+/// }
+/// ```
+pub struct VirtualList<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    count: usize,
+    key: Box<dyn Fn(usize) -> Key + 'a>,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: Length,
+    height: Height,
+    spacing: f32,
+    overscan: f32,
+    items: Vec<Item<'a, Key, Message, Theme, Renderer>>,
+}
+
+struct Item<'a, Key, Message, Theme, Renderer> {
+    key: Key,
+    element: Element<'a, Message, Theme, Renderer>,
+}
+
+/// The height of the items of a [`VirtualList`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Height {
+    /// Every item has the same fixed height.
+    ///
+    /// No items are built if the height, plus the spacing between them,
+    /// is not positive.
+    Fixed(f32),
+
+    /// Every item is measured once it is laid out for the first time.
+    ///
+    /// Until then, an item is assumed to have the given estimated height.
+    Measured(f32),
+}
+
+impl<'a, Message, Theme, Renderer> VirtualList<'a, usize, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The default overscan of a [`VirtualList`].
+    pub const DEFAULT_OVERSCAN: f32 = 200.0;
+
+    /// Creates a new [`VirtualList`] with the given amount of items and the
+    /// function that builds the item at a given index.
+    pub fn new(
+        count: usize,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            count,
+            key: Box::new(|index| index),
+            view: Box::new(view),
+            width: Length::Fill,
+            height: Height::Measured(20.0),
+            spacing: 0.0,
+            overscan: Self::DEFAULT_OVERSCAN,
+            items: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> VirtualList<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + Eq + Hash + 'static,
+    Renderer: core::Renderer,
+{
+    /// Sets the function that produces the key of the item at a given index.
+    ///
+    /// The internal state of an item follows its key; so a stable key
+    /// (like the id of a message) keeps the state of the item intact
+    /// when other items are inserted or removed.
+    pub fn key<K>(
+        self,
+        key: impl Fn(usize) -> K + 'a,
+    ) -> VirtualList<'a, K, Message, Theme, Renderer> {
+        VirtualList {
+            count: self.count,
+            key: Box::new(key),
+            view: self.view,
+            width: self.width,
+            height: self.height,
+            spacing: self.spacing,
+            overscan: self.overscan,
+            items: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Height`] of the items of the [`VirtualList`].
+    pub fn height(mut self, height: Height) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the items of the [`VirtualList`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the extra space above and below the visible area of the
+    /// [`VirtualList`] whose items will be built as well.
+    ///
+    /// A bigger overscan means fewer rebuilds while scrolling, but more
+    /// items being built at once.
+    pub fn overscan(mut self, overscan: impl Into<Pixels>) -> Self {
+        self.overscan = overscan.into().0;
+        self
+    }
+
+    /// Returns the indices of the items that should be built for the given
+    /// visible range, the vertical offset of the first one, and the total
+    /// height of the [`VirtualList`].
+    fn window(&self, state: &State<Key>, visible: Range<f32>) -> (Range<usize>, f32, f32) {
+        let start = (visible.start - self.overscan).max(0.0);
+        let end = visible.end + self.overscan;
+
+        match self.height {
+            Height::Fixed(height) => {
+                let stride = height + self.spacing;
+
+                if stride <= 0.0 {
+                    return (0..0, 0.0, 0.0);
+                }
+
+                let total = (stride * self.count as f32 - self.spacing).max(0.0);
+
+                let first = ((start / stride).floor() as usize).min(self.count);
+                let last = ((end / stride).ceil() as usize).min(self.count);
+
+                (first..last, first as f32 * stride, total)
+            }
+            Height::Measured(_) => {
+                let range = state.heights.window(start..end);
+                let offset = state.heights.offset(range.start);
+
+                (range, offset, state.heights.total())
+            }
+        }
+    }
+}
+
+struct State<Key> {
+    visible: Option<Range<f32>>,
+    built: Range<f32>,
+    trees: FxHashMap<Key, Tree>,
+    heights: Heights<Key>,
+}
+
+/// The heights of the items of a [`VirtualList`] with [`Height::Measured`].
+///
+/// Measured heights are kept by item key, while the offsets of the items
+/// are cached as prefix sums; so the visible items can be found with a
+/// binary search.
+struct Heights<Key> {
+    measured: FxHashMap<Key, f32>,
+    keys: u64,
+    heights: Vec<f32>,
+    offsets: Vec<f32>,
+    estimate: f32,
+    spacing: f32,
+    outdated: Option<usize>,
+}
+
+impl<Key> Heights<Key>
+where
+    Key: Copy + Eq + Hash,
+{
+    fn new() -> Self {
+        Self {
+            measured: FxHashMap::default(),
+            keys: 0,
+            heights: Vec::new(),
+            offsets: vec![0.0],
+            estimate: 0.0,
+            spacing: 0.0,
+            outdated: None,
+        }
+    }
+
+    /// Recomputes the height of every item if the keys of the items, the
+    /// estimate, or the spacing have changed.
+    ///
+    /// The keys are compared by their fingerprint, so they are not stored.
+    /// The measurements of the items that are gone are discarded.
+    fn sync(&mut self, count: usize, estimate: f32, spacing: f32, key: impl Fn(usize) -> Key) {
+        let keys = {
+            let mut hasher = FxHasher::default();
+
+            count.hash(&mut hasher);
+            (0..count).for_each(|index| key(index).hash(&mut hasher));
+
+            hasher.finish()
+        };
+
+        if self.keys == keys
+            && self.heights.len() == count
+            && self.estimate == estimate
+            && self.spacing == spacing
+        {
+            return;
+        }
+
+        let mut measured = FxHashMap::default();
+
+        self.heights = (0..count)
+            .map(|index| {
+                let key = key(index);
+
+                match self.measured.get(&key) {
+                    Some(height) => {
+                        let _ = measured.insert(key, *height);
+
+                        *height
+                    }
+                    None => estimate,
+                }
+            })
+            .collect();
+
+        self.measured = measured;
+        self.keys = keys;
+        self.estimate = estimate;
+        self.spacing = spacing;
+        self.outdated = Some(0);
+
+        self.flush();
+    }
+
+    /// Records the measured height of the item with the given index and key.
+    ///
+    /// The offsets of the items are not updated until [`Self::flush`].
+    fn measure(&mut self, index: usize, key: Key, height: f32) {
+        let _ = self.measured.insert(key, height);
+
+        if self.heights[index] != height {
+            self.heights[index] = height;
+            self.outdated = Some(self.outdated.map_or(index, |outdated| outdated.min(index)));
+        }
+    }
+
+    /// Updates the offsets of the items after any new measurements.
+    fn flush(&mut self) {
+        let Some(outdated) = self.outdated.take() else {
+            return;
+        };
+
+        self.offsets.truncate(outdated + 1);
+
+        let mut offset = self.offsets[outdated];
+
+        for height in &self.heights[outdated..] {
+            offset += height + self.spacing;
+            self.offsets.push(offset);
+        }
+    }
+
+    /// Returns the indices of the items that intersect the given range.
+    fn window(&self, range: Range<f32>) -> Range<usize> {
+        let first = self.offsets[1..].partition_point(|offset| offset - self.spacing < range.start);
+        let last =
+            self.offsets[..self.heights.len()].partition_point(|offset| *offset <= range.end);
+
+        first.min(last)..last
+    }
+
+    /// Returns the vertical offset of the item with the given index.
+    fn offset(&self, index: usize) -> f32 {
+        self.offsets[index]
+    }
+
+    /// Returns the total height of all the items.
+    fn total(&self) -> f32 {
+        (self.offsets[self.heights.len()] - self.spacing).max(0.0)
+    }
+}
+
+impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VirtualList<'_, Key, Message, Theme, Renderer>
+where
+    Key: Copy + Eq + Hash + 'static,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key> {
+            visible: None,
+            built: 0.0..0.0,
+            trees: FxHashMap::default(),
+            heights: Heights::new(),
+        })
+    }
+
+    fn diff(&self, _tree: &mut Tree) {
+        // Diff is deferred to layout
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        if let Height::Measured(estimate) = self.height {
+            state
+                .heights
+                .sync(self.count, estimate, self.spacing, |index| {
+                    (self.key)(index)
+                });
+        }
+
+        let visible = state.visible.clone().unwrap_or_else(|| {
+            let height = limits.max().height;
+
+            0.0..if height.is_finite() { height } else { 0.0 }
+        });
+
+        let (range, mut y, mut total) = self.window(state, visible.clone());
+
+        state.built = (visible.start - self.overscan).max(0.0)..visible.end + self.overscan;
+
+        self.items = range
+            .clone()
+            .map(|index| Item {
+                key: (self.key)(index),
+                element: (self.view)(index),
+            })
+            .collect();
+
+        let mut trees = FxHashMap::default();
+        let mut nodes = Vec::with_capacity(self.items.len());
+
+        for (index, item) in range.zip(&mut self.items) {
+            let mut tree = match state.trees.remove(&item.key) {
+                Some(mut tree) => {
+                    tree.diff(item.element.as_widget());
+                    tree
+                }
+                None => Tree::new(item.element.as_widget()),
+            };
+
+            let node = match self.height {
+                Height::Fixed(height) => item.element.as_widget_mut().layout(
+                    &mut tree,
+                    renderer,
+                    &layout::Limits::new(Size::new(0.0, height), Size::new(max_width, height)),
+                ),
+                Height::Measured(_) => {
+                    let node = item.element.as_widget_mut().layout(
+                        &mut tree,
+                        renderer,
+                        &layout::Limits::new(Size::ZERO, Size::new(max_width, f32::INFINITY)),
+                    );
+
+                    state.heights.measure(index, item.key, node.size().height);
+
+                    node
+                }
+            };
+
+            let height = node.size().height;
+
+            nodes.push(node.move_to((0.0, y)));
+            let _ = trees.insert(item.key, tree);
+
+            y += height + self.spacing;
+        }
+
+        state.trees = trees;
+
+        if let Height::Measured(_) = self.height {
+            state.heights.flush();
+            total = state.heights.total();
+        }
+
+        let intrinsic_width = nodes
+            .iter()
+            .map(|node| node.size().width)
+            .fold(0.0, f32::max);
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(intrinsic_width, total),
+        );
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for (item, layout) in self.items.iter_mut().zip(layout.children()) {
+                if let Some(tree) = state.trees.get_mut(&item.key) {
+                    item.element
+                        .as_widget_mut()
+                        .operate(tree, layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        let visible = (viewport.y - bounds.y)..(viewport.y - bounds.y + viewport.height);
+
+        if state.visible.as_ref() != Some(&visible) {
+            let is_outdated = (visible.start < state.built.start && state.built.start > 0.0)
+                || (visible.end > state.built.end && state.built.end < bounds.height);
+
+            state.visible = Some(visible);
+
+            if is_outdated {
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+        }
+
+        for (item, layout) in self.items.iter_mut().zip(layout.children()) {
+            if let Some(tree) = state.trees.get_mut(&item.key) {
+                item.element.as_widget_mut().update(
+                    tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Key>>();
+
+        self.items
+            .iter()
+            .zip(layout.children())
+            .filter_map(|(item, layout)| {
+                let tree = state.trees.get(&item.key)?;
+
+                Some(
+                    item.element
+                        .as_widget()
+                        .mouse_interaction(tree, layout, cursor, viewport, renderer),
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+
+        for (item, layout) in self.items.iter().zip(layout.children()) {
+            if !layout.bounds().intersects(viewport) {
+                continue;
+            }
+
+            if let Some(tree) = state.trees.get(&item.key) {
+                item.element
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        let mut trees: FxHashMap<Key, &'b mut Tree> = state
+            .trees
+            .iter_mut()
+            .map(|(key, tree)| (*key, tree))
+            .collect();
+
+        let children = self
+            .items
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(item, layout)| {
+                let tree = trees.remove(&item.key)?;
+
+                item.element
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, viewport, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<VirtualList<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + Eq + Hash + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(list: VirtualList<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Space;
+
+    #[test]
+    fn fixed_heights_window_the_visible_items() {
+        let list: VirtualList<'_, usize, (), crate::Theme, ()> =
+            VirtualList::new(100, |_| Space::new().into())
+                .height(Height::Fixed(10.0))
+                .spacing(2.0)
+                .overscan(0.0);
+
+        let state = State {
+            visible: None,
+            built: 0.0..0.0,
+            trees: FxHashMap::default(),
+            heights: Heights::new(),
+        };
+
+        assert_eq!(list.window(&state, 0.0..30.0), (0..3, 0.0, 1198.0));
+        assert_eq!(list.window(&state, 50.0..70.0), (4..6, 48.0, 1198.0));
+        assert_eq!(
+            list.window(&state, 2000.0..2100.0),
+            (100..100, 1200.0, 1198.0)
+        );
+
+        // Items without height are never built
+        let list = list.height(Height::Fixed(0.0)).spacing(0.0);
+
+        assert_eq!(list.window(&state, 0.0..30.0), (0..0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn measured_heights_window_the_visible_items() {
+        let mut heights = Heights::new();
+        heights.sync(10, 10.0, 0.0, |index| index);
+
+        assert_eq!(heights.window(25.0..35.0), 2..4);
+        assert_eq!(heights.total(), 100.0);
+
+        heights.measure(0, 0, 30.0);
+        heights.measure(2, 2, 5.0);
+
+        assert_eq!(heights.window(25.0..35.0), 2..4);
+
+        heights.flush();
+
+        assert_eq!(heights.window(25.0..35.0), 0..2);
+        assert_eq!(heights.window(41.0..44.0), 2..3);
+        assert_eq!(heights.offset(3), 45.0);
+        assert_eq!(heights.total(), 115.0);
+    }
+
+    #[test]
+    fn measured_heights_follow_their_keys() {
+        let mut heights = Heights::new();
+        heights.sync(3, 10.0, 1.0, |index| index * 10);
+        heights.measure(1, 10, 50.0);
+        heights.flush();
+
+        assert_eq!(heights.offset(2), 62.0);
+
+        // An item is inserted at the beginning
+        heights.sync(4, 10.0, 1.0, |index| match index {
+            0 => 99,
+            index => (index - 1) * 10,
+        });
+
+        assert_eq!(heights.offset(2), 22.0);
+        assert_eq!(heights.offset(3), 73.0);
+        assert_eq!(heights.total(), 83.0);
+
+        // The measured item is removed
+        heights.sync(3, 10.0, 1.0, |index| [99, 0, 20][index]);
+
+        assert_eq!(heights.total(), 32.0);
+        assert!(!heights.measured.contains_key(&10));
+
+        // The items are replaced, keeping their amount
+        heights.measure(1, 0, 30.0);
+        heights.flush();
+        heights.sync(3, 10.0, 1.0, |index| [0, 20, 99][index]);
+
+        assert_eq!(heights.offset(1), 31.0);
+        assert_eq!(heights.offset(2), 42.0);
+    }
+}
@@ -0,0 +1,75 @@
+//! Expose user interfaces to assistive technologies.
+pub use crate::core::widget::operation::accessibility::Tree;
+
+use crate::Error;
+use crate::core::theme;
+use crate::core::window;
+use crate::program::Program;
+use crate::runtime::user_interface::UserInterface;
+
+/// A platform adapter that exposes the accessibility [`Tree`] of a window
+/// to assistive technologies.
+///
+/// Every window gets its own [`Adapter`], which is dropped when the window
+/// is closed. The shell keeps it up to date by building a new [`Tree`]
+/// every time the user interface of the window is built or updated.
+pub trait Adapter {
+    /// Notifies the [`Adapter`] of an event of its window.
+    fn process_event(&mut self, _event: &winit::event::WindowEvent) {}
+
+    /// Updates the accessibility [`Tree`] of the window.
+    ///
+    /// It is only called when the [`Tree`] changes.
+    fn update(&mut self, tree: Tree);
+}
+
+/// A function that connects a new window to an [`Adapter`], before the
+/// window becomes visible.
+pub type Connect = Box<dyn Fn(window::Id, &winit::window::Window) -> Box<dyn Adapter>>;
+
+/// Runs a [`Program`], exposing its windows to assistive technologies through
+/// the [`Adapter`]s produced by the given function.
+pub fn run<P>(
+    program: P,
+    connect: impl Fn(window::Id, &winit::window::Window) -> Box<dyn Adapter> + 'static,
+) -> Result<(), Error>
+where
+    P: Program + 'static,
+    P::Theme: theme::Base,
+{
+    crate::launch(program, Some(Box::new(connect)))
+}
+
+/// The [`Adapter`] of a window, along with the last [`Tree`] it was given.
+pub(crate) struct Accessibility {
+    adapter: Box<dyn Adapter>,
+    tree: Option<Tree>,
+}
+
+impl Accessibility {
+    pub fn new(adapter: Box<dyn Adapter>) -> Self {
+        Self {
+            adapter,
+            tree: None,
+        }
+    }
+
+    pub fn process_event(&mut self, event: &winit::event::WindowEvent) {
+        self.adapter.process_event(event);
+    }
+
+    pub fn update<Message, Theme, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Theme, Renderer>,
+        renderer: &Renderer,
+    ) where
+        Renderer: crate::core::Renderer,
+    {
+        let tree = user_interface.accessibility(renderer);
+
+        if self.tree.as_ref() != Some(&tree) {
+            self.tree = Some(tree.clone());
+            self.adapter.update(tree);
+        }
+    }
+}
@@ -1,11 +1,15 @@
 //! Access the clipboard.
 
-use crate::core::clipboard::Kind;
+use crate::core::clipboard::{Content, Format, Image, Kind};
 use std::sync::Arc;
 use winit::window::{Window, WindowId};
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
+///
+/// Text, HTML and images are exchanged with the system clipboard. HTML and
+/// images are only supported on Windows, macOS and Linux. Custom MIME types
+/// are not supported.
 pub struct Clipboard {
     state: State,
 }
@@ -13,6 +17,7 @@ pub struct Clipboard {
 enum State {
     Connected {
         clipboard: window_clipboard::Clipboard,
+        rich: Option<rich::Clipboard>,
         // Held until drop to satisfy the safety invariants of
         // `window_clipboard::Clipboard`.
         //
@@ -34,7 +39,11 @@ impl Clipboard {
         let clipboard = unsafe { window_clipboard::Clipboard::connect(&window) };
 
         let state = match clipboard {
-            Ok(clipboard) => State::Connected { clipboard, window },
+            Ok(clipboard) => State::Connected {
+                clipboard,
+                rich: rich::Clipboard::connect(),
+                window,
+            },
             Err(_) => State::Unavailable,
         };
 
@@ -80,6 +89,106 @@ impl Clipboard {
         }
     }
 
+    /// Reads the current content of the [`Clipboard`] in the given [`Format`].
+    pub fn read_format(&self, kind: Kind, format: &Format) -> Option<Content> {
+        match format {
+            Format::Text => self.read(kind).map(Content::Text),
+            Format::Html => self.rich()?.read_html(kind).map(Content::Html),
+            Format::Image => self.rich()?.read_image(kind).map(Content::Image),
+            Format::Mime(_) => None,
+        }
+    }
+
+    /// Writes the given contents, in multiple formats, to the [`Clipboard`].
+    ///
+    /// The system clipboard can only hold one of these at a time, in order
+    /// of preference:
+    ///
+    /// - The first [`Content::Html`], together with the first
+    ///   [`Content::Text`] as its plain text alternative.
+    /// - The first [`Content::Image`].
+    /// - The first [`Content::Text`].
+    ///
+    /// [`Content::Mime`] is not supported and is discarded.
+    pub fn write_formats(&mut self, kind: Kind, contents: Vec<Content>) {
+        let mut text = None;
+        let mut html = None;
+        let mut image = None;
+
+        for content in contents {
+            match content {
+                Content::Text(contents) => {
+                    let _ = text.get_or_insert(contents);
+                }
+                Content::Html(contents) => {
+                    let _ = html.get_or_insert(contents);
+                }
+                Content::Image(contents) => {
+                    let _ = image.get_or_insert(contents);
+                }
+                Content::Mime { mime, .. } => {
+                    log::warn!("unsupported clipboard format: {mime}");
+                }
+            }
+        }
+
+        let result = match (html, image) {
+            (Some(html), _) => self
+                .rich_mut()
+                .map(|rich| rich.write_html(kind, html, text.take())),
+            (None, Some(image)) => self.rich_mut().map(|rich| rich.write_image(kind, image)),
+            (None, None) => None,
+        };
+
+        match result {
+            Some(Ok(())) => {}
+            Some(Err(error)) => {
+                log::warn!("error writing to clipboard: {error}");
+            }
+            None => {
+                if let Some(text) = text {
+                    self.write(kind, text);
+                }
+            }
+        }
+    }
+
+    /// Returns the [`Format`]s the current content of the [`Clipboard`] is
+    /// available in.
+    pub fn formats(&self, kind: Kind) -> Vec<Format> {
+        let mut formats = Vec::new();
+
+        if self.read(kind).is_some() {
+            formats.push(Format::Text);
+        }
+
+        if let Some(rich) = self.rich() {
+            if rich.read_html(kind).is_some() {
+                formats.push(Format::Html);
+            }
+
+            if rich.read_image(kind).is_some() {
+                formats.push(Format::Image);
+            }
+        }
+
+        formats
+    }
+
+    fn rich(&self) -> Option<&rich::Clipboard> {
+        match &self.state {
+            State::Connected { rich, .. } => rich.as_ref(),
+            State::Unavailable => None,
+        }
+    }
+
+    fn rich_mut(&mut self) -> Option<&mut rich::Clipboard> {
+        match &mut self.state {
+            State::Connected { rich, .. } => rich.as_mut(),
+            State::Unavailable => None,
+        }
+    }
+
     /// Returns the identifier of the window used to create the [`Clipboard`], if any.
     pub fn window_id(&self) -> Option<WindowId> {
         match &self.state {
@@ -97,4 +206,159 @@ impl crate::core::Clipboard for Clipboard {
     fn write(&mut self, kind: Kind, contents: String) {
         self.write(kind, contents);
     }
+
+    fn read_format(&self, kind: Kind, format: &Format) -> Option<Content> {
+        self.read_format(kind, format)
+    }
+
+    fn write_formats(&mut self, kind: Kind, contents: Vec<Content>) {
+        self.write_formats(kind, contents);
+    }
+
+    fn formats(&self, kind: Kind) -> Vec<Format> {
+        self.formats(kind)
+    }
+}
+
+#[cfg(any(windows, target_os = "macos", target_os = "linux"))]
+mod rich {
+    use super::{Image, Kind};
+
+    use std::cell::RefCell;
+
+    /// A system clipboard that supports HTML and images.
+    pub struct Clipboard(RefCell<arboard::Clipboard>);
+
+    impl Clipboard {
+        pub fn connect() -> Option<Self> {
+            match arboard::Clipboard::new() {
+                Ok(clipboard) => Some(Self(RefCell::new(clipboard))),
+                Err(error) => {
+                    log::warn!("HTML and images will not be copied or pasted: {error}");
+                    None
+                }
+            }
+        }
+
+        pub fn read_html(&self, kind: Kind) -> Option<String> {
+            let mut clipboard = self.0.borrow_mut();
+
+            get(&mut clipboard, kind)?.html().ok()
+        }
+
+        pub fn read_image(&self, kind: Kind) -> Option<Image> {
+            let mut clipboard = self.0.borrow_mut();
+            let image = get(&mut clipboard, kind)?.image().ok()?;
+
+            Some(Image::new(
+                u32::try_from(image.width).ok()?,
+                u32::try_from(image.height).ok()?,
+                image.bytes.into_owned(),
+            ))
+        }
+
+        pub fn write_html(
+            &mut self,
+            kind: Kind,
+            html: String,
+            text: Option<String>,
+        ) -> Result<(), arboard::Error> {
+            let clipboard = self.0.get_mut();
+
+            match set(clipboard, kind) {
+                Some(set) => set.html(html, text),
+                None => Ok(()),
+            }
+        }
+
+        pub fn write_image(&mut self, kind: Kind, image: Image) -> Result<(), arboard::Error> {
+            let clipboard = self.0.get_mut();
+
+            match set(clipboard, kind) {
+                Some(set) => set.image(arboard::ImageData {
+                    width: image.width as usize,
+                    height: image.height as usize,
+                    bytes: image.rgba.to_vec().into(),
+                }),
+                None => Ok(()),
+            }
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn get(clipboard: &mut arboard::Clipboard, kind: Kind) -> Option<arboard::Get<'_>> {
+        use arboard::GetExtLinux;
+
+        Some(clipboard.get().clipboard(selection(kind)))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn get(clipboard: &mut arboard::Clipboard, kind: Kind) -> Option<arboard::Get<'_>> {
+        match kind {
+            Kind::Standard => Some(clipboard.get()),
+            Kind::Primary => None,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn set(clipboard: &mut arboard::Clipboard, kind: Kind) -> Option<arboard::Set<'_>> {
+        use arboard::SetExtLinux;
+
+        Some(clipboard.set().clipboard(selection(kind)))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn set(clipboard: &mut arboard::Clipboard, kind: Kind) -> Option<arboard::Set<'_>> {
+        match kind {
+            Kind::Standard => Some(clipboard.set()),
+            Kind::Primary => None,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn selection(kind: Kind) -> arboard::LinuxClipboardKind {
+        match kind {
+            Kind::Standard => arboard::LinuxClipboardKind::Clipboard,
+            Kind::Primary => arboard::LinuxClipboardKind::Primary,
+        }
+    }
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "linux")))]
+mod rich {
+    use super::{Image, Kind};
+
+    use std::convert::Infallible;
+
+    /// A system clipboard that supports HTML and images.
+    ///
+    /// It is not available on this platform.
+    pub enum Clipboard {}
+
+    impl Clipboard {
+        pub fn connect() -> Option<Self> {
+            None
+        }
+
+        pub fn read_html(&self, _kind: Kind) -> Option<String> {
+            match *self {}
+        }
+
+        pub fn read_image(&self, _kind: Kind) -> Option<Image> {
+            match *self {}
+        }
+
+        pub fn write_html(
+            &mut self,
+            _kind: Kind,
+            _html: String,
+            _text: Option<String>,
+        ) -> Result<(), Infallible> {
+            match *self {}
+        }
+
+        pub fn write_image(&mut self, _kind: Kind, _image: Image) -> Result<(), Infallible> {
+            match *self {}
+        }
+    }
 }
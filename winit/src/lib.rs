@@ -29,6 +29,7 @@ pub use program::runtime;
 pub use runtime::futures;
 pub use winit;
 
+pub mod accessibility;
 pub mod clipboard;
 pub mod conversion;
 
@@ -59,6 +60,7 @@ use crate::runtime::system;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::{Action, Task};
 
+use accessibility::Accessibility;
 use program::Program;
 use window::WindowManager;
 
@@ -70,6 +72,14 @@ use std::sync::Arc;
 
 /// Runs a [`Program`] with the provided settings.
 pub fn run<P>(program: P) -> Result<(), Error>
+where
+    P: Program + 'static,
+    P::Theme: theme::Base,
+{
+    launch(program, None)
+}
+
+fn launch<P>(program: P, accessibility: Option<accessibility::Connect>) -> Result<(), Error>
 where
     P: Program + 'static,
     P::Theme: theme::Base,
@@ -141,6 +151,7 @@ where
         graphics_settings,
         settings.fonts,
         system_theme_receiver,
+        accessibility,
     ));
 
     let context = task::Context::from_waker(task::noop_waker_ref());
@@ -504,6 +515,7 @@ async fn run_instance<P>(
     graphics_settings: graphics::Settings,
     default_fonts: Vec<Cow<'static, [u8]>>,
     mut _system_theme: oneshot::Receiver<theme::Mode>,
+    accessibility: Option<accessibility::Connect>,
 ) where
     P: Program + 'static,
     P::Theme: theme::Base,
@@ -689,6 +701,17 @@ async fn run_instance<P>(
                 );
                 let _ = ui_caches.insert(id, user_interface::Cache::default());
 
+                if let Some(connect) = &accessibility {
+                    let mut accessibility = Accessibility::new(connect(id, &window.raw));
+
+                    accessibility.update(
+                        user_interfaces.get_mut(&id).expect("Get user interface"),
+                        &window.renderer,
+                    );
+
+                    window.accessibility = Some(accessibility);
+                }
+
                 if make_visible {
                     window.raw.set_visible(true);
                 }
@@ -761,6 +784,8 @@ async fn run_instance<P>(
                             &mut system_theme,
                         );
                         actions += 1;
+
+                        update_accessibility(&mut user_interfaces, &mut window_manager);
                     }
                     event::Event::WindowEvent {
                         window_id: id,
@@ -879,6 +904,8 @@ async fn run_instance<P>(
                                     );
                                 }
 
+                                update_accessibility(&mut user_interfaces, &mut window_manager);
+
                                 for (window_id, window) in window_manager.iter_mut() {
                                     // We are already redrawing this window
                                     if window_id == id {
@@ -1025,6 +1052,10 @@ async fn run_instance<P>(
                             continue;
                         };
 
+                        if let Some(accessibility) = &mut window.accessibility {
+                            accessibility.process_event(&window_event);
+                        }
+
                         match window_event {
                             winit::event::WindowEvent::Resized(_) => {
                                 window.raw.request_redraw();
@@ -1190,6 +1221,8 @@ async fn run_instance<P>(
                             }
                         }
 
+                        update_accessibility(&mut user_interfaces, &mut window_manager);
+
                         if let Some(redraw_at) = window_manager.redraw_at() {
                             let _ = control_sender
                                 .start_send(Control::ChangeFlow(ControlFlow::WaitUntil(redraw_at)));
@@ -1308,6 +1341,16 @@ fn run_action<'a, P, C>(
             clipboard::Action::Write { target, contents } => {
                 clipboard.write(target, contents);
             }
+            clipboard::Action::ReadFormat {
+                target,
+                format,
+                channel,
+            } => {
+                let _ = channel.send(clipboard.read_format(target, &format));
+            }
+            clipboard::Action::WriteFormats { target, contents } => {
+                clipboard.write_formats(target, contents);
+            }
         },
         Action::Window(action) => match action {
             window::Action::Open(id, settings, channel) => {
@@ -1737,6 +1780,26 @@ fn run_action<'a, P, C>(
     }
 }
 
+/// Updates the accessibility tree of every window.
+fn update_accessibility<P: Program, C>(
+    user_interfaces: &mut FxHashMap<
+        window::Id,
+        UserInterface<'_, P::Message, P::Theme, P::Renderer>,
+    >,
+    window_manager: &mut WindowManager<P, C>,
+) where
+    C: Compositor<Renderer = P::Renderer>,
+    P::Theme: theme::Base,
+{
+    for (id, window) in window_manager.iter_mut() {
+        if let Some(accessibility) = &mut window.accessibility
+            && let Some(user_interface) = user_interfaces.get_mut(&id)
+        {
+            accessibility.update(user_interface, &window.renderer);
+        }
+    }
+}
+
 /// Build the user interface for every window.
 pub fn build_user_interfaces<'a, P: Program, C>(
     program: &'a program::Instance<P>,
@@ -4,6 +4,7 @@ use state::State;
 
 pub use crate::core::window::{Event, Id, RedrawRequest, Settings};
 
+use crate::accessibility::Accessibility;
 use crate::conversion;
 use crate::core::alignment;
 use crate::core::input_method;
@@ -75,6 +76,7 @@ where
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
                 redraw_at: None,
+                accessibility: None,
                 preedit: None,
                 ime_state: None,
             },
@@ -164,6 +166,7 @@ where
     pub surface_version: u64,
     pub renderer: P::Renderer,
     pub redraw_at: Option<Instant>,
+    pub(crate) accessibility: Option<Accessibility>,
     preedit: Option<Preedit<P::Renderer>>,
     ime_state: Option<(Rectangle, input_method::Purpose)>,
 }